@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ruff::autofix::fixer;
+use ruff::linter::lint_path;
+use ruff::registry::CheckCode;
+use ruff::settings::{flags, Settings};
+
+/// Benchmark the full lint pipeline against a single, larger-than-average
+/// file, with checks enabled across every `LintSource` (Lines, Tokens, AST,
+/// and Imports). The token stream, AST, and line index are each computed
+/// once per file and shared across all four passes, so this is a rough
+/// end-to-end proxy for the cost of that pipeline as files grow.
+fn criterion_benchmark(c: &mut Criterion) {
+    let path = Path::new("resources/test/fixtures/pycodestyle/E501.py");
+    let settings = Settings::for_rules(vec![
+        CheckCode::E501, // Lines
+        CheckCode::Q000, // Tokens
+        CheckCode::F401, // AST
+        CheckCode::I001, // Imports
+    ]);
+    c.bench_function("lint_path", |b| {
+        b.iter(|| {
+            lint_path(
+                black_box(path),
+                None,
+                &settings,
+                flags::Cache::Disabled,
+                flags::Noqa::Enabled,
+                fixer::Mode::None,
+            )
+            .unwrap();
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -97,14 +97,24 @@ pub fn derive_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream>
     let prefix_ident = Ident::new(&format!("{ident}Prefix"), ident.span());
     let prefix = expand(&ident, &prefix_ident, &variants);
     let expanded = quote! {
-        #[derive(PartialEq, Eq, PartialOrd, Ord)]
-        pub enum SuffixLength {
-            None,
-            Zero,
-            One,
-            Two,
-            Three,
-            Four,
+        /// How many numeric characters appear in a `CheckCodePrefix`'s
+        /// suffix, used to order prefixes from least to most specific (e.g.,
+        /// `E` is less specific than `E5`, which is less specific than
+        /// `E501`). `none()` is reserved for the catch-all `ALL` prefix,
+        /// which is less specific than any real prefix. Computed directly
+        /// from each prefix's length, so it scales to codes of any suffix
+        /// length without needing a hand-maintained variant per length.
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+        pub struct SuffixLength(Option<u8>);
+
+        impl SuffixLength {
+            pub const fn none() -> Self {
+                SuffixLength(None)
+            }
+
+            pub const fn new(num_numeric: u8) -> Self {
+                SuffixLength(Some(num_numeric))
+            }
         }
 
         #prefix
@@ -169,6 +179,7 @@ fn expand(
     quote! {
         #[derive(
             ::strum_macros::EnumString,
+            ::strum_macros::EnumIter,
             ::strum_macros::AsRefStr,
             Debug,
             PartialEq,
@@ -179,7 +190,18 @@ fn expand(
             ::serde::Serialize,
             ::serde::Deserialize,
             ::schemars::JsonSchema,
+            ::clap::ValueEnum,
         )]
+        // Every variant is named after the check code (or code prefix) it
+        // represents, so preserve that exact spelling instead of clap's
+        // default kebab-case renaming (which would mangle e.g. `ALL`).
+        #[value(rename_all = "verbatim")]
+        // Route through `TryFrom<String>` so that an unrecognized code or
+        // prefix in a `pyproject.toml`/`ruff.toml` file produces a
+        // "did you mean" suggestion instead of serde's default (and, given
+        // how many variants this enum has, unreadable) list of every valid
+        // variant.
+        #[serde(try_from = "String")]
         pub enum #prefix_ident {
             #(#prefix_variants,)*
         }
@@ -230,24 +252,19 @@ fn generate_impls(
     let specificity_match_arms = prefix_to_codes.keys().map(|prefix| {
         if *prefix == ALL {
             quote! {
-                #prefix_ident::#prefix => SuffixLength::None,
+                #prefix_ident::#prefix => SuffixLength::none(),
             }
         } else {
-            let num_numeric = prefix
-                .to_string()
-                .chars()
-                .filter(|char| char.is_numeric())
-                .count();
-            let suffix_len = match num_numeric {
-                0 => quote! { SuffixLength::Zero },
-                1 => quote! { SuffixLength::One },
-                2 => quote! { SuffixLength::Two },
-                3 => quote! { SuffixLength::Three },
-                4 => quote! { SuffixLength::Four },
-                _ => panic!("Invalid prefix: {prefix}"),
-            };
+            let num_numeric = u8::try_from(
+                prefix
+                    .to_string()
+                    .chars()
+                    .filter(|char| char.is_numeric())
+                    .count(),
+            )
+            .unwrap_or_else(|_| panic!("Prefix has an implausibly long numeric suffix: {prefix}"));
             quote! {
-                #prefix_ident::#prefix => #suffix_len,
+                #prefix_ident::#prefix => SuffixLength::new(#num_numeric),
             }
         }
     });
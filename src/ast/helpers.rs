@@ -203,6 +203,28 @@ pub fn is_assignment_to_a_dunder(stmt: &Stmt) -> bool {
     }
 }
 
+/// Return `true` if the `Stmt` is a call to `sys.path.insert`, `.append`, or
+/// `.extend`, the common patterns used by entry-point scripts to mutate
+/// `sys.path` before importing from a non-standard location.
+pub fn is_sys_path_manipulation(stmt: &Stmt) -> bool {
+    let StmtKind::Expr { value } = &stmt.node else {
+        return false;
+    };
+    let ExprKind::Call { func, .. } = &value.node else {
+        return false;
+    };
+    let ExprKind::Attribute { value, attr, .. } = &func.node else {
+        return false;
+    };
+    if !matches!(attr.as_str(), "insert" | "append" | "extend") {
+        return false;
+    }
+    let ExprKind::Attribute { value, attr, .. } = &value.node else {
+        return false;
+    };
+    attr == "path" && matches!(&value.node, ExprKind::Name { id, .. } if id == "sys")
+}
+
 /// Return `true` if the `Expr` is a singleton (`None`, `True`, `False`, or
 /// `...`).
 pub fn is_singleton(expr: &Expr) -> bool {
@@ -357,6 +379,41 @@ pub fn match_leading_content(stmt: &Stmt, locator: &SourceCodeLocator) -> bool {
     prefix.chars().any(|char| !char.is_whitespace())
 }
 
+/// Return the `Location` at which an `import` statement for `module` should
+/// be inserted, along with the text to insert there.
+///
+/// Skips past any module docstring and any leading `from __future__ import`
+/// statements, so the new import lands alongside the rest of the file's
+/// imports rather than splitting them from the top of the file.
+pub fn insert_import(module: &str, body: &[Stmt]) -> (Location, String) {
+    let mut location = Location::new(1, 0);
+    let mut iter = body.iter().peekable();
+
+    if let Some(stmt) = iter.peek() {
+        if matches!(
+            &stmt.node,
+            StmtKind::Expr { value }
+                if matches!(value.node, ExprKind::Constant { value: Constant::Str(_), .. })
+        ) {
+            location = Location::new(stmt.end_location.unwrap().row() + 1, 0);
+            iter.next();
+        }
+    }
+
+    while let Some(stmt) = iter.peek() {
+        let StmtKind::ImportFrom { module: from_module, .. } = &stmt.node else {
+            break;
+        };
+        if from_module.as_deref() != Some("__future__") {
+            break;
+        }
+        location = Location::new(stmt.end_location.unwrap().row() + 1, 0);
+        iter.next();
+    }
+
+    (location, format!("import {module}\n"))
+}
+
 /// Return `true` if a `Stmt` has trailing content.
 pub fn match_trailing_content(stmt: &Stmt, locator: &SourceCodeLocator) -> bool {
     let range = Range::new(
@@ -529,6 +586,7 @@ pub fn else_range(stmt: &Stmt, locator: &SourceCodeLocator) -> Option<Range> {
         StmtKind::For { body, orelse, .. }
         | StmtKind::AsyncFor { body, orelse, .. }
         | StmtKind::While { body, orelse, .. }
+        | StmtKind::If { body, orelse, .. }
             if !orelse.is_empty() =>
         {
             let body_end = body
@@ -72,6 +72,54 @@ pub fn extract_all_names(stmt: &Stmt, scope: &Scope, bindings: &[Binding]) -> Ve
     names
 }
 
+/// Extract the string literal names passed to a `__all__.append(...)` or
+/// `__all__.extend([...])` call, returning `None` if `call` isn't such a
+/// call.
+pub fn extract_all_names_from_call(call: &Expr) -> Option<Vec<String>> {
+    let ExprKind::Call { func, args, .. } = &call.node else {
+        return None;
+    };
+    let ExprKind::Attribute { value, attr, .. } = &func.node else {
+        return None;
+    };
+    let ExprKind::Name { id, .. } = &value.node else {
+        return None;
+    };
+    if id != "__all__" {
+        return None;
+    }
+
+    let mut names = vec![];
+    match attr.as_str() {
+        "append" => {
+            if let Some(ExprKind::Constant {
+                value: Constant::Str(value),
+                ..
+            }) = args.first().map(|arg| &arg.node)
+            {
+                names.push(value.to_string());
+            }
+        }
+        "extend" => {
+            if let Some(ExprKind::List { elts, .. } | ExprKind::Tuple { elts, .. }) =
+                args.first().map(|arg| &arg.node)
+            {
+                for elt in elts {
+                    if let ExprKind::Constant {
+                        value: Constant::Str(value),
+                        ..
+                    } = &elt.node
+                    {
+                        names.push(value.to_string());
+                    }
+                }
+            }
+        }
+        _ => return None,
+    }
+    Some(names)
+}
+
 #[derive(Default)]
 struct GlobalVisitor<'a> {
     globals: FxHashMap<&'a str, &'a Stmt>,
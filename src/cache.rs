@@ -3,13 +3,17 @@ use std::fs;
 use std::hash::{Hash, Hasher};
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use anyhow::Result;
+use colored::Colorize;
 use filetime::FileTime;
 use log::error;
 use path_absolutize::Absolutize;
 use serde::{Deserialize, Serialize};
+use walkdir::WalkDir;
 
+use crate::fs::relativize_path;
 use crate::message::Message;
 use crate::settings::{flags, Settings};
 
@@ -17,6 +21,13 @@ pub const CACHE_DIR_NAME: &str = ".ruff_cache";
 
 const CARGO_PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Counters for `get()` outcomes over the lifetime of the process, surfaced
+/// via `--cache-info`. Plain `AtomicUsize`s (rather than something like the
+/// `timing` module's `Mutex`-guarded map) since there's only ever two
+/// counters and no need to key them by anything.
+static HITS: AtomicUsize = AtomicUsize::new(0);
+static MISSES: AtomicUsize = AtomicUsize::new(0);
+
 #[derive(Serialize, Deserialize)]
 struct CacheMetadata {
     mtime: i64,
@@ -90,6 +101,21 @@ pub fn get<P: AsRef<Path>>(
     metadata: &fs::Metadata,
     settings: &Settings,
     autofix: flags::Autofix,
+) -> Option<Vec<Message>> {
+    let result = get_inner(path, metadata, settings, autofix);
+    if result.is_some() {
+        HITS.fetch_add(1, Ordering::Relaxed);
+    } else {
+        MISSES.fetch_add(1, Ordering::Relaxed);
+    }
+    result
+}
+
+fn get_inner<P: AsRef<Path>>(
+    path: P,
+    metadata: &fs::Metadata,
+    settings: &Settings,
+    autofix: flags::Autofix,
 ) -> Option<Vec<Message>> {
     let encoded = read_sync(&settings.cache_dir, cache_key(path, settings, autofix)).ok()?;
     let (mtime, messages) = match bincode::deserialize::<CheckResult>(&encoded[..]) {
@@ -130,3 +156,25 @@ pub fn set<P: AsRef<Path>>(
         error!("Failed to write to cache: {e:?}");
     }
 }
+
+/// The total size, in bytes, of every file in `cache_dir`.
+fn dir_size(cache_dir: &Path) -> u64 {
+    WalkDir::new(cache_dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+/// Print the cache's location, on-disk size, and hit/miss counts accumulated
+/// over the lifetime of this process (i.e., the run that's about to exit).
+pub fn print_info(cache_dir: &Path) {
+    let hits = HITS.load(Ordering::Relaxed);
+    let misses = MISSES.load(Ordering::Relaxed);
+    eprintln!("Cache directory: {}", relativize_path(cache_dir).bold());
+    eprintln!("Cache size: {} bytes", dir_size(cache_dir));
+    eprintln!("Hits: {hits}");
+    eprintln!("Misses: {misses}");
+}
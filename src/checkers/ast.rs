@@ -10,7 +10,7 @@ use rustpython_ast::{Comprehension, Located, Location};
 use rustpython_common::cformat::{CFormatError, CFormatErrorType};
 use rustpython_parser::ast::{
     Arg, Arguments, Constant, Excepthandler, ExcepthandlerKind, Expr, ExprContext, ExprKind,
-    KeywordData, Operator, Stmt, StmtKind, Suite,
+    KeywordData, MatchCase, Operator, Pattern, PatternKind, Stmt, StmtKind, Suite,
 };
 use rustpython_parser::parser;
 
@@ -37,12 +37,15 @@ use crate::source_code_locator::SourceCodeLocator;
 use crate::source_code_style::SourceCodeStyleDetector;
 use crate::visibility::{module_visibility, transition_scope, Modifier, Visibility, VisibleScope};
 use crate::{
-    autofix, docstrings, flake8_2020, flake8_annotations, flake8_bandit, flake8_blind_except,
+    autofix, darglint, docstrings, flake8_2020, flake8_annotations, flake8_bandit,
+    flake8_blind_except,
     flake8_boolean_trap, flake8_bugbear, flake8_builtins, flake8_comprehensions, flake8_datetimez,
-    flake8_debugger, flake8_errmsg, flake8_implicit_str_concat, flake8_import_conventions,
-    flake8_pie, flake8_print, flake8_pytest_style, flake8_return, flake8_simplify,
-    flake8_tidy_imports, flake8_unused_arguments, mccabe, noqa, pandas_vet, pep8_naming,
-    pycodestyle, pydocstyle, pyflakes, pygrep_hooks, pylint, pyupgrade, ruff, violations,
+    flake8_debugger, flake8_doctests, flake8_errmsg, flake8_implicit_str_concat,
+    flake8_import_conventions,
+    flake8_pie, flake8_print, flake8_pyi, flake8_pytest_style, flake8_return, flake8_simplify,
+    flake8_tidy_imports, flake8_unused_arguments, furb, mccabe, noqa, numpy, pandas_vet,
+    pep8_naming,
+    perflint, pycodestyle, pydocstyle, pyflakes, pygrep_hooks, pylint, pyupgrade, ruff, violations,
     visibility,
 };
 
@@ -56,6 +59,7 @@ pub struct Checker<'a> {
     path: &'a Path,
     autofix: flags::Autofix,
     noqa: flags::Noqa,
+    pub(crate) python_ast: &'a Suite,
     pub(crate) settings: &'a Settings,
     pub(crate) noqa_line_for: &'a IntMap<usize, usize>,
     pub(crate) locator: &'a SourceCodeLocator<'a>,
@@ -100,6 +104,7 @@ pub struct Checker<'a> {
     except_handlers: Vec<Vec<Vec<&'a str>>>,
     // Check-specific state.
     pub(crate) flake8_bugbear_seen: Vec<&'a Expr>,
+    pub(crate) flake8_bugbear_function_body: Option<&'a [Stmt]>,
 }
 
 impl<'a> Checker<'a> {
@@ -111,6 +116,7 @@ impl<'a> Checker<'a> {
         path: &'a Path,
         locator: &'a SourceCodeLocator,
         style: &'a SourceCodeStyleDetector,
+        python_ast: &'a Suite,
     ) -> Checker<'a> {
         Checker {
             settings,
@@ -120,6 +126,7 @@ impl<'a> Checker<'a> {
             path,
             locator,
             style,
+            python_ast,
             checks: vec![],
             definitions: vec![],
             deletions: FxHashSet::default(),
@@ -156,6 +163,7 @@ impl<'a> Checker<'a> {
             except_handlers: vec![],
             // Check-specific state.
             flake8_bugbear_seen: vec![],
+            flake8_bugbear_function_body: None,
         }
     }
 
@@ -165,6 +173,11 @@ impl<'a> Checker<'a> {
         matches!(self.autofix, flags::Autofix::Enabled) && self.settings.fixable.contains(code)
     }
 
+    /// Return `true` if the file under checking is a type stub (`.pyi`).
+    pub fn is_stub_file(&self) -> bool {
+        self.path.extension().map_or(false, |ext| ext == "pyi")
+    }
+
     /// Return `true` if the `Expr` is a reference to `typing.${target}`.
     pub fn match_typing_expr(&self, expr: &Expr, target: &str) -> bool {
         let call_path = dealias_call_path(collect_call_paths(expr), &self.import_aliases);
@@ -210,7 +223,7 @@ impl<'a> Checker<'a> {
             Location::new(noqa_lineno + 1, 0),
         ));
         match noqa::extract_noqa_directive(&line) {
-            Directive::None => false,
+            Directive::None | Directive::Invalid(..) => false,
             Directive::All(..) => true,
             Directive::Codes(.., codes) => noqa::includes(code, &codes),
         }
@@ -243,6 +256,8 @@ where
                 self.futures_allowed = false;
                 if !self.seen_import_boundary
                     && !helpers::is_assignment_to_a_dunder(stmt)
+                    && !(self.settings.pycodestyle.allow_sys_path_manipulation
+                        && helpers::is_sys_path_manipulation(stmt))
                     && !operations::in_nested_block(
                         self.parents.iter().rev().map(std::convert::Into::into),
                     )
@@ -374,6 +389,14 @@ where
                 body,
                 ..
             } => {
+                if self.settings.enabled.contains(&CheckCode::E301)
+                    || self.settings.enabled.contains(&CheckCode::E302)
+                    || self.settings.enabled.contains(&CheckCode::E303)
+                    || self.settings.enabled.contains(&CheckCode::E306)
+                {
+                    pycodestyle::plugins::blank_lines(self, stmt, decorator_list);
+                }
+
                 if self.settings.enabled.contains(&CheckCode::E743) {
                     if let Some(check) = pycodestyle::checks::ambiguous_function_name(name, || {
                         helpers::identifier_range(stmt, self.locator)
@@ -481,6 +504,67 @@ where
                     pylint::plugins::property_with_parameters(self, stmt, decorator_list, args);
                 }
 
+                if self.settings.enabled.contains(&CheckCode::PLE0101) && name == "__init__" {
+                    pylint::plugins::init_returns_value(self, body);
+                }
+
+                if self.settings.enabled.contains(&CheckCode::PLE0302) {
+                    pylint::plugins::bad_dunder_method_signature(self, stmt, name, args);
+                }
+
+                if self.settings.enabled.contains(&CheckCode::PLR1711) {
+                    pylint::plugins::useless_return(self, body);
+                }
+
+                if self.settings.enabled.contains(&CheckCode::PLW0101) {
+                    self.checks
+                        .extend(pylint::plugins::unreachable_code(body));
+                }
+
+                if self.settings.enabled.contains(&CheckCode::PLR0911) {
+                    if let Some(check) = pylint::plugins::too_many_return_statements(
+                        stmt,
+                        body,
+                        self.settings.pylint.max_returns,
+                        self.locator,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+
+                if self.settings.enabled.contains(&CheckCode::PLR0912) {
+                    if let Some(check) = pylint::plugins::too_many_branches(
+                        stmt,
+                        body,
+                        self.settings.pylint.max_branches,
+                        self.locator,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+
+                if self.settings.enabled.contains(&CheckCode::PLR0913) {
+                    if let Some(check) = pylint::plugins::too_many_arguments(
+                        stmt,
+                        args,
+                        self.settings.pylint.max_args,
+                        self.locator,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+
+                if self.settings.enabled.contains(&CheckCode::PLR0915) {
+                    if let Some(check) = pylint::plugins::too_many_statements(
+                        stmt,
+                        body,
+                        self.settings.pylint.max_statements,
+                        self.locator,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+
                 if self.settings.enabled.contains(&CheckCode::PT001)
                     || self.settings.enabled.contains(&CheckCode::PT002)
                     || self.settings.enabled.contains(&CheckCode::PT003)
@@ -588,6 +672,14 @@ where
                 decorator_list,
                 body,
             } => {
+                if self.settings.enabled.contains(&CheckCode::E301)
+                    || self.settings.enabled.contains(&CheckCode::E302)
+                    || self.settings.enabled.contains(&CheckCode::E303)
+                    || self.settings.enabled.contains(&CheckCode::E306)
+                {
+                    pycodestyle::plugins::blank_lines(self, stmt, decorator_list);
+                }
+
                 if self.settings.enabled.contains(&CheckCode::UP004) {
                     pyupgrade::plugins::useless_object_inheritance(
                         self, stmt, name, bases, keywords,
@@ -621,6 +713,10 @@ where
                     }
                 }
 
+                if self.settings.enabled.contains(&CheckCode::PLE0241) {
+                    pylint::plugins::duplicate_bases(self, stmt, bases);
+                }
+
                 if self.settings.enabled.contains(&CheckCode::B018) {
                     flake8_bugbear::plugins::useless_expression(self, body);
                 }
@@ -641,6 +737,24 @@ where
                     flake8_pie::plugins::dupe_class_field_definitions(self, bases, body);
                 }
 
+                if self.settings.enabled.contains(&CheckCode::RUF008) {
+                    self.checks.extend(ruff::checks::mutable_dataclass_default(
+                        decorator_list,
+                        body,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ));
+                }
+
+                if self.settings.enabled.contains(&CheckCode::RUF012) {
+                    self.checks.extend(ruff::checks::mutable_class_default(
+                        decorator_list,
+                        body,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ));
+                }
+
                 self.check_builtin_shadowing(name, stmt, false);
 
                 for expr in bases {
@@ -765,6 +879,9 @@ where
                     if self.settings.enabled.contains(&CheckCode::PLR0402) {
                         pylint::plugins::use_from_import(self, alias);
                     }
+                    if self.settings.enabled.contains(&CheckCode::PLW0406) {
+                        pylint::plugins::import_self(self, stmt, &alias.node.name);
+                    }
 
                     if let Some(asname) = &alias.node.asname {
                         for alias in names {
@@ -938,6 +1055,17 @@ where
                     }
                 }
 
+                if let Some(module) = module {
+                    if self.settings.enabled.contains(&CheckCode::PLW0406) {
+                        pylint::plugins::import_from_self(self, stmt, level.as_ref(), module);
+                    }
+                    if self.settings.enabled.contains(&CheckCode::PLC2701) {
+                        for alias in names {
+                            pylint::plugins::import_private_name(self, module, alias);
+                        }
+                    }
+                }
+
                 for alias in names {
                     if let Some("__future__") = module.as_deref() {
                         let name = alias.node.asname.as_ref().unwrap_or(&alias.node.name);
@@ -982,6 +1110,14 @@ where
                             ));
                         }
                     } else if alias.node.name == "*" {
+                        if self.settings.enabled.contains(&CheckCode::B029) {
+                            flake8_bugbear::plugins::star_import_shadows_existing(
+                                self,
+                                stmt,
+                                module.as_deref(),
+                            );
+                        }
+
                         self.add_binding(
                             "*",
                             Binding {
@@ -1199,6 +1335,20 @@ where
                         self.current_stmt_parent().map(|parent| parent.0),
                     );
                 }
+                if self.settings.enabled.contains(&CheckCode::FURB110) {
+                    furb::plugins::if_else_dict_get(self, stmt);
+                }
+                if self.settings.enabled.contains(&CheckCode::PLR5501) {
+                    pylint::plugins::collapsible_else_if(self, stmt);
+                }
+                if self.settings.enabled.contains(&CheckCode::SIM401) {
+                    flake8_simplify::plugins::use_dict_get_with_default(self, stmt);
+                }
+                if self.settings.enabled.contains(&CheckCode::PLW0125) {
+                    if let Some(check) = pylint::plugins::using_constant_test(test) {
+                        self.checks.push(check);
+                    }
+                }
             }
             StmtKind::Assert { test, msg } => {
                 if self.settings.enabled.contains(&CheckCode::F631) {
@@ -1227,6 +1377,11 @@ where
                         self.checks.push(check);
                     }
                 }
+                if self.settings.enabled.contains(&CheckCode::RUF023) {
+                    if let Some(msg) = msg {
+                        ruff::plugins::assert_message_side_effect(self, msg);
+                    }
+                }
             }
             StmtKind::With { items, body, .. } | StmtKind::AsyncWith { items, body, .. } => {
                 if self.settings.enabled.contains(&CheckCode::B017) {
@@ -1238,6 +1393,17 @@ where
                 if self.settings.enabled.contains(&CheckCode::SIM117) {
                     flake8_simplify::plugins::multiple_with_statements(self, stmt);
                 }
+                if self.settings.enabled.contains(&CheckCode::PLW2901) {
+                    for item in items {
+                        if let Some(optional_vars) = &item.optional_vars {
+                            pylint::plugins::loop_variable_overwritten(
+                                self,
+                                optional_vars,
+                                body,
+                            );
+                        }
+                    }
+                }
             }
             StmtKind::While { body, orelse, .. } => {
                 if self.settings.enabled.contains(&CheckCode::B023) {
@@ -1246,6 +1412,9 @@ where
                 if self.settings.enabled.contains(&CheckCode::PLW0120) {
                     pylint::plugins::useless_else_on_loop(self, stmt, body, orelse);
                 }
+                if self.settings.enabled.contains(&CheckCode::PERF102) {
+                    perflint::plugins::try_except_in_loop(self, body);
+                }
             }
             StmtKind::For {
                 target,
@@ -1276,6 +1445,15 @@ where
                 if self.settings.enabled.contains(&CheckCode::SIM118) {
                     flake8_simplify::plugins::key_in_dict_for(self, target, iter);
                 }
+                if self.settings.enabled.contains(&CheckCode::PERF102) {
+                    perflint::plugins::try_except_in_loop(self, body);
+                }
+                if self.settings.enabled.contains(&CheckCode::PLW2901) {
+                    pylint::plugins::loop_variable_overwritten(self, target, body);
+                }
+                if self.settings.enabled.contains(&CheckCode::B031) {
+                    flake8_bugbear::plugins::reuse_of_groupby_generator(self, target, iter, body);
+                }
             }
             StmtKind::Try {
                 body,
@@ -1299,6 +1477,9 @@ where
                 if self.settings.enabled.contains(&CheckCode::B013) {
                     flake8_bugbear::plugins::redundant_tuple_in_exception_handler(self, handlers);
                 }
+                if self.settings.enabled.contains(&CheckCode::B030) {
+                    flake8_bugbear::plugins::except_with_non_exception_classes(self, handlers);
+                }
                 if self.settings.enabled.contains(&CheckCode::UP024) {
                     pyupgrade::plugins::os_error_alias(self, handlers);
                 }
@@ -1318,6 +1499,20 @@ where
                         self, body, handlers, finalbody,
                     );
                 }
+                if self.settings.enabled.contains(&CheckCode::S110) {
+                    self.checks
+                        .extend(flake8_bandit::checks::try_except_pass(
+                            handlers,
+                            self.settings.flake8_bandit.check_typed_exception,
+                        ));
+                }
+                if self.settings.enabled.contains(&CheckCode::S112) {
+                    self.checks
+                        .extend(flake8_bandit::checks::try_except_continue(
+                            handlers,
+                            self.settings.flake8_bandit.check_typed_exception,
+                        ));
+                }
             }
             StmtKind::Assign { targets, value, .. } => {
                 if self.settings.enabled.contains(&CheckCode::E731) {
@@ -1356,10 +1551,31 @@ where
                 }
 
                 if self.settings.enabled.contains(&CheckCode::PD901) {
-                    if let Some(check) = pandas_vet::checks::assignment_to_df(targets) {
+                    if let Some(check) = pandas_vet::checks::assignment_to_df(
+                        targets,
+                        &self.settings.pandas_vet.banned_variable_names,
+                    ) {
                         self.checks.push(check);
                     }
                 }
+
+                if self.settings.enabled.contains(&CheckCode::SIM115) {
+                    flake8_simplify::plugins::open_file_with_context_handler(self, value);
+                }
+
+                if self.settings.enabled.contains(&CheckCode::RUF022)
+                    && matches!(self.current_scope().kind, ScopeKind::Module)
+                {
+                    if let [Expr {
+                        node: ExprKind::Name { id, .. },
+                        ..
+                    }] = &targets[..]
+                    {
+                        if id == "__all__" {
+                            ruff::plugins::unsorted_dunder_all(self, value);
+                        }
+                    }
+                }
             }
             StmtKind::AnnAssign { target, value, .. } => {
                 if self.settings.enabled.contains(&CheckCode::E731) {
@@ -1367,12 +1583,50 @@ where
                         pycodestyle::plugins::do_not_assign_lambda(self, target, value, stmt);
                     }
                 }
+
+                if self.settings.enabled.contains(&CheckCode::RUF022)
+                    && matches!(self.current_scope().kind, ScopeKind::Module)
+                {
+                    if let ExprKind::Name { id, .. } = &target.node {
+                        if id == "__all__" {
+                            if let Some(value) = value {
+                                ruff::plugins::unsorted_dunder_all(self, value);
+                            }
+                        }
+                    }
+                }
             }
             StmtKind::Delete { .. } => {}
             StmtKind::Expr { value, .. } => {
                 if self.settings.enabled.contains(&CheckCode::B015) {
                     flake8_bugbear::plugins::useless_comparison(self, value);
                 }
+
+                // Track `__all__.append(...)` and `__all__.extend([...])` calls as
+                // contributing to the module's exports, the same way we already
+                // track `__all__ += [...]`.
+                if matches!(self.current_scope().kind, ScopeKind::Module) {
+                    if let Some(new_names) = operations::extract_all_names_from_call(value) {
+                        let existing_names = self.current_scope().values.get("__all__").and_then(
+                            |index| match &self.bindings[*index].kind {
+                                BindingKind::Export(names) => Some(names.clone()),
+                                _ => None,
+                            },
+                        );
+                        if let Some(mut names) = existing_names {
+                            names.extend(new_names);
+                            self.add_binding(
+                                "__all__",
+                                Binding {
+                                    kind: BindingKind::Export(names),
+                                    used: None,
+                                    range: Range::from_located(value),
+                                    source: Some(self.current_stmt().clone()),
+                                },
+                            );
+                        }
+                    }
+                }
             }
             _ => {}
         }
@@ -1397,6 +1651,14 @@ where
                 if self.settings.enabled.contains(&CheckCode::B021) {
                     flake8_bugbear::plugins::f_string_docstring(self, body);
                 }
+                if self.is_stub_file() {
+                    if self.settings.enabled.contains(&CheckCode::PYI001) {
+                        flake8_pyi::plugins::docstring_in_stub(self, body);
+                    }
+                    if self.settings.enabled.contains(&CheckCode::PYI002) {
+                        flake8_pyi::plugins::non_empty_stub_body(self, body);
+                    }
+                }
                 let definition = docstrings::extraction::extract(
                     &self.visible_scope,
                     stmt,
@@ -1458,6 +1720,9 @@ where
                 if self.settings.enabled.contains(&CheckCode::B021) {
                     flake8_bugbear::plugins::f_string_docstring(self, body);
                 }
+                if self.is_stub_file() && self.settings.enabled.contains(&CheckCode::PYI001) {
+                    flake8_pyi::plugins::docstring_in_stub(self, body);
+                }
                 let definition = docstrings::extraction::extract(
                     &self.visible_scope,
                     stmt,
@@ -1536,6 +1801,21 @@ where
                 }
                 self.visit_expr(target);
             }
+            StmtKind::Match { subject, cases } => {
+                self.visit_expr(subject);
+                for case in cases {
+                    let MatchCase {
+                        pattern,
+                        guard,
+                        body,
+                    } = case;
+                    self.bind_match_pattern(pattern);
+                    if let Some(guard) = guard {
+                        self.visit_expr(guard);
+                    }
+                    self.visit_body(body);
+                }
+            }
             _ => visitor::walk_stmt(self, stmt),
         };
         self.visible_scope = prev_visible_scope;
@@ -1630,6 +1910,16 @@ where
                 {
                     flake8_2020::plugins::subscript(self, value, slice);
                 }
+
+                if self.settings.enabled.contains(&CheckCode::PERF101) {
+                    perflint::plugins::unnecessary_list_cast(self, expr, value, slice);
+                }
+
+                if self.settings.enabled.contains(&CheckCode::SIM112) {
+                    flake8_simplify::plugins::use_capitalized_environment_variables_subscript(
+                        self, value, slice,
+                    );
+                }
             }
             ExprKind::Tuple { elts, ctx } | ExprKind::List { elts, ctx } => {
                 if matches!(ctx, ExprContext::Store) {
@@ -1731,6 +2021,27 @@ where
                     flake8_2020::plugins::name_or_attribute(self, expr);
                 }
 
+                if self.settings.enabled.contains(&CheckCode::NPY001) {
+                    if let Some(check) =
+                        numpy::checks::deprecated_type_alias(expr, self.patch(&CheckCode::NPY001))
+                    {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::NPY002) {
+                    if let Some(check) = numpy::checks::legacy_random(expr) {
+                        self.checks.push(check);
+                    }
+                }
+
+                if self.settings.enabled.contains(&CheckCode::S305) {
+                    if let Some(check) =
+                        flake8_bandit::checks::insecure_ssl_protocol_use(expr, attr, value)
+                    {
+                        self.checks.push(check);
+                    }
+                }
+
                 for (code, name) in vec![
                     (CheckCode::PD007, "ix"),
                     (CheckCode::PD008, "at"),
@@ -1762,6 +2073,8 @@ where
                                                 | BindingKind::Importation(..)
                                                 | BindingKind::FromImportation(..)
                                                 | BindingKind::SubmoduleImportation(..)
+                                        ) || pandas_vet::helpers::is_non_dataframe_literal_binding(
+                                            binding,
                                         )
                                     }) {
                                         continue;
@@ -1870,6 +2183,12 @@ where
                 if self.settings.enabled.contains(&CheckCode::UP018) {
                     pyupgrade::plugins::native_literals(self, expr, func, args, keywords);
                 }
+
+                if self.settings.enabled.contains(&CheckCode::SIM112) {
+                    flake8_simplify::plugins::use_capitalized_environment_variables_call(
+                        self, func, args,
+                    );
+                }
                 if self.settings.enabled.contains(&CheckCode::UP020) {
                     pyupgrade::plugins::open_alias(self, expr, func);
                 }
@@ -1918,6 +2237,9 @@ where
                         self, expr, func, keywords,
                     );
                 }
+                if self.settings.enabled.contains(&CheckCode::B028) {
+                    flake8_bugbear::plugins::no_explicit_stacklevel(self, expr, args, keywords);
+                }
 
                 // flake8-bandit
                 if self.settings.enabled.contains(&CheckCode::S102) {
@@ -1962,6 +2284,123 @@ where
                     self.checks
                         .extend(flake8_bandit::checks::hardcoded_password_func_arg(keywords));
                 }
+                if self.settings.enabled.contains(&CheckCode::S602) {
+                    if let Some(check) = flake8_bandit::checks::subprocess_with_shell_equals_true(
+                        func,
+                        args,
+                        keywords,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S603) {
+                    if let Some(check) = flake8_bandit::checks::subprocess_without_shell_equals_true(
+                        func,
+                        args,
+                        keywords,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S604) {
+                    if let Some(check) = flake8_bandit::checks::call_with_shell_equals_true(
+                        func,
+                        args,
+                        keywords,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S605) {
+                    if let Some(check) = flake8_bandit::checks::start_process_with_a_shell(
+                        expr,
+                        func,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S606) {
+                    if let Some(check) = flake8_bandit::checks::start_process_with_no_shell(func) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S607) {
+                    if let Some(check) = flake8_bandit::checks::start_process_with_partial_path(
+                        expr,
+                        func,
+                        args,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S609) {
+                    if let Some(check) = flake8_bandit::checks::unix_command_wildcard_injection(
+                        expr,
+                        func,
+                        args,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S301) {
+                    if let Some(check) = flake8_bandit::checks::suspicious_pickle_use(
+                        func,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S302) {
+                    if let Some(check) = flake8_bandit::checks::suspicious_marshal_use(
+                        func,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S303) {
+                    if let Some(check) = flake8_bandit::checks::insecure_cipher_use(
+                        func,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S304) {
+                    if let Some(check) = flake8_bandit::checks::insecure_cipher_mode_use(
+                        func,
+                        &self.from_imports,
+                        &self.import_aliases,
+                    ) {
+                        self.checks.push(check);
+                    }
+                }
+                if self.settings.enabled.contains(&CheckCode::S311) {
+                    if let Some(check) =
+                        flake8_bandit::checks::suspicious_non_cryptographic_random_use(
+                            func,
+                            &self.from_imports,
+                            &self.import_aliases,
+                        )
+                    {
+                        self.checks.push(check);
+                    }
+                }
                 if self.settings.enabled.contains(&CheckCode::S324) {
                     if let Some(check) = flake8_bandit::checks::hashlib_insecure_hash_functions(
                         func,
@@ -2179,6 +2618,35 @@ where
                         self.checks.push(check);
                     };
                 }
+                if self.settings.enabled.contains(&CheckCode::C418) {
+                    if let Some(check) =
+                        flake8_comprehensions::checks::unnecessary_dict_passed_to_dict(
+                            expr,
+                            func,
+                            args,
+                            keywords,
+                            self.locator,
+                            self.patch(&CheckCode::C418),
+                            Range::from_located(expr),
+                        )
+                    {
+                        self.checks.push(check);
+                    };
+                }
+                if self.settings.enabled.contains(&CheckCode::C419) {
+                    if let Some(check) =
+                        flake8_comprehensions::checks::unnecessary_comprehension_any_all(
+                            expr,
+                            func,
+                            args,
+                            self.locator,
+                            self.patch(&CheckCode::C419),
+                            Range::from_located(expr),
+                        )
+                    {
+                        self.checks.push(check);
+                    };
+                }
 
                 // flake8-boolean-trap
                 if self.settings.enabled.contains(&CheckCode::FBT003) {
@@ -2262,6 +2730,13 @@ where
                         self.checks.push(check);
                     };
                 }
+                if self.settings.enabled.contains(&CheckCode::PD101) {
+                    if let Some(check) =
+                        pandas_vet::checks::use_of_len_and_unique(expr, func, args)
+                    {
+                        self.checks.push(check);
+                    };
+                }
 
                 // flake8-datetimez
                 if self.settings.enabled.contains(&CheckCode::DTZ001) {
@@ -2334,6 +2809,26 @@ where
                         Range::from_located(expr),
                     );
                 }
+                if self.settings.enabled.contains(&CheckCode::DTZ013) {
+                    flake8_datetimez::plugins::call_datetime_time_without_tzinfo(
+                        self,
+                        func,
+                        args,
+                        keywords,
+                        Range::from_located(expr),
+                    );
+                }
+                if self.settings.enabled.contains(&CheckCode::DTZ014) {
+                    if let ExprKind::Attribute { value, attr, .. } = &func.node {
+                        if attr == "astimezone" {
+                            flake8_datetimez::plugins::call_datetime_astimezone_on_naive_datetime(
+                                self,
+                                value,
+                                Range::from_located(expr),
+                            );
+                        }
+                    }
+                }
 
                 // pygrep-hooks
                 if self.settings.enabled.contains(&CheckCode::PGH001) {
@@ -2344,12 +2839,21 @@ where
                 }
 
                 // pylint
+                if self.settings.enabled.contains(&CheckCode::PLC2801) {
+                    pylint::plugins::unnecessary_dunder_call(self, expr, func, args);
+                }
                 if self.settings.enabled.contains(&CheckCode::PLC3002) {
                     pylint::plugins::unnecessary_direct_lambda_call(self, expr, func);
                 }
                 if self.settings.enabled.contains(&CheckCode::PLR1722) {
                     pylint::plugins::use_sys_exit(self, func);
                 }
+                if self.settings.enabled.contains(&CheckCode::PLE1132) {
+                    pylint::plugins::repeated_keyword_argument(self, keywords);
+                }
+                if self.settings.enabled.contains(&CheckCode::PLW3301) {
+                    pylint::plugins::nested_min_max(self, expr, func, args, keywords);
+                }
 
                 // flake8-pytest-style
                 if self.settings.enabled.contains(&CheckCode::PT008) {
@@ -2384,6 +2888,11 @@ where
                             args, keywords,
                         ));
                 }
+
+                // furb
+                if self.settings.enabled.contains(&CheckCode::FURB105) {
+                    furb::plugins::use_math_inf(self, expr, func, args);
+                }
             }
             ExprKind::Dict { keys, values } => {
                 if self.settings.enabled.contains(&CheckCode::F601)
@@ -2432,6 +2941,20 @@ where
                 if self.settings.enabled.contains(&CheckCode::F541) {
                     pyflakes::plugins::f_string_missing_placeholders(expr, values, self);
                 }
+                if self.settings.enabled.contains(&CheckCode::S608) {
+                    if let Some(ExprKind::Constant {
+                        value: Constant::Str(value),
+                        ..
+                    }) = values.first().map(|value| &value.node)
+                    {
+                        if let Some(check) = flake8_bandit::checks::hardcoded_sql_expression(
+                            &Range::from_located(expr),
+                            value,
+                        ) {
+                            self.checks.push(check);
+                        }
+                    }
+                }
             }
             ExprKind::BinOp {
                 left,
@@ -2452,6 +2975,14 @@ where
                     ..
                 } = &left.node
                 {
+                    if self.settings.enabled.contains(&CheckCode::S608) {
+                        if let Some(check) = flake8_bandit::checks::hardcoded_sql_expression(
+                            &Range::from_located(expr),
+                            value,
+                        ) {
+                            self.checks.push(check);
+                        }
+                    }
                     if self.settings.enabled.contains(&CheckCode::F501)
                         || self.settings.enabled.contains(&CheckCode::F502)
                         || self.settings.enabled.contains(&CheckCode::F503)
@@ -2525,13 +3056,18 @@ where
                 }
             }
             ExprKind::BinOp {
-                op: Operator::Add, ..
+                op: Operator::Add,
+                left,
+                right,
             } => {
                 if self.settings.enabled.contains(&CheckCode::ISC003) {
                     if let Some(check) = flake8_implicit_str_concat::checks::explicit(expr) {
                         self.checks.push(check);
                     }
                 }
+                if self.settings.enabled.contains(&CheckCode::RUF006) {
+                    ruff::plugins::collection_literal_concatenation(self, expr, left, right);
+                }
             }
             ExprKind::UnaryOp { op, operand } => {
                 let check_not_in = self.settings.enabled.contains(&CheckCode::E713);
@@ -2639,6 +3175,18 @@ where
                 if self.settings.enabled.contains(&CheckCode::SIM300) {
                     flake8_simplify::plugins::yoda_conditions(self, expr, left, ops, comparators);
                 }
+
+                if self.settings.enabled.contains(&CheckCode::PLR2004) {
+                    pylint::plugins::magic_value_comparison(self, left, ops, comparators);
+                }
+
+                if self.settings.enabled.contains(&CheckCode::PLR0133) {
+                    pylint::plugins::comparison_of_constants(self, left, comparators);
+                }
+
+                if self.settings.enabled.contains(&CheckCode::PLR0124) {
+                    pylint::plugins::comparison_with_itself(self, left, comparators);
+                }
             }
             ExprKind::Constant {
                 value: Constant::Str(value),
@@ -2747,7 +3295,20 @@ where
                 }
                 self.push_scope(Scope::new(ScopeKind::Generator));
             }
-            ExprKind::GeneratorExp { .. } | ExprKind::DictComp { .. } => {
+            ExprKind::DictComp {
+                key, generators, ..
+            } => {
+                if self.settings.enabled.contains(&CheckCode::B023) {
+                    flake8_bugbear::plugins::function_uses_loop_variable(self, &Node::Expr(expr));
+                }
+                if self.settings.enabled.contains(&CheckCode::B032) {
+                    flake8_bugbear::plugins::dict_comprehension_with_static_key(
+                        self, key, generators,
+                    );
+                }
+                self.push_scope(Scope::new(ScopeKind::Generator));
+            }
+            ExprKind::GeneratorExp { .. } => {
                 if self.settings.enabled.contains(&CheckCode::B023) {
                     flake8_bugbear::plugins::function_uses_loop_variable(self, &Node::Expr(expr));
                 }
@@ -3017,6 +3578,8 @@ where
                         body,
                         excepthandler,
                         self.locator,
+                        self.patch(&CheckCode::E722),
+                        self.settings.pycodestyle.bare_except_use_base_exception,
                     ) {
                         self.checks.push(check);
                     }
@@ -3083,7 +3646,9 @@ where
                                 [*(self.scope_stack.last().expect("No current scope found"))];
                             &scope.values.remove(&name.as_str())
                         } {
-                            if self.bindings[*index].used.is_none() {
+                            if self.bindings[*index].used.is_none()
+                                && !self.settings.dummy_variable_rgx.is_match(name)
+                            {
                                 if self.settings.enabled.contains(&CheckCode::F841) {
                                     let mut check = Check::new(
                                         violations::UnusedVariable(name.to_string()),
@@ -3331,6 +3896,93 @@ impl<'a> Checker<'a> {
             .map(|index| &self.scopes[*index])
     }
 
+    /// Recursively walk a `match` case's pattern, visiting any embedded
+    /// expressions (e.g. `case Point(x=X):`) as loads and registering a
+    /// binding for every name the pattern captures (e.g. the `x` in
+    /// `case [x, *rest]:`, `case {"k": v}:`, or `case _ as x:`), so that
+    /// F841/F821 and friends see these bindings the same way they'd see an
+    /// ordinary assignment target.
+    fn bind_match_pattern<'b>(&mut self, pattern: &'b Pattern)
+    where
+        'b: 'a,
+    {
+        match &pattern.node {
+            PatternKind::MatchValue { value } => self.visit_expr(value),
+            PatternKind::MatchSingleton { .. } => {}
+            PatternKind::MatchSequence { patterns } => {
+                for pattern in patterns {
+                    self.bind_match_pattern(pattern);
+                }
+            }
+            PatternKind::MatchMapping {
+                keys,
+                patterns,
+                rest,
+                ..
+            } => {
+                for key in keys {
+                    self.visit_expr(key);
+                }
+                for pattern in patterns {
+                    self.bind_match_pattern(pattern);
+                }
+                if let Some(rest) = rest {
+                    self.bind_match_capture(rest, pattern);
+                }
+            }
+            PatternKind::MatchClass {
+                cls,
+                patterns,
+                kwd_patterns,
+                ..
+            } => {
+                self.visit_expr(cls);
+                for pattern in patterns {
+                    self.bind_match_pattern(pattern);
+                }
+                for pattern in kwd_patterns {
+                    self.bind_match_pattern(pattern);
+                }
+            }
+            PatternKind::MatchStar { name } => {
+                if let Some(name) = name {
+                    self.bind_match_capture(name, pattern);
+                }
+            }
+            PatternKind::MatchAs {
+                pattern: inner,
+                name,
+            } => {
+                if let Some(inner) = inner {
+                    self.bind_match_pattern(inner);
+                }
+                if let Some(name) = name {
+                    self.bind_match_capture(name, pattern);
+                }
+            }
+            PatternKind::MatchOr { patterns } => {
+                for pattern in patterns {
+                    self.bind_match_pattern(pattern);
+                }
+            }
+        }
+    }
+
+    fn bind_match_capture<'b>(&mut self, name: &'b str, pattern: &Pattern)
+    where
+        'b: 'a,
+    {
+        self.add_binding(
+            name,
+            Binding {
+                kind: BindingKind::Assignment,
+                used: None,
+                range: Range::from_located(pattern),
+                source: Some(self.current_stmt().clone()),
+            },
+        );
+    }
+
     fn add_binding<'b>(&mut self, name: &'b str, binding: Binding<'a>)
     where
         'b: 'a,
@@ -3738,7 +4390,7 @@ impl<'a> Checker<'a> {
         self.definitions.push((
             Definition {
                 kind: if self.path.ends_with("__init__.py") {
-                    DefinitionKind::Package
+                    DefinitionKind::Package(python_ast)
                 } else {
                     DefinitionKind::Module
                 },
@@ -3809,7 +4461,9 @@ impl<'a> Checker<'a> {
             match &stmt.node {
                 StmtKind::FunctionDef { body, args, .. }
                 | StmtKind::AsyncFunctionDef { body, args, .. } => {
+                    self.flake8_bugbear_function_body = Some(body);
                     self.visit_arguments(args);
+                    self.flake8_bugbear_function_body = None;
                     self.visit_body(body);
                 }
                 _ => unreachable!("Expected StmtKind::FunctionDef | StmtKind::AsyncFunctionDef"),
@@ -3910,15 +4564,42 @@ impl<'a> Checker<'a> {
                 });
 
             if self.settings.enabled.contains(&CheckCode::F822) {
-                if !scope.import_starred && !self.path.ends_with("__init__.py") {
-                    if let Some(all_binding) = all_binding {
-                        if let Some(names) = &all_names {
-                            for &name in names {
-                                if !scope.values.contains_key(name) {
-                                    checks.push(Check::new(
-                                        violations::UndefinedExport(name.to_string()),
-                                        all_binding.range,
-                                    ));
+                if !self.path.ends_with("__init__.py") {
+                    // If the module contains a star import, we can only check names against
+                    // `__all__` if we're able to resolve the star-imported module (e.g., it's a
+                    // first-party module we can find on disk) and read its exported names.
+                    // Otherwise, we don't know what the star import binds, so we bail out
+                    // entirely to avoid false positives.
+                    let star_imported_names: Option<Vec<String>> = if scope.import_starred {
+                        scope.values.get("*").and_then(|index| {
+                            match &self.bindings[*index].kind {
+                                BindingKind::StarImportation(level, module) => {
+                                    pyflakes::module::star_import_names(
+                                        self.path,
+                                        &self.settings.src,
+                                        *level,
+                                        module.as_deref(),
+                                    )
+                                }
+                                _ => None,
+                            }
+                        })
+                    } else {
+                        Some(Vec::new())
+                    };
+
+                    if let Some(star_imported_names) = star_imported_names {
+                        if let Some(all_binding) = all_binding {
+                            if let Some(names) = &all_names {
+                                for &name in names {
+                                    if !scope.values.contains_key(name)
+                                        && !star_imported_names.iter().any(|n| n == name)
+                                    {
+                                        checks.push(Check::new(
+                                            violations::UndefinedExport(name.to_string()),
+                                            all_binding.range,
+                                        ));
+                                    }
                                 }
                             }
                         }
@@ -4060,8 +4741,12 @@ impl<'a> Checker<'a> {
                     }
                 }
 
-                let ignore_init =
-                    self.settings.ignore_init_module_imports && self.path.ends_with("__init__.py");
+                // Unused imports in `__init__.py` files and type stubs are often
+                // intentional re-exports, so downgrade them to the "consider
+                // `__all__`" message rather than treating them as dead code.
+                let ignore_init = (self.settings.ignore_init_module_imports
+                    && self.path.ends_with("__init__.py"))
+                    || self.is_stub_file();
                 for ((defined_by, defined_in), unused_imports) in unused
                     .into_iter()
                     .sorted_by_key(|((defined_by, _), _)| defined_by.location)
@@ -4219,7 +4904,11 @@ impl<'a> Checker<'a> {
             }
 
             // pydocstyle
-            if enforce_docstrings {
+            //
+            // Stub files (`.pyi`) are not introspected at runtime and are
+            // conventionally left undocumented, so docstring enforcement is
+            // skipped for them entirely.
+            if enforce_docstrings && !self.is_stub_file() {
                 if definition.docstring.is_none() {
                     pydocstyle::plugins::not_missing(self, &definition, &visibility);
                     continue;
@@ -4327,6 +5016,23 @@ impl<'a> Checker<'a> {
                         self.settings.pydocstyle.convention.as_ref(),
                     );
                 }
+
+                // darglint
+                if self.settings.enabled.contains(&CheckCode::DAR201)
+                    || self.settings.enabled.contains(&CheckCode::DAR301)
+                    || self.settings.enabled.contains(&CheckCode::DAR401)
+                {
+                    darglint::plugins::docstring_matches_function(
+                        self,
+                        &docstring,
+                        self.settings.pydocstyle.convention.as_ref(),
+                    );
+                }
+
+                // flake8-doctests
+                if self.settings.enabled.contains(&CheckCode::DOC001) {
+                    flake8_doctests::plugins::doctest_syntax_errors(self, &docstring);
+                }
             }
         }
     }
@@ -4338,6 +5044,7 @@ impl<'a> Checker<'a> {
                     name,
                     located,
                     flake8_builtins::types::ShadowingType::Attribute,
+                    &self.settings.flake8_builtins.builtins_ignorelist,
                 ) {
                     self.checks.push(check);
                 }
@@ -4348,6 +5055,7 @@ impl<'a> Checker<'a> {
                     name,
                     located,
                     flake8_builtins::types::ShadowingType::Variable,
+                    &self.settings.flake8_builtins.builtins_ignorelist,
                 ) {
                     self.checks.push(check);
                 }
@@ -4361,6 +5069,7 @@ impl<'a> Checker<'a> {
                 name,
                 arg,
                 flake8_builtins::types::ShadowingType::Argument,
+                &self.settings.flake8_builtins.builtins_ignorelist,
             ) {
                 self.checks.push(check);
             }
@@ -4387,6 +5096,7 @@ pub fn check_ast(
         path,
         locator,
         stylist,
+        python_ast,
     );
     checker.push_scope(Scope::new(ScopeKind::Module));
     checker.bind_builtins();
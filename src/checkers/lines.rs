@@ -1,9 +1,10 @@
 //! Lint rules based on checking raw physical lines.
 
-use crate::pycodestyle::checks::{line_too_long, no_newline_at_end_of_file};
-use crate::pygrep_hooks::plugins::{blanket_noqa, blanket_type_ignore};
+use crate::pycodestyle::checks::{line_too_long, no_newline_at_end_of_file, trailing_whitespace};
+use crate::pygrep_hooks::plugins::{blanket_noqa, blanket_type_ignore, invalid_mock_access};
 use crate::pyupgrade::checks::unnecessary_coding_comment;
 use crate::registry::{Check, CheckCode};
+use crate::ruff::checks::mixed_line_endings;
 use crate::settings::{flags, Settings};
 
 pub fn check_lines(
@@ -17,8 +18,12 @@ pub fn check_lines(
     let enforce_unnecessary_coding_comment = settings.enabled.contains(&CheckCode::UP009);
     let enforce_line_too_long = settings.enabled.contains(&CheckCode::E501);
     let enforce_no_newline_at_end_of_file = settings.enabled.contains(&CheckCode::W292);
+    let enforce_trailing_whitespace = settings.enabled.contains(&CheckCode::W291)
+        || settings.enabled.contains(&CheckCode::W293);
     let enforce_blanket_type_ignore = settings.enabled.contains(&CheckCode::PGH003);
     let enforce_blanket_noqa = settings.enabled.contains(&CheckCode::PGH004);
+    let enforce_invalid_mock_access = settings.enabled.contains(&CheckCode::PGH005);
+    let enforce_mixed_line_endings = settings.enabled.contains(&CheckCode::RUF013);
 
     let mut commented_lines_iter = commented_lines.iter().peekable();
     for (index, line) in contents.lines().enumerate() {
@@ -61,6 +66,18 @@ pub fn check_lines(
                 checks.push(check);
             }
         }
+
+        if enforce_trailing_whitespace {
+            if let Some(check) = trailing_whitespace(index, line, settings, autofix) {
+                checks.push(check);
+            }
+        }
+
+        if enforce_invalid_mock_access {
+            if let Some(check) = invalid_mock_access(index, line) {
+                checks.push(check);
+            }
+        }
     }
 
     if enforce_no_newline_at_end_of_file {
@@ -73,6 +90,16 @@ pub fn check_lines(
         }
     }
 
+    if enforce_mixed_line_endings {
+        if let Some(check) = mixed_line_endings(
+            contents,
+            matches!(autofix, flags::Autofix::Enabled)
+                && settings.fixable.contains(&CheckCode::RUF013),
+        ) {
+            checks.push(check);
+        }
+    }
+
     checks
 }
 
@@ -19,11 +19,14 @@ pub fn check_noqa(
     noqa_line_for: &IntMap<usize, usize>,
     settings: &Settings,
     autofix: flags::Autofix,
+    noqa: flags::Noqa,
 ) {
     let mut noqa_directives: IntMap<usize, (Directive, Vec<&str>)> = IntMap::default();
     let mut ignored = vec![];
 
     let enforce_noqa = settings.enabled.contains(&CheckCode::RUF100);
+    let enforce_malformed_noqa = settings.enabled.contains(&CheckCode::RUF101);
+    let enforce_misplaced_noqa = settings.enabled.contains(&CheckCode::RUF102);
 
     let lines: Vec<&str> = contents.lines().collect();
     for lineno in commented_lines {
@@ -33,7 +36,7 @@ pub fn check_noqa(
             return;
         }
 
-        if enforce_noqa {
+        if enforce_noqa || enforce_malformed_noqa || enforce_misplaced_noqa {
             noqa_directives
                 .entry(lineno - 1)
                 .or_insert_with(|| (noqa::extract_noqa_directive(lines[lineno - 1]), vec![]));
@@ -66,7 +69,7 @@ pub fn check_noqa(
                             continue;
                         }
                     }
-                    (Directive::None, ..) => {}
+                    (Directive::None | Directive::Invalid(..), ..) => {}
                 }
             }
         }
@@ -89,17 +92,41 @@ pub fn check_noqa(
                         ignored.push(index);
                     }
                 }
-                (Directive::None, ..) => {}
+                (Directive::None | Directive::Invalid(..), ..) => {}
             }
         }
     }
 
-    // Enforce that the noqa directive was actually used (RUF100).
-    if enforce_noqa {
+    // Enforce that the noqa directive was actually used (RUF100), that any
+    // noqa-like directive actually parses (RUF101), and that it's placed on the line
+    // flake8 expects it to be on for continued logical lines (RUF102).
+    if enforce_noqa || enforce_malformed_noqa || enforce_misplaced_noqa {
         for (row, (directive, matches)) in noqa_directives {
+            if enforce_misplaced_noqa {
+                let bounds = match &directive {
+                    Directive::All(_, start, end) | Directive::Codes(_, start, end, ..) => {
+                        Some((*start, *end))
+                    }
+                    Directive::Invalid(..) | Directive::None => None,
+                };
+                if let Some((start, end)) = bounds {
+                    if let Some(&target) = noqa_line_for.get(&(row + 1)) {
+                        if target != row + 1 {
+                            checks.push(Check::new(
+                                violations::MisplacedNOQA(target),
+                                Range::new(
+                                    Location::new(row + 1, start),
+                                    Location::new(row + 1, end),
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+
             match directive {
                 Directive::All(spaces, start, end) => {
-                    if matches.is_empty() {
+                    if enforce_noqa && matches.is_empty() {
                         let mut check = Check::new(
                             violations::UnusedNOQA(None),
                             Range::new(Location::new(row + 1, start), Location::new(row + 1, end)),
@@ -115,7 +142,11 @@ pub fn check_noqa(
                         checks.push(check);
                     }
                 }
-                Directive::Codes(spaces, start, end, codes) => {
+                Directive::Codes(spaces, start, end, codes, sep) => {
+                    if !enforce_noqa {
+                        continue;
+                    }
+
                     let mut disabled_codes = vec![];
                     let mut unknown_codes = vec![];
                     let mut unmatched_codes = vec![];
@@ -178,7 +209,7 @@ pub fn check_noqa(
                                 ));
                             } else {
                                 check.amend(Fix::replacement(
-                                    format!("# noqa: {}", valid_codes.join(", ")),
+                                    format!("# noqa: {}", valid_codes.join(sep)),
                                     Location::new(row + 1, start),
                                     Location::new(row + 1, lines[row].chars().count()),
                                 ));
@@ -187,13 +218,38 @@ pub fn check_noqa(
                         checks.push(check);
                     }
                 }
+                Directive::Invalid(spaces, start, end) => {
+                    if enforce_malformed_noqa {
+                        let mut check = Check::new(
+                            violations::MalformedNOQA,
+                            Range::new(Location::new(row + 1, start), Location::new(row + 1, end)),
+                        );
+                        if matches!(autofix, flags::Autofix::Enabled)
+                            && settings.fixable.contains(check.kind.code())
+                        {
+                            check.amend(Fix::deletion(
+                                Location::new(row + 1, start - spaces),
+                                Location::new(row + 1, lines[row].chars().count()),
+                            ));
+                        }
+                        checks.push(check);
+                    }
+                }
                 Directive::None => {}
             }
         }
     }
 
-    ignored.sort_unstable();
-    for index in ignored.iter().rev() {
-        checks.swap_remove(*index);
+    if matches!(noqa, flags::Noqa::Ignored) {
+        // Surface the otherwise-suppressed checks instead of dropping them, so that
+        // `--ignore-noqa` can be used to audit what a `noqa` directive is hiding.
+        for index in ignored {
+            checks[index].is_suppressed = true;
+        }
+    } else {
+        ignored.sort_unstable();
+        for index in ignored.iter().rev() {
+            checks.swap_remove(*index);
+        }
     }
 }
@@ -28,6 +28,16 @@ pub fn check_tokens(
     let enforce_invalid_escape_sequence = settings.enabled.contains(&CheckCode::W605);
     let enforce_implicit_string_concatenation = settings.enabled.contains(&CheckCode::ISC001)
         || settings.enabled.contains(&CheckCode::ISC002);
+    let enforce_implicit_string_concat_in_collection =
+        settings.enabled.contains(&CheckCode::RUF005);
+    let enforce_extraneous_whitespace = settings.enabled.contains(&CheckCode::E201)
+        || settings.enabled.contains(&CheckCode::E202)
+        || settings.enabled.contains(&CheckCode::E211)
+        || settings.enabled.contains(&CheckCode::E231);
+    let enforce_indentation =
+        settings.enabled.contains(&CheckCode::E111) || settings.enabled.contains(&CheckCode::E117);
+    let enforce_binary_operator_line_breaks =
+        settings.enabled.contains(&CheckCode::W503) || settings.enabled.contains(&CheckCode::W504);
 
     let mut state_machine = StateMachine::default();
     for &(start, ref tok, end) in tokens.iter().flatten() {
@@ -67,7 +77,8 @@ pub fn check_tokens(
                     start,
                     end,
                     is_docstring,
-                    &settings.flake8_quotes,
+                    settings,
+                    autofix,
                 ) {
                     if settings.enabled.contains(check.kind.code()) {
                         checks.push(check);
@@ -110,5 +121,29 @@ pub fn check_tokens(
         );
     }
 
+    // RUF005
+    if enforce_implicit_string_concat_in_collection {
+        checks.extend(ruff::checks::implicit_string_concat_in_collection(tokens));
+    }
+
+    // E201, E202, E211, E231
+    if enforce_extraneous_whitespace {
+        checks.extend(pycodestyle::checks::extraneous_whitespace(
+            tokens, settings, autofix,
+        ));
+    }
+
+    // E111, E117
+    if enforce_indentation {
+        checks.extend(pycodestyle::checks::indentation(tokens, settings));
+    }
+
+    // W503, W504
+    if enforce_binary_operator_line_breaks {
+        checks.extend(pycodestyle::checks::break_around_binary_operators(
+            tokens, settings,
+        ));
+    }
+
     checks
 }
@@ -16,7 +16,7 @@ use crate::settings::types::{
 #[command(version)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Cli {
-    #[arg(required_unless_present_any = ["clean", "explain", "generate_shell_completion"])]
+    #[arg(required_unless_present_any = ["clean", "explain", "explain_all", "linter", "generate_shell_completion"])]
     pub files: Vec<PathBuf>,
     /// Path to the `pyproject.toml` or `ruff.toml` file to use for
     /// configuration.
@@ -33,8 +33,14 @@ pub struct Cli {
     #[arg(short, long, group = "verbosity")]
     pub silent: bool,
     /// Exit with status code "0", even upon detecting errors.
-    #[arg(short, long)]
+    #[arg(short, long, conflicts_with = "exit_non_zero_on_fix")]
     pub exit_zero: bool,
+    /// Exit with a non-zero status code if any fixes were applied, in
+    /// addition to any other non-zero exit conditions. Useful for CI
+    /// policies that want to flag fixed-up code for review, even when no
+    /// violations remain.
+    #[arg(long)]
+    pub exit_non_zero_on_fix: bool,
     /// Run in watch mode by re-running whenever files change.
     #[arg(short, long)]
     pub watch: bool,
@@ -56,23 +62,56 @@ pub struct Cli {
     /// Disable cache reads.
     #[arg(short, long)]
     pub no_cache: bool,
+    /// Print the cache's location, on-disk size, and hit/miss counts from
+    /// this run before exiting.
+    #[arg(long)]
+    pub cache_info: bool,
+    /// Report violations that would otherwise be suppressed by a `noqa`
+    /// directive, marking them as suppressed rather than hiding them. Useful
+    /// for auditing how much is hidden behind suppressions. Implies
+    /// `--no-cache`, and can't be combined with `--fix`, `--fix-only`, or
+    /// `--diff`.
+    #[arg(
+        long,
+        conflicts_with = "fix",
+        conflicts_with = "fix_only",
+        conflicts_with = "diff"
+    )]
+    pub ignore_noqa: bool,
+    /// Exit after reporting on the first N violations. Useful for very
+    /// broken trees, where reporting on every violation is more output than
+    /// is actionable.
+    #[arg(long)]
+    pub max_violations: Option<usize>,
+    /// Report the time spent running checks from each lint source (tokens,
+    /// AST, lines, and imports), summed across every file, at the end of the
+    /// run. Useful for tracking down which rules are slow on a given
+    /// codebase.
+    #[arg(long, env = "RUFF_TIMING")]
+    pub timing: bool,
+    /// Emit violations in the order files finish checking, instead of
+    /// sorting them by file path. Checks run in parallel, so this makes
+    /// output ordering nondeterministic; useful for scripts that only care
+    /// about throughput and will do their own sorting (or none at all).
+    #[arg(long)]
+    pub no_sort: bool,
     /// Ignore all configuration files.
     #[arg(long, conflicts_with = "config")]
     pub isolated: bool,
     /// Comma-separated list of error codes to enable (or ALL, to enable all
     /// checks).
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, value_delimiter = ',', value_enum, hide_possible_values = true)]
     pub select: Option<Vec<CheckCodePrefix>>,
     /// Like --select, but adds additional error codes on top of the selected
     /// ones.
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, value_delimiter = ',', value_enum, hide_possible_values = true)]
     pub extend_select: Option<Vec<CheckCodePrefix>>,
     /// Comma-separated list of error codes to disable.
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, value_delimiter = ',', value_enum, hide_possible_values = true)]
     pub ignore: Option<Vec<CheckCodePrefix>>,
     /// Like --ignore, but adds additional error codes on top of the ignored
     /// ones.
-    #[arg(long, value_delimiter = ',')]
+    #[arg(long, value_delimiter = ',', value_enum, hide_possible_values = true)]
     pub extend_ignore: Option<Vec<CheckCodePrefix>>,
     /// List of paths, used to exclude files and/or directories from checks.
     #[arg(long, value_delimiter = ',')]
@@ -85,6 +124,10 @@ pub struct Cli {
     /// when autofix itself is enabled (e.g., via `--fix`).
     #[arg(long, value_delimiter = ',')]
     pub fixable: Option<Vec<CheckCodePrefix>>,
+    /// Like --fixable, but adds additional error codes on top of the fixable
+    /// ones.
+    #[arg(long, value_delimiter = ',')]
+    pub extend_fixable: Option<Vec<CheckCodePrefix>>,
     /// List of error codes to treat as ineligible for autofix. Only applicable
     /// when autofix itself is enabled (e.g., via `--fix`).
     #[arg(long, value_delimiter = ',')]
@@ -123,6 +166,12 @@ pub struct Cli {
     update_check: bool,
     #[clap(long, overrides_with("update_check"), hide = true)]
     no_update_check: bool,
+    /// Enable preview mode; checks classified as preview will be enabled in
+    /// addition to the current set of stable checks.
+    #[arg(long, overrides_with("no_preview"))]
+    preview: bool,
+    #[clap(long, overrides_with("preview"), hide = true)]
+    no_preview: bool,
     /// Regular expression matching the name of dummy variables.
     #[arg(long)]
     pub dummy_variable_rgx: Option<Regex>,
@@ -142,6 +191,8 @@ pub struct Cli {
         // conflicts_with = "add_noqa",
         conflicts_with = "clean",
         conflicts_with = "explain",
+        conflicts_with = "explain_all",
+        conflicts_with = "linter",
         conflicts_with = "generate_shell_completion",
         conflicts_with = "show_files",
         conflicts_with = "show_settings",
@@ -157,6 +208,8 @@ pub struct Cli {
         conflicts_with = "add_noqa",
         // conflicts_with = "clean",
         conflicts_with = "explain",
+        conflicts_with = "explain_all",
+        conflicts_with = "linter",
         conflicts_with = "generate_shell_completion",
         conflicts_with = "show_files",
         conflicts_with = "show_settings",
@@ -172,6 +225,8 @@ pub struct Cli {
         conflicts_with = "add_noqa",
         conflicts_with = "clean",
         // conflicts_with = "explain",
+        conflicts_with = "explain_all",
+        conflicts_with = "linter",
         conflicts_with = "generate_shell_completion",
         conflicts_with = "show_files",
         conflicts_with = "show_settings",
@@ -180,6 +235,43 @@ pub struct Cli {
         conflicts_with = "watch",
     )]
     pub explain: Option<CheckCode>,
+    /// Explain every rule, dumping the full rule registry as machine-readable
+    /// metadata (code, name, origin, summary, fixable, default-enabled).
+    #[arg(
+        long,
+        // Fake subcommands.
+        conflicts_with = "add_noqa",
+        conflicts_with = "clean",
+        conflicts_with = "explain",
+        // conflicts_with = "explain_all",
+        conflicts_with = "linter",
+        conflicts_with = "generate_shell_completion",
+        conflicts_with = "show_files",
+        conflicts_with = "show_settings",
+        // Unsupported default-command arguments.
+        conflicts_with = "stdin_filename",
+        conflicts_with = "watch",
+    )]
+    pub explain_all: bool,
+    /// List every supported linter, its code prefixes, and how many of its
+    /// rules Ruff implements, for assessing coverage when migrating from a
+    /// flake8 plugin stack.
+    #[arg(
+        long,
+        // Fake subcommands.
+        conflicts_with = "add_noqa",
+        conflicts_with = "clean",
+        conflicts_with = "explain",
+        conflicts_with = "explain_all",
+        // conflicts_with = "linter",
+        conflicts_with = "generate_shell_completion",
+        conflicts_with = "show_files",
+        conflicts_with = "show_settings",
+        // Unsupported default-command arguments.
+        conflicts_with = "stdin_filename",
+        conflicts_with = "watch",
+    )]
+    pub linter: bool,
     /// Generate shell completion
     #[arg(
         long,
@@ -189,6 +281,8 @@ pub struct Cli {
         conflicts_with = "add_noqa",
         conflicts_with = "clean",
         conflicts_with = "explain",
+        conflicts_with = "explain_all",
+        conflicts_with = "linter",
         // conflicts_with = "generate_shell_completion",
         conflicts_with = "show_files",
         conflicts_with = "show_settings",
@@ -204,6 +298,8 @@ pub struct Cli {
         conflicts_with = "add_noqa",
         conflicts_with = "clean",
         conflicts_with = "explain",
+        conflicts_with = "explain_all",
+        conflicts_with = "linter",
         conflicts_with = "generate_shell_completion",
         // conflicts_with = "show_files",
         conflicts_with = "show_settings",
@@ -219,6 +315,8 @@ pub struct Cli {
         conflicts_with = "add_noqa",
         conflicts_with = "clean",
         conflicts_with = "explain",
+        conflicts_with = "explain_all",
+        conflicts_with = "linter",
         conflicts_with = "generate_shell_completion",
         conflicts_with = "show_files",
         // conflicts_with = "show_settings",
@@ -236,20 +334,28 @@ impl Cli {
         (
             Arguments {
                 add_noqa: self.add_noqa,
+                cache_info: self.cache_info,
                 clean: self.clean,
                 config: self.config,
                 diff: self.diff,
+                exit_non_zero_on_fix: self.exit_non_zero_on_fix,
                 exit_zero: self.exit_zero,
                 explain: self.explain,
+                explain_all: self.explain_all,
                 files: self.files,
                 generate_shell_completion: self.generate_shell_completion,
+                ignore_noqa: self.ignore_noqa,
                 isolated: self.isolated,
+                linter: self.linter,
+                max_violations: self.max_violations,
                 no_cache: self.no_cache,
+                no_sort: self.no_sort,
                 quiet: self.quiet,
                 show_files: self.show_files,
                 show_settings: self.show_settings,
                 silent: self.silent,
                 stdin_filename: self.stdin_filename,
+                timing: self.timing,
                 verbose: self.verbose,
                 watch: self.watch,
             },
@@ -258,12 +364,14 @@ impl Cli {
                 exclude: self.exclude,
                 extend_exclude: self.extend_exclude,
                 extend_ignore: self.extend_ignore,
+                extend_fixable: self.extend_fixable,
                 extend_select: self.extend_select,
                 fixable: self.fixable,
                 ignore: self.ignore,
                 line_length: self.line_length,
                 max_complexity: self.max_complexity,
                 per_file_ignores: self.per_file_ignores,
+                preview: resolve_bool_arg(self.preview, self.no_preview),
                 respect_gitignore: resolve_bool_arg(
                     self.respect_gitignore,
                     self.no_respect_gitignore,
@@ -298,20 +406,28 @@ fn resolve_bool_arg(yes: bool, no: bool) -> Option<bool> {
 #[allow(clippy::struct_excessive_bools)]
 pub struct Arguments {
     pub add_noqa: bool,
+    pub cache_info: bool,
     pub clean: bool,
     pub config: Option<PathBuf>,
     pub diff: bool,
+    pub exit_non_zero_on_fix: bool,
     pub exit_zero: bool,
     pub explain: Option<CheckCode>,
+    pub explain_all: bool,
     pub files: Vec<PathBuf>,
     pub generate_shell_completion: Option<clap_complete_command::Shell>,
+    pub ignore_noqa: bool,
     pub isolated: bool,
+    pub linter: bool,
+    pub max_violations: Option<usize>,
     pub no_cache: bool,
+    pub no_sort: bool,
     pub quiet: bool,
     pub show_files: bool,
     pub show_settings: bool,
     pub silent: bool,
     pub stdin_filename: Option<PathBuf>,
+    pub timing: bool,
     pub verbose: bool,
     pub watch: bool,
 }
@@ -324,12 +440,14 @@ pub struct Overrides {
     pub exclude: Option<Vec<FilePattern>>,
     pub extend_exclude: Option<Vec<FilePattern>>,
     pub extend_ignore: Option<Vec<CheckCodePrefix>>,
+    pub extend_fixable: Option<Vec<CheckCodePrefix>>,
     pub extend_select: Option<Vec<CheckCodePrefix>>,
     pub fixable: Option<Vec<CheckCodePrefix>>,
     pub ignore: Option<Vec<CheckCodePrefix>>,
     pub line_length: Option<usize>,
     pub max_complexity: Option<usize>,
     pub per_file_ignores: Option<Vec<PatternPrefixPair>>,
+    pub preview: Option<bool>,
     pub respect_gitignore: Option<bool>,
     pub select: Option<Vec<CheckCodePrefix>>,
     pub show_source: Option<bool>,
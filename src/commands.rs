@@ -1,9 +1,11 @@
 use std::fs::remove_dir_all;
 use std::io::{self, Read};
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Instant;
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use colored::Colorize;
 use ignore::Error;
 use itertools::Itertools;
@@ -22,12 +24,26 @@ use crate::iterators::par_iter;
 use crate::linter::{add_noqa_to_path, lint_path, lint_stdin, Diagnostics};
 use crate::logging::LogLevel;
 use crate::message::Message;
-use crate::registry::CheckCode;
+use crate::registry::{CheckCategory, CheckCode};
 use crate::resolver::{FileDiscovery, PyprojectDiscovery};
-use crate::settings::flags;
+use crate::settings::configuration::Configuration;
 use crate::settings::types::SerializationFormat;
+use crate::settings::{flags, Settings};
 use crate::{cache, fs, one_time_warning, packages, resolver, violations};
 
+/// Extract a human-readable message from a caught panic payload, falling
+/// back to a generic description if the payload isn't a `&str` or `String`
+/// (as is the case for, e.g., `panic!("{}", err)` versus a bare `unwrap()`).
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "a rule panicked while checking this file".to_string()
+    }
+}
+
 /// Run the linter over a collection of files.
 pub fn run(
     files: &[PathBuf],
@@ -35,7 +51,9 @@ pub fn run(
     file_strategy: &FileDiscovery,
     overrides: &Overrides,
     cache: flags::Cache,
+    noqa: flags::Noqa,
     autofix: fixer::Mode,
+    sort: flags::Sort,
 ) -> Result<Diagnostics> {
     // Collect all the Python files to check.
     let start = Instant::now();
@@ -91,6 +109,12 @@ pub fn run(
     );
 
     let start = Instant::now();
+    // Panics inside `lint_path` are caught below and reported as diagnostics,
+    // so suppress the default panic hook's backtrace printing for the
+    // duration of the run to avoid spamming stderr with noise the user can't
+    // act on (the diagnostic already names the offending file).
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
     let mut diagnostics: Diagnostics = par_iter(&paths)
         .map(|entry| {
             match entry {
@@ -101,8 +125,16 @@ pub fn run(
                         .and_then(|parent| package_roots.get(parent))
                         .and_then(|package| *package);
                     let settings = resolver.resolve(path, pyproject_strategy);
-                    lint_path(path, package, settings, cache, autofix)
-                        .map_err(|e| (Some(path.to_owned()), e.to_string()))
+                    // Isolate each file's checks: a panic in a single rule (a bug in
+                    // that rule, most likely) shouldn't take down the whole run and
+                    // deny every other file a report.
+                    catch_unwind(AssertUnwindSafe(|| {
+                        lint_path(path, package, settings, cache, noqa, autofix)
+                    }))
+                    .unwrap_or_else(|panic| {
+                        Err(anyhow!("internal error: {}", panic_message(&panic)))
+                    })
+                    .map_err(|e| (Some(path.to_owned()), e.to_string()))
                 }
                 Err(e) => Err((
                     if let Error::WithPath { path, .. } = e {
@@ -116,23 +148,29 @@ pub fn run(
             }
             .unwrap_or_else(|(path, message)| {
                 if let Some(path) = &path {
+                    let failure = format!("{}: {message}", path.to_string_lossy());
                     let settings = resolver.resolve(path, pyproject_strategy);
-                    if settings.enabled.contains(&CheckCode::E902) {
+                    let mut diagnostics = if settings.enabled.contains(&CheckCode::E902) {
                         Diagnostics::new(vec![Message {
                             kind: violations::IOError(message).into(),
                             location: Location::default(),
                             end_location: Location::default(),
                             fix: None,
-                            filename: path.to_string_lossy().to_string(),
+                            filename: Arc::from(path.to_string_lossy().as_ref()),
                             source: None,
+                            is_suppressed: false,
                         }])
                     } else {
-                        error!("Failed to check {}: {message}", path.to_string_lossy());
+                        error!("Failed to check {}: {failure}", path.to_string_lossy());
                         Diagnostics::default()
-                    }
+                    };
+                    diagnostics.failures.push(failure);
+                    diagnostics
                 } else {
                     error!("{message}");
-                    Diagnostics::default()
+                    let mut diagnostics = Diagnostics::default();
+                    diagnostics.failures.push(message);
+                    diagnostics
                 }
             })
         })
@@ -140,8 +178,11 @@ pub fn run(
             acc += item;
             acc
         });
+    std::panic::set_hook(previous_hook);
 
-    diagnostics.messages.sort_unstable();
+    if matches!(sort, flags::Sort::Enabled) {
+        diagnostics.messages.sort_unstable();
+    }
     let duration = start.elapsed();
     debug!("Checked files in: {:?}", duration);
 
@@ -161,6 +202,7 @@ pub fn run_stdin(
     pyproject_strategy: &PyprojectDiscovery,
     file_strategy: &FileDiscovery,
     overrides: &Overrides,
+    noqa: flags::Noqa,
     autofix: fixer::Mode,
 ) -> Result<Diagnostics> {
     if let Some(filename) = filename {
@@ -176,7 +218,7 @@ pub fn run_stdin(
         .and_then(Path::parent)
         .and_then(packages::detect_package_root);
     let stdin = read_from_stdin()?;
-    let mut diagnostics = lint_stdin(filename, package_root, &stdin, settings, autofix)?;
+    let mut diagnostics = lint_stdin(filename, package_root, &stdin, settings, noqa, autofix)?;
     diagnostics.messages.sort_unstable();
     Ok(diagnostics)
 }
@@ -332,6 +374,134 @@ pub fn explain(code: &CheckCode, format: &SerializationFormat) -> Result<()> {
         SerializationFormat::Gitlab => {
             bail!("`--explain` does not support GitLab format")
         }
+        SerializationFormat::Sarif => {
+            bail!("`--explain` does not support SARIF format")
+        }
+    };
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct RuleMetadata<'a> {
+    code: &'a str,
+    name: &'a str,
+    origin: &'a str,
+    summary: String,
+    fixable: bool,
+    #[serde(rename = "default-enabled")]
+    default_enabled: bool,
+}
+
+/// Explain every `CheckCode` in the registry, for use by documentation sites
+/// and editor plugins that need to stay in sync with Ruff's rule set.
+pub fn explain_all(format: &SerializationFormat) -> Result<()> {
+    use strum::IntoEnumIterator;
+
+    // Resolve Ruff's default settings, so we can report which codes are
+    // enabled out of the box.
+    let default_settings = Settings::from_configuration(Configuration::default(), &path_dedot::CWD)?;
+
+    let rules: Vec<RuleMetadata> = CheckCode::iter()
+        .map(|code| {
+            let kind = code.kind();
+            RuleMetadata {
+                code: code.as_ref(),
+                name: kind.as_ref(),
+                origin: code.category().title(),
+                summary: kind.summary(),
+                fixable: kind.fixable(),
+                default_enabled: default_settings.enabled.contains(&code),
+            }
+        })
+        .collect();
+
+    match format {
+        SerializationFormat::Text | SerializationFormat::Grouped => {
+            for rule in rules {
+                println!(
+                    "{} ({}): {}{}",
+                    rule.code,
+                    rule.origin,
+                    rule.summary,
+                    if rule.fixable { " [fixable]" } else { "" },
+                );
+            }
+        }
+        SerializationFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&rules)?);
+        }
+        SerializationFormat::Junit => {
+            bail!("`--explain-all` does not support junit format")
+        }
+        SerializationFormat::Github => {
+            bail!("`--explain-all` does not support GitHub format")
+        }
+        SerializationFormat::Gitlab => {
+            bail!("`--explain-all` does not support GitLab format")
+        }
+        SerializationFormat::Sarif => {
+            bail!("`--explain-all` does not support SARIF format")
+        }
+    };
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct LinterMetadata {
+    name: &'static str,
+    prefixes: Vec<String>,
+    rules_implemented: usize,
+}
+
+/// List every supported linter (i.e., `CheckCategory`), its code prefixes,
+/// and how many of its rules Ruff implements, to help users assess coverage
+/// when migrating from a flake8 plugin stack.
+pub fn show_linters(format: &SerializationFormat) -> Result<()> {
+    use strum::IntoEnumIterator;
+
+    let linters: Vec<LinterMetadata> = CheckCategory::iter()
+        .map(|category| {
+            let rules_implemented = CheckCode::iter()
+                .filter(|code| code.category() == category)
+                .count();
+            LinterMetadata {
+                name: category.title(),
+                prefixes: category
+                    .codes()
+                    .iter()
+                    .map(|prefix| prefix.as_ref().to_string())
+                    .collect(),
+                rules_implemented,
+            }
+        })
+        .collect();
+
+    match format {
+        SerializationFormat::Text | SerializationFormat::Grouped => {
+            for linter in linters {
+                println!(
+                    "{} ({}): {} rule(s) implemented",
+                    linter.name,
+                    linter.prefixes.join(", "),
+                    linter.rules_implemented,
+                );
+            }
+        }
+        SerializationFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&linters)?);
+        }
+        SerializationFormat::Junit => {
+            bail!("`--linter` does not support junit format")
+        }
+        SerializationFormat::Github => {
+            bail!("`--linter` does not support GitHub format")
+        }
+        SerializationFormat::Gitlab => {
+            bail!("`--linter` does not support GitLab format")
+        }
+        SerializationFormat::Sarif => {
+            bail!("`--linter` does not support SARIF format")
+        }
     };
     Ok(())
 }
@@ -0,0 +1,99 @@
+use itertools::Itertools;
+use rustpython_ast::StmtKind;
+
+use crate::ast::types::Range;
+use crate::ast::visitor::Visitor;
+use crate::ast::whitespace::LinesWithTrailingNewline;
+use crate::checkers::ast::Checker;
+use crate::darglint::visitor::BodyVisitor;
+use crate::docstrings::definition::{DefinitionKind, Docstring};
+use crate::docstrings::sections::{section_contexts, SectionContext};
+use crate::docstrings::styles::SectionStyle;
+use crate::pydocstyle::settings::Convention;
+use crate::registry::{Check, CheckCode};
+use crate::violations;
+
+/// Collect the lowercase names of the sections present in a docstring, using
+/// the same Google/NumPy convention-detection logic as `pydocstyle::plugins::sections`.
+fn section_names(body: &str, convention: Option<&Convention>) -> Vec<String> {
+    let lines: Vec<&str> = LinesWithTrailingNewline::from(body).collect();
+    if lines.len() < 2 {
+        return Vec::new();
+    }
+
+    let to_names = |contexts: Vec<SectionContext>| {
+        contexts
+            .iter()
+            .map(|context| context.section_name.to_lowercase())
+            .collect()
+    };
+
+    match convention {
+        Some(Convention::Google) => to_names(section_contexts(&lines, &SectionStyle::Google)),
+        Some(Convention::Numpy) => to_names(section_contexts(&lines, &SectionStyle::Numpy)),
+        Some(Convention::Pep257) | None => {
+            let numpy_contexts = section_contexts(&lines, &SectionStyle::Numpy);
+            if numpy_contexts.is_empty() {
+                to_names(section_contexts(&lines, &SectionStyle::Google))
+            } else {
+                to_names(numpy_contexts)
+            }
+        }
+    }
+}
+
+/// DAR201, DAR301, DAR401
+pub fn docstring_matches_function(
+    checker: &mut Checker,
+    docstring: &Docstring,
+    convention: Option<&Convention>,
+) {
+    let (DefinitionKind::Function(stmt)
+    | DefinitionKind::NestedFunction(stmt)
+    | DefinitionKind::Method(stmt)) = docstring.kind
+    else {
+        return;
+    };
+    let body = match &stmt.node {
+        StmtKind::FunctionDef { body, .. } | StmtKind::AsyncFunctionDef { body, .. } => body,
+        _ => return,
+    };
+
+    let mut visitor = BodyVisitor::default();
+    for body_stmt in body {
+        visitor.visit_stmt(body_stmt);
+    }
+
+    let section_names = section_names(docstring.body, convention);
+
+    if checker.settings.enabled.contains(&CheckCode::DAR201)
+        && visitor.returns_value
+        && !section_names.iter().any(|name| name == "return" || name == "returns")
+    {
+        checker.checks.push(Check::new(
+            violations::UndocumentedReturn,
+            Range::from_located(docstring.expr),
+        ));
+    }
+
+    if checker.settings.enabled.contains(&CheckCode::DAR301)
+        && visitor.yields
+        && !section_names.iter().any(|name| name == "yield" || name == "yields")
+    {
+        checker.checks.push(Check::new(
+            violations::UndocumentedYield,
+            Range::from_located(docstring.expr),
+        ));
+    }
+
+    if checker.settings.enabled.contains(&CheckCode::DAR401) {
+        for exception in visitor.raised_exceptions.iter().sorted() {
+            if !docstring.body.contains(*exception) {
+                checker.checks.push(Check::new(
+                    violations::UndocumentedException((*exception).to_string()),
+                    Range::from_located(docstring.expr),
+                ));
+            }
+        }
+    }
+}
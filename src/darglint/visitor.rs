@@ -0,0 +1,66 @@
+use rustc_hash::FxHashSet;
+use rustpython_ast::{Constant, Expr, ExprKind, Stmt, StmtKind};
+
+use crate::ast::visitor;
+use crate::ast::visitor::Visitor;
+
+/// Summarizes the `return`, `yield`, and `raise` statements in a function
+/// body, without descending into nested functions or classes (which document
+/// their own contracts separately).
+#[derive(Default)]
+pub struct BodyVisitor<'a> {
+    pub returns_value: bool,
+    pub yields: bool,
+    pub raised_exceptions: FxHashSet<&'a str>,
+}
+
+impl<'a> Visitor<'a> for BodyVisitor<'a> {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        match &stmt.node {
+            StmtKind::FunctionDef { .. }
+            | StmtKind::AsyncFunctionDef { .. }
+            | StmtKind::ClassDef { .. } => {
+                // Don't recurse.
+            }
+            StmtKind::Return {
+                value: Some(value), ..
+            } => {
+                if !matches!(
+                    value.node,
+                    ExprKind::Constant {
+                        value: Constant::None,
+                        ..
+                    }
+                ) {
+                    self.returns_value = true;
+                }
+                visitor::walk_stmt(self, stmt);
+            }
+            StmtKind::Raise { exc: Some(exc), .. } => {
+                if let Some(name) = exception_name(exc) {
+                    self.raised_exceptions.insert(name);
+                }
+                visitor::walk_stmt(self, stmt);
+            }
+            _ => visitor::walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if matches!(expr.node, ExprKind::Yield { .. } | ExprKind::YieldFrom { .. }) {
+            self.yields = true;
+        }
+        visitor::walk_expr(self, expr);
+    }
+}
+
+/// Return the simple name of a raised exception, e.g. `ValueError` for both
+/// `raise ValueError` and `raise ValueError("...")`.
+fn exception_name(exc: &Expr) -> Option<&str> {
+    match &exc.node {
+        ExprKind::Name { id, .. } => Some(id),
+        ExprKind::Attribute { attr, .. } => Some(attr),
+        ExprKind::Call { func, .. } => exception_name(func),
+        _ => None,
+    }
+}
@@ -70,10 +70,18 @@ pub fn extract_commented_lines(lxr: &[LexResult]) -> Vec<usize> {
 /// Extract a mapping from logical line to noqa line.
 pub fn extract_noqa_line_for(lxr: &[LexResult]) -> IntMap<usize, usize> {
     let mut noqa_line_for: IntMap<usize, usize> = IntMap::default();
+    // The row of the first token of the logical line currently being scanned, reset
+    // every time we get back to the top level at the end of a logical line. This lets
+    // us map every physical line of a continued logical line (whether continued via
+    // an implicit parenthesized/bracketed expression or via a trailing backslash) to
+    // the line flake8 expects a `noqa` directive to live on: the last one.
+    let mut depth: u32 = 0;
+    let mut line_start: Option<usize> = None;
     for (start, tok, end) in lxr.iter().flatten() {
         if matches!(tok, Tok::EndOfFile) {
             break;
         }
+
         // For multi-line strings, we expect `noqa` directives on the last line of the
         // string.
         if matches!(tok, Tok::String { .. }) && end.row() > start.row() {
@@ -81,6 +89,26 @@ pub fn extract_noqa_line_for(lxr: &[LexResult]) -> IntMap<usize, usize> {
                 noqa_line_for.insert(i, end.row());
             }
         }
+
+        if line_start.is_none() {
+            line_start = Some(start.row());
+        }
+
+        match tok {
+            Tok::Lpar | Tok::Lsqb | Tok::Lbrace => depth += 1,
+            Tok::Rpar | Tok::Rsqb | Tok::Rbrace => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+
+        if depth == 0 && matches!(tok, Tok::Newline) {
+            if let Some(first) = line_start.take() {
+                if end.row() > first {
+                    for i in first..end.row() {
+                        noqa_line_for.insert(i, end.row());
+                    }
+                }
+            }
+        }
     }
     noqa_line_for
 }
@@ -234,6 +262,34 @@ z = x + 1",
             extract_noqa_line_for(&lxr),
             IntMap::from_iter([(2, 5), (3, 5), (4, 5)])
         );
+
+        // An implicit, parenthesized continuation maps every line but the last to the
+        // last, just like a multi-line string.
+        let lxr: Vec<LexResult> = lexer::make_tokenizer(
+            "x = foo(
+    1,
+    2,
+)
+y = 2",
+        )
+        .collect();
+        assert_eq!(
+            extract_noqa_line_for(&lxr),
+            IntMap::from_iter([(1, 4), (2, 4), (3, 4)])
+        );
+
+        // Same for a backslash continuation.
+        let lxr: Vec<LexResult> = lexer::make_tokenizer(
+            "x = 1 + \\
+    2 + \\
+    3
+y = 2",
+        )
+        .collect();
+        assert_eq!(
+            extract_noqa_line_for(&lxr),
+            IntMap::from_iter([(1, 3), (2, 3)])
+        );
     }
 
     #[test]
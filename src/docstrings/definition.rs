@@ -1,11 +1,11 @@
 use std::borrow::Cow;
 
-use rustpython_ast::{Expr, Stmt};
+use rustpython_ast::{Expr, Stmt, Suite};
 
 #[derive(Debug, Clone)]
 pub enum DefinitionKind<'a> {
     Module,
-    Package,
+    Package(&'a Suite),
     Class(&'a Stmt),
     NestedClass(&'a Stmt),
     Function(&'a Stmt),
@@ -9,6 +9,12 @@ pub(crate) struct SectionContext<'a> {
     pub(crate) following_lines: &'a [&'a str],
     pub(crate) is_last_section: bool,
     pub(crate) original_index: usize,
+    /// The docstring convention (Google or NumPy) under which this section was
+    /// identified, so that downstream consumers don't need to re-derive it.
+    pub(crate) style: SectionStyle,
+    /// The leading whitespace of the section header line, i.e. the indentation
+    /// that entries within the section are expected to share.
+    pub(crate) indentation: &'a str,
 }
 
 fn suspected_as_section(line: &str, style: &SectionStyle) -> bool {
@@ -70,6 +76,8 @@ pub(crate) fn section_contexts<'a>(
             following_lines: &lines[lineno + 1..],
             original_index: lineno,
             is_last_section: false,
+            style: *style,
+            indentation: whitespace::leading_space(lines[lineno]),
         };
         if is_docstring_section(&context) {
             contexts.push(context);
@@ -91,6 +99,8 @@ pub(crate) fn section_contexts<'a>(
             },
             original_index: context.original_index,
             is_last_section: end.is_none(),
+            style: context.style,
+            indentation: context.indentation,
         });
         end = Some(next_end);
     }
@@ -4,6 +4,7 @@ use rustc_hash::FxHashSet;
 use crate::docstrings::google::{GOOGLE_SECTION_NAMES, LOWERCASE_GOOGLE_SECTION_NAMES};
 use crate::docstrings::numpy::{LOWERCASE_NUMPY_SECTION_NAMES, NUMPY_SECTION_NAMES};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum SectionStyle {
     Numpy,
     Google,
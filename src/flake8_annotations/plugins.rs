@@ -71,7 +71,7 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
     // vs. secret vs. protected.
     match &definition.kind {
         DefinitionKind::Module => {}
-        DefinitionKind::Package => {}
+        DefinitionKind::Package(..) => {}
         DefinitionKind::Class(_) => {}
         DefinitionKind::NestedClass(_) => {}
         DefinitionKind::Function(stmt) | DefinitionKind::NestedFunction(stmt) => {
@@ -343,10 +343,22 @@ pub fn definition(checker: &mut Checker, definition: &Definition, visibility: &V
                     }
                 } else if visibility::is_magic(stmt) {
                     if checker.settings.enabled.contains(&CheckCode::ANN204) {
-                        checker.checks.push(Check::new(
+                        let mut check = Check::new(
                             violations::MissingReturnTypeSpecialMethod(name.to_string()),
                             helpers::identifier_range(stmt, checker.locator),
-                        ));
+                        );
+                        // Unlike `__init__`, most magic methods (`__eq__`, `__len__`, etc.)
+                        // return something other than `None`, so only offer a fix when the
+                        // body itself proves the return type is unambiguous.
+                        if checker.patch(check.kind.code()) && is_none_returning(body) {
+                            match fixes::add_return_none_annotation(checker.locator, stmt) {
+                                Ok(fix) => {
+                                    check.amend(fix);
+                                }
+                                Err(e) => error!("Failed to generate fix: {e}"),
+                            }
+                        }
+                        checker.checks.push(check);
                     }
                 } else {
                     match visibility {
@@ -0,0 +1,21 @@
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+const SQL_KEYWORDS: [&str; 4] = ["select", "insert", "update", "delete"];
+
+fn looks_like_sql(value: &str) -> bool {
+    let lowered = value.trim_start().to_lowercase();
+    SQL_KEYWORDS
+        .iter()
+        .any(|keyword| lowered.starts_with(keyword))
+}
+
+/// S608
+pub fn hardcoded_sql_expression(expr: &Range, value: &str) -> Option<Check> {
+    if looks_like_sql(value) {
+        Some(Check::new(violations::HardcodedSQLExpression, *expr))
+    } else {
+        None
+    }
+}
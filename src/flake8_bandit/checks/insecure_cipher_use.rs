@@ -0,0 +1,74 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustpython_ast::{Expr, ExprKind};
+
+use crate::ast::helpers::match_module_member;
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+const WEAK_CRYPTOGRAPHY_CIPHERS: [&str; 3] = ["ARC4", "Blowfish", "DES"];
+const WEAK_CRYPTOGRAPHY_MODES: [&str; 1] = ["ECB"];
+const INSECURE_SSL_PROTOCOLS: [&str; 3] = ["PROTOCOL_SSLv2", "PROTOCOL_SSLv3", "PROTOCOL_TLSv1"];
+
+/// S303
+pub fn insecure_cipher_use(
+    func: &Expr,
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    for cipher in WEAK_CRYPTOGRAPHY_CIPHERS {
+        if match_module_member(
+            func,
+            "cryptography.hazmat.primitives.ciphers.algorithms",
+            cipher,
+            from_imports,
+            import_aliases,
+        ) {
+            return Some(Check::new(
+                violations::InsecureCipherUsage(cipher.to_string()),
+                Range::from_located(func),
+            ));
+        }
+    }
+    None
+}
+
+/// S304
+pub fn insecure_cipher_mode_use(
+    func: &Expr,
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    for mode in WEAK_CRYPTOGRAPHY_MODES {
+        if match_module_member(
+            func,
+            "cryptography.hazmat.primitives.ciphers.modes",
+            mode,
+            from_imports,
+            import_aliases,
+        ) {
+            return Some(Check::new(
+                violations::InsecureCipherModeUsage(mode.to_string()),
+                Range::from_located(func),
+            ));
+        }
+    }
+    None
+}
+
+/// S305
+pub fn insecure_ssl_protocol_use(expr: &Expr, attr: &str, value: &Expr) -> Option<Check> {
+    if !INSECURE_SSL_PROTOCOLS.contains(&attr) {
+        return None;
+    }
+    let ExprKind::Name { id, .. } = &value.node else {
+        return None;
+    };
+    if id != "ssl" {
+        return None;
+    }
+    Some(Check::new(
+        violations::InsecureSSLProtocolUsage(attr.to_string()),
+        Range::from_located(expr),
+    ))
+}
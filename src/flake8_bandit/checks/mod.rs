@@ -9,8 +9,23 @@ pub use hardcoded_password_string::{
 };
 pub use hardcoded_tmp_directory::hardcoded_tmp_directory;
 pub use hashlib_insecure_hash_functions::hashlib_insecure_hash_functions;
+pub use hardcoded_sql_expression::hardcoded_sql_expression;
+pub use insecure_cipher_use::{
+    insecure_cipher_mode_use, insecure_cipher_use, insecure_ssl_protocol_use,
+};
 pub use request_with_no_cert_validation::request_with_no_cert_validation;
 pub use request_without_timeout::request_without_timeout;
+pub use start_process_with_a_shell::{
+    start_process_with_a_shell, start_process_with_no_shell, start_process_with_partial_path,
+};
+pub use subprocess_with_shell_equals_true::{
+    call_with_shell_equals_true, subprocess_with_shell_equals_true,
+    subprocess_without_shell_equals_true,
+};
+pub use suspicious_pickle_use::{suspicious_marshal_use, suspicious_pickle_use};
+pub use suspicious_random_use::suspicious_non_cryptographic_random_use;
+pub use try_except_pass::{try_except_continue, try_except_pass};
+pub use unix_command_wildcard_injection::unix_command_wildcard_injection;
 pub use unsafe_yaml_load::unsafe_yaml_load;
 
 mod assert_used;
@@ -20,8 +35,16 @@ mod hardcoded_bind_all_interfaces;
 mod hardcoded_password_default;
 mod hardcoded_password_func_arg;
 mod hardcoded_password_string;
+mod hardcoded_sql_expression;
 mod hardcoded_tmp_directory;
 mod hashlib_insecure_hash_functions;
+mod insecure_cipher_use;
 mod request_with_no_cert_validation;
 mod request_without_timeout;
+mod start_process_with_a_shell;
+mod subprocess_with_shell_equals_true;
+mod suspicious_pickle_use;
+mod suspicious_random_use;
+mod try_except_pass;
+mod unix_command_wildcard_injection;
 mod unsafe_yaml_load;
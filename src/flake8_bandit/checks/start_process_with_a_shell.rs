@@ -0,0 +1,113 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustpython_ast::{Constant, Expr, ExprKind};
+
+use crate::ast::helpers::match_module_member;
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+/// S605
+pub fn start_process_with_a_shell(
+    expr: &Expr,
+    func: &Expr,
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    if match_module_member(func, "os", "system", from_imports, import_aliases)
+        || match_module_member(func, "os", "popen", from_imports, import_aliases)
+        || match_module_member(func, "popen2", "Popen3", from_imports, import_aliases)
+        || match_module_member(func, "popen2", "Popen4", from_imports, import_aliases)
+    {
+        Some(Check::new(
+            violations::StartProcessWithAShell,
+            Range::from_located(expr),
+        ))
+    } else {
+        None
+    }
+}
+
+/// S606
+pub fn start_process_with_no_shell(func: &Expr) -> Option<Check> {
+    let ExprKind::Attribute { value, attr, .. } = &func.node else {
+        return None;
+    };
+    let ExprKind::Name { id, .. } = &value.node else {
+        return None;
+    };
+    if id != "os" {
+        return None;
+    }
+    if matches!(
+        attr.as_str(),
+        "execl"
+            | "execle"
+            | "execlp"
+            | "execlpe"
+            | "execv"
+            | "execve"
+            | "execvp"
+            | "execvpe"
+            | "spawnl"
+            | "spawnle"
+            | "spawnlp"
+            | "spawnlpe"
+            | "spawnv"
+            | "spawnve"
+            | "spawnvp"
+            | "spawnvpe"
+            | "startfile"
+    ) {
+        Some(Check::new(
+            violations::StartProcessWithNoShell,
+            Range::from_located(func),
+        ))
+    } else {
+        None
+    }
+}
+
+const PROCESS_STARTING_FUNCTIONS: [&str; 5] = ["Popen", "call", "check_call", "check_output", "run"];
+
+/// S607
+pub fn start_process_with_partial_path(
+    expr: &Expr,
+    func: &Expr,
+    args: &[Expr],
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    let is_process_starting_call = PROCESS_STARTING_FUNCTIONS
+        .iter()
+        .any(|name| match_module_member(func, "subprocess", name, from_imports, import_aliases))
+        || match_module_member(func, "os", "system", from_imports, import_aliases)
+        || match_module_member(func, "os", "popen", from_imports, import_aliases);
+    if !is_process_starting_call {
+        return None;
+    }
+    let first_arg = args.first()?;
+    let value = match &first_arg.node {
+        ExprKind::Constant {
+            value: Constant::Str(value),
+            ..
+        } => value,
+        ExprKind::List { elts, .. } | ExprKind::Tuple { elts, .. } => {
+            let ExprKind::Constant {
+                value: Constant::Str(value),
+                ..
+            } = &elts.first()?.node
+            else {
+                return None;
+            };
+            value
+        }
+        _ => return None,
+    };
+    if value.starts_with('/') || value.starts_with('.') || value.is_empty() {
+        return None;
+    }
+    Some(Check::new(
+        violations::StartProcessWithPartialPath,
+        Range::from_located(expr),
+    ))
+}
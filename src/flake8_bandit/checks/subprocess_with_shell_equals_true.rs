@@ -0,0 +1,102 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustpython_ast::{Constant, Expr, ExprKind, Keyword};
+
+use crate::ast::helpers::{collect_call_paths, dealias_call_path, match_module_member, SimpleCallArgs};
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+const SUBPROCESS_FUNCTIONS: [&str; 5] = ["Popen", "call", "check_call", "check_output", "run"];
+
+fn is_shell_true(expr: &Expr) -> bool {
+    matches!(
+        &expr.node,
+        ExprKind::Constant {
+            value: Constant::Bool(true),
+            ..
+        }
+    )
+}
+
+fn is_subprocess_call(
+    func: &Expr,
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> bool {
+    SUBPROCESS_FUNCTIONS
+        .iter()
+        .any(|name| match_module_member(func, "subprocess", name, from_imports, import_aliases))
+}
+
+/// S602
+pub fn subprocess_with_shell_equals_true(
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    if !is_subprocess_call(func, from_imports, import_aliases) {
+        return None;
+    }
+    let call_args = SimpleCallArgs::new(args, keywords);
+    let shell_arg = call_args.get_argument("shell", None)?;
+    if is_shell_true(shell_arg) {
+        return Some(Check::new(
+            violations::SubprocessPopenWithShellEqualsTrue,
+            Range::from_located(shell_arg),
+        ));
+    }
+    None
+}
+
+/// S603
+pub fn subprocess_without_shell_equals_true(
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    if !is_subprocess_call(func, from_imports, import_aliases) {
+        return None;
+    }
+    let call_args = SimpleCallArgs::new(args, keywords);
+    if let Some(shell_arg) = call_args.get_argument("shell", None) {
+        if is_shell_true(shell_arg) {
+            // Covered by S602, which carries the more specific message.
+            return None;
+        }
+    }
+    Some(Check::new(
+        violations::SubprocessWithoutShellEqualsTrue,
+        Range::from_located(func),
+    ))
+}
+
+/// S604
+pub fn call_with_shell_equals_true(
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    if is_subprocess_call(func, from_imports, import_aliases) {
+        // Covered by S602, which carries the more specific message.
+        return None;
+    }
+    let call_path = dealias_call_path(collect_call_paths(func), import_aliases);
+    if call_path.is_empty() {
+        return None;
+    }
+    let call_args = SimpleCallArgs::new(args, keywords);
+    let shell_arg = call_args.get_argument("shell", None)?;
+    if is_shell_true(shell_arg) {
+        return Some(Check::new(
+            violations::CallWithShellEqualsTrue,
+            Range::from_located(shell_arg),
+        ));
+    }
+    None
+}
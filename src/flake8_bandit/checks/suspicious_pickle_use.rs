@@ -0,0 +1,44 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustpython_ast::Expr;
+
+use crate::ast::helpers::match_module_member;
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+/// S301
+pub fn suspicious_pickle_use(
+    func: &Expr,
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    for module in ["pickle", "cPickle", "dill", "shelve"] {
+        for member in ["loads", "load", "Unpickler"] {
+            if match_module_member(func, module, member, from_imports, import_aliases) {
+                return Some(Check::new(
+                    violations::SuspiciousPickleUsage,
+                    Range::from_located(func),
+                ));
+            }
+        }
+    }
+    None
+}
+
+/// S302
+pub fn suspicious_marshal_use(
+    func: &Expr,
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    if match_module_member(func, "marshal", "loads", from_imports, import_aliases)
+        || match_module_member(func, "marshal", "load", from_imports, import_aliases)
+    {
+        Some(Check::new(
+            violations::SuspiciousMarshalUsage,
+            Range::from_located(func),
+        ))
+    } else {
+        None
+    }
+}
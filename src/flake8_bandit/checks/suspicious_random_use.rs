@@ -0,0 +1,35 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustpython_ast::Expr;
+
+use crate::ast::helpers::match_module_member;
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+const INSECURE_RANDOM_FUNCTIONS: [&str; 8] = [
+    "random",
+    "randrange",
+    "randint",
+    "choice",
+    "choices",
+    "uniform",
+    "triangular",
+    "sample",
+];
+
+/// S311
+pub fn suspicious_non_cryptographic_random_use(
+    func: &Expr,
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    for function_name in INSECURE_RANDOM_FUNCTIONS {
+        if match_module_member(func, "random", function_name, from_imports, import_aliases) {
+            return Some(Check::new(
+                violations::SuspiciousNonCryptographicRandomUsage,
+                Range::from_located(func),
+            ));
+        }
+    }
+    None
+}
@@ -0,0 +1,68 @@
+use rustpython_ast::{Excepthandler, ExcepthandlerKind, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+fn is_untyped_exception(handler: &Excepthandler, check_typed_exception: bool) -> bool {
+    if check_typed_exception {
+        return true;
+    }
+    let ExcepthandlerKind::ExceptHandler { type_, .. } = &handler.node;
+    match type_ {
+        None => true,
+        Some(type_) => {
+            matches!(&type_.node, ExprKind::Name { id, .. } if id == "Exception" || id == "BaseException")
+        }
+    }
+}
+
+/// S110
+pub fn try_except_pass(handlers: &[Excepthandler], check_typed_exception: bool) -> Vec<Check> {
+    handlers
+        .iter()
+        .filter_map(|handler| {
+            if !is_untyped_exception(handler, check_typed_exception) {
+                return None;
+            }
+            let ExcepthandlerKind::ExceptHandler { body, .. } = &handler.node;
+            if let [Stmt {
+                node: StmtKind::Pass,
+                ..
+            }] = body.as_slice()
+            {
+                Some(Check::new(
+                    violations::TryExceptPass,
+                    Range::from_located(handler),
+                ))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// S112
+pub fn try_except_continue(handlers: &[Excepthandler], check_typed_exception: bool) -> Vec<Check> {
+    handlers
+        .iter()
+        .filter_map(|handler| {
+            if !is_untyped_exception(handler, check_typed_exception) {
+                return None;
+            }
+            let ExcepthandlerKind::ExceptHandler { body, .. } = &handler.node;
+            if let [Stmt {
+                node: StmtKind::Continue,
+                ..
+            }] = body.as_slice()
+            {
+                Some(Check::new(
+                    violations::TryExceptContinue,
+                    Range::from_located(handler),
+                ))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustpython_ast::{Constant, Expr, ExprKind};
+
+use crate::ast::helpers::match_module_member;
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+const WILDCARD_COMMANDS: [&str; 2] = ["chown", "chmod"];
+const WILDCARD_TOOLS: [&str; 2] = ["tar", "rsync"];
+
+fn command_contains_wildcard(value: &str) -> bool {
+    let Some((command, rest)) = value.trim().split_once(char::is_whitespace) else {
+        return false;
+    };
+    let command = command.rsplit('/').next().unwrap_or(command);
+    (WILDCARD_COMMANDS.contains(&command) || WILDCARD_TOOLS.contains(&command))
+        && rest.contains('*')
+}
+
+/// S609
+pub fn unix_command_wildcard_injection(
+    expr: &Expr,
+    func: &Expr,
+    args: &[Expr],
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<Check> {
+    let is_shell_call = match_module_member(func, "os", "system", from_imports, import_aliases)
+        || match_module_member(func, "os", "popen", from_imports, import_aliases)
+        || match_module_member(func, "subprocess", "call", from_imports, import_aliases)
+        || match_module_member(func, "subprocess", "run", from_imports, import_aliases);
+    if !is_shell_call {
+        return None;
+    }
+    let first_arg = args.first()?;
+    let ExprKind::Constant {
+        value: Constant::Str(value),
+        ..
+    } = &first_arg.node
+    else {
+        return None;
+    };
+    if command_contains_wildcard(value) {
+        Some(Check::new(
+            violations::UnixCommandWildcardInjection,
+            Range::from_located(expr),
+        ))
+    } else {
+        None
+    }
+}
@@ -21,7 +21,23 @@ mod tests {
     #[test_case(CheckCode::S106, Path::new("S106.py"); "S106")]
     #[test_case(CheckCode::S107, Path::new("S107.py"); "S107")]
     #[test_case(CheckCode::S108, Path::new("S108.py"); "S108")]
+    #[test_case(CheckCode::S110, Path::new("S110.py"); "S110")]
+    #[test_case(CheckCode::S112, Path::new("S112.py"); "S112")]
     #[test_case(CheckCode::S113, Path::new("S113.py"); "S113")]
+    #[test_case(CheckCode::S301, Path::new("S301.py"); "S301")]
+    #[test_case(CheckCode::S302, Path::new("S302.py"); "S302")]
+    #[test_case(CheckCode::S303, Path::new("S303.py"); "S303")]
+    #[test_case(CheckCode::S304, Path::new("S304.py"); "S304")]
+    #[test_case(CheckCode::S305, Path::new("S305.py"); "S305")]
+    #[test_case(CheckCode::S311, Path::new("S311.py"); "S311")]
+    #[test_case(CheckCode::S608, Path::new("S608.py"); "S608")]
+    #[test_case(CheckCode::S602, Path::new("S602.py"); "S602")]
+    #[test_case(CheckCode::S603, Path::new("S603.py"); "S603")]
+    #[test_case(CheckCode::S604, Path::new("S604.py"); "S604")]
+    #[test_case(CheckCode::S605, Path::new("S605.py"); "S605")]
+    #[test_case(CheckCode::S606, Path::new("S606.py"); "S606")]
+    #[test_case(CheckCode::S607, Path::new("S607.py"); "S607")]
+    #[test_case(CheckCode::S609, Path::new("S609.py"); "S609")]
     #[test_case(CheckCode::S324, Path::new("S324.py"); "S324")]
     #[test_case(CheckCode::S501, Path::new("S501.py"); "S501")]
     #[test_case(CheckCode::S506, Path::new("S506.py"); "S506")]
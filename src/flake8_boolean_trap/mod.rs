@@ -1,4 +1,5 @@
 pub mod plugins;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -10,7 +11,7 @@ mod tests {
 
     use crate::linter::test_path;
     use crate::registry::CheckCode;
-    use crate::settings;
+    use crate::{flake8_boolean_trap, settings};
 
     #[test_case(CheckCode::FBT001, Path::new("FBT.py"); "FBT001")]
     #[test_case(CheckCode::FBT002, Path::new("FBT.py"); "FBT002")]
@@ -26,4 +27,23 @@ mod tests {
         insta::assert_yaml_snapshot!(snapshot, checks);
         Ok(())
     }
+
+    #[test]
+    fn extend_allowed_calls() -> Result<()> {
+        let snapshot = "extend_allowed_calls".to_string();
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/flake8_boolean_trap/FBT003_extended.py"),
+            &settings::Settings {
+                flake8_boolean_trap: flake8_boolean_trap::settings::Settings {
+                    extend_allowed_calls: vec![
+                        "dict.get".to_string(),
+                        "pytest.param".to_string(),
+                    ],
+                },
+                ..settings::Settings::for_rules(vec![CheckCode::FBT003])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, checks);
+        Ok(())
+    }
 }
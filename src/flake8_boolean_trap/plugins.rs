@@ -1,6 +1,9 @@
 use rustpython_ast::{Arguments, ExprKind};
 use rustpython_parser::ast::{Constant, Expr};
 
+use crate::ast::helpers::{
+    collect_call_paths, dealias_call_path, match_call_path, to_module_and_member,
+};
 use crate::ast::types::Range;
 use crate::checkers::ast::Checker;
 use crate::registry::{Check, CheckKind};
@@ -23,18 +26,30 @@ const FUNC_NAME_ALLOWLIST: &[&str] = &[
 ];
 
 /// Returns `true` if an argument is allowed to use a boolean trap. To return
-/// `true`, the function name must be explicitly allowed, and the argument must
-/// be either the first or second argument in the call.
-fn allow_boolean_trap(func: &Expr) -> bool {
+/// `true`, the function name must be explicitly allowed (either via the
+/// built-in allowlist, matched by name alone, or via `extend-allowed-calls`,
+/// matched by fully-qualified call path).
+fn allow_boolean_trap(func: &Expr, checker: &Checker) -> bool {
     if let ExprKind::Attribute { attr, .. } = &func.node {
-        return FUNC_NAME_ALLOWLIST.contains(&attr.as_ref());
-    }
-
-    if let ExprKind::Name { id, .. } = &func.node {
-        return FUNC_NAME_ALLOWLIST.contains(&id.as_ref());
+        if FUNC_NAME_ALLOWLIST.contains(&attr.as_ref()) {
+            return true;
+        }
+    } else if let ExprKind::Name { id, .. } = &func.node {
+        if FUNC_NAME_ALLOWLIST.contains(&id.as_ref()) {
+            return true;
+        }
     }
 
-    false
+    let call_path = dealias_call_path(collect_call_paths(func), &checker.import_aliases);
+    checker
+        .settings
+        .flake8_boolean_trap
+        .extend_allowed_calls
+        .iter()
+        .any(|target| {
+            let (module, member) = to_module_and_member(target);
+            match_call_path(&call_path, module, member, &checker.from_imports)
+        })
 }
 
 fn is_boolean_arg(arg: &Expr) -> bool {
@@ -102,7 +117,7 @@ pub fn check_boolean_positional_value_in_function_call(
     func: &Expr,
 ) {
     for arg in args {
-        if allow_boolean_trap(func) {
+        if allow_boolean_trap(func, checker) {
             continue;
         }
         add_if_boolean(
@@ -0,0 +1,49 @@
+//! Settings for the `flake8-boolean-trap` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Default, Serialize, Deserialize, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "Flake8BooleanTrapOptions"
+)]
+pub struct Options {
+    #[option(
+        default = r#"[]"#,
+        value_type = "Vec<String>",
+        example = r#"
+            # Allow boolean positional arguments in calls to `dict.get`, `setattr`,
+            # and `pytest.param`, which force this pattern in their public API.
+            extend-allowed-calls = ["dict.get", "setattr", "pytest.param"]
+        "#
+    )]
+    /// Additional callable functions with which to allow boolean positional
+    /// arguments, in addition to the default set (e.g., `dict.get`, `setattr`).
+    pub extend_allowed_calls: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Hash)]
+pub struct Settings {
+    pub extend_allowed_calls: Vec<String>,
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            extend_allowed_calls: options.extend_allowed_calls.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            extend_allowed_calls: Some(settings.extend_allowed_calls),
+        }
+    }
+}
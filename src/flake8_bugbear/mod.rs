@@ -40,6 +40,11 @@ mod tests {
     #[test_case(CheckCode::B027, Path::new("B027.py"); "B027")]
     #[test_case(CheckCode::B904, Path::new("B904.py"); "B904")]
     #[test_case(CheckCode::B905, Path::new("B905.py"); "B905")]
+    #[test_case(CheckCode::B028, Path::new("B028.py"); "B028")]
+    #[test_case(CheckCode::B029, Path::new("B029.py"); "B029")]
+    #[test_case(CheckCode::B030, Path::new("B030.py"); "B030")]
+    #[test_case(CheckCode::B031, Path::new("B031.py"); "B031")]
+    #[test_case(CheckCode::B032, Path::new("B032.py"); "B032")]
     fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", check_code.as_ref(), path.to_string_lossy());
         let checks = test_path(
@@ -0,0 +1,68 @@
+use rustpython_ast::{Comprehension, Expr, ExprContext, ExprKind};
+
+use crate::ast::types::Range;
+use crate::ast::visitor;
+use crate::ast::visitor::Visitor;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::violations;
+
+#[derive(Default)]
+struct LoadedNamesVisitor<'a> {
+    names: Vec<&'a str>,
+}
+
+impl<'a> Visitor<'a> for LoadedNamesVisitor<'a> {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if let ExprKind::Name { id, ctx } = &expr.node {
+            if matches!(ctx, ExprContext::Load) {
+                self.names.push(id);
+            }
+        }
+        visitor::walk_expr(self, expr);
+    }
+}
+
+fn bound_names(target: &Expr, names: &mut Vec<String>) {
+    match &target.node {
+        ExprKind::Name { id, .. } => names.push(id.clone()),
+        ExprKind::Tuple { elts, .. } | ExprKind::List { elts, .. } => {
+            for elt in elts {
+                bound_names(elt, names);
+            }
+        }
+        ExprKind::Starred { value, .. } => bound_names(value, names),
+        _ => {}
+    }
+}
+
+/// B032
+pub fn dict_comprehension_with_static_key(
+    checker: &mut Checker,
+    key: &Expr,
+    generators: &[Comprehension],
+) {
+    let mut loop_variables = vec![];
+    for generator in generators {
+        bound_names(&generator.target, &mut loop_variables);
+    }
+    if loop_variables.is_empty() {
+        return;
+    }
+
+    let mut visitor = LoadedNamesVisitor::default();
+    visitor.visit_expr(key);
+
+    if visitor
+        .names
+        .iter()
+        .any(|name| loop_variables.iter().any(|var| var == name))
+    {
+        return;
+    }
+
+    checker.checks.push(Check::new(
+        violations::DictComprehensionWithStaticKey,
+        Range::from_located(key),
+    ));
+}
@@ -0,0 +1,44 @@
+use rustpython_ast::{Excepthandler, ExcepthandlerKind, Expr, ExprKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::violations;
+
+/// Return `true` if `expr` is obviously not a valid exception class (a
+/// literal, rather than a name, attribute, or call that could resolve to
+/// one).
+fn is_obviously_not_exception(expr: &Expr) -> bool {
+    matches!(
+        &expr.node,
+        ExprKind::Constant { .. }
+            | ExprKind::List { .. }
+            | ExprKind::Dict { .. }
+            | ExprKind::Set { .. }
+            | ExprKind::Tuple { .. }
+    )
+}
+
+/// B030
+pub fn except_with_non_exception_classes(checker: &mut Checker, handlers: &[Excepthandler]) {
+    for handler in handlers {
+        let ExcepthandlerKind::ExceptHandler {
+            type_: Some(type_), ..
+        } = &handler.node
+        else {
+            continue;
+        };
+        let exception_types: Vec<&Expr> = match &type_.node {
+            ExprKind::Tuple { elts, .. } => elts.iter().collect(),
+            _ => vec![type_.as_ref()],
+        };
+        for exception_type in exception_types {
+            if is_obviously_not_exception(exception_type) {
+                checker.checks.push(Check::new(
+                    violations::ExceptWithNonExceptionClasses,
+                    Range::from_located(exception_type),
+                ));
+            }
+        }
+    }
+}
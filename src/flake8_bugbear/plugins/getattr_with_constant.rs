@@ -3,7 +3,7 @@ use rustpython_ast::{Constant, Expr, ExprContext, ExprKind, Location};
 use crate::ast::types::Range;
 use crate::autofix::Fix;
 use crate::checkers::ast::Checker;
-use crate::python::identifiers::IDENTIFIER_REGEX;
+use crate::python::identifiers::{is_dunder, IDENTIFIER_REGEX};
 use crate::python::keyword::KWLIST;
 use crate::registry::Check;
 use crate::source_code_generator::SourceCodeGenerator;
@@ -44,6 +44,9 @@ pub fn getattr_with_constant(checker: &mut Checker, expr: &Expr, func: &Expr, ar
     if KWLIST.contains(&value.as_str()) {
         return;
     }
+    if is_dunder(value) {
+        return;
+    }
 
     let mut check = Check::new(violations::GetAttrWithConstant, Range::from_located(expr));
     if checker.patch(check.kind.code()) {
@@ -4,7 +4,9 @@ pub use assert_raises_exception::assert_raises_exception;
 pub use assignment_to_os_environ::assignment_to_os_environ;
 pub use cached_instance_method::cached_instance_method;
 pub use cannot_raise_literal::cannot_raise_literal;
+pub use dict_comprehension_with_static_key::dict_comprehension_with_static_key;
 pub use duplicate_exceptions::duplicate_exceptions;
+pub use except_with_non_exception_classes::except_with_non_exception_classes;
 pub use f_string_docstring::f_string_docstring;
 pub use function_call_argument_default::function_call_argument_default;
 pub use function_uses_loop_variable::function_uses_loop_variable;
@@ -12,10 +14,13 @@ pub use getattr_with_constant::getattr_with_constant;
 pub use jump_statement_in_finally::jump_statement_in_finally;
 pub use loop_variable_overrides_iterator::loop_variable_overrides_iterator;
 pub use mutable_argument_default::mutable_argument_default;
+pub use no_explicit_stacklevel::no_explicit_stacklevel;
 pub use raise_without_from_inside_except::raise_without_from_inside_except;
 pub use redundant_tuple_in_exception_handler::redundant_tuple_in_exception_handler;
+pub use reuse_of_groupby_generator::reuse_of_groupby_generator;
 pub use setattr_with_constant::setattr_with_constant;
 pub use star_arg_unpacking_after_keyword_arg::star_arg_unpacking_after_keyword_arg;
+pub use star_import_shadows_existing::star_import_shadows_existing;
 pub use strip_with_multi_characters::strip_with_multi_characters;
 pub use unary_prefix_increment::unary_prefix_increment;
 pub use unreliable_callable_check::unreliable_callable_check;
@@ -31,7 +36,9 @@ mod assert_raises_exception;
 mod assignment_to_os_environ;
 mod cached_instance_method;
 mod cannot_raise_literal;
+mod dict_comprehension_with_static_key;
 mod duplicate_exceptions;
+mod except_with_non_exception_classes;
 mod f_string_docstring;
 mod function_call_argument_default;
 mod function_uses_loop_variable;
@@ -39,10 +46,13 @@ mod getattr_with_constant;
 mod jump_statement_in_finally;
 mod loop_variable_overrides_iterator;
 mod mutable_argument_default;
+mod no_explicit_stacklevel;
 mod raise_without_from_inside_except;
 mod redundant_tuple_in_exception_handler;
+mod reuse_of_groupby_generator;
 mod setattr_with_constant;
 mod star_arg_unpacking_after_keyword_arg;
+mod star_import_shadows_existing;
 mod strip_with_multi_characters;
 mod unary_prefix_increment;
 mod unreliable_callable_check;
@@ -1,8 +1,10 @@
 use rustc_hash::{FxHashMap, FxHashSet};
-use rustpython_ast::{Arguments, Constant, Expr, ExprKind, Operator};
+use rustpython_ast::{Arg, Arguments, Constant, Expr, ExprKind, Operator, Stmt, StmtKind};
 
 use crate::ast::helpers::{collect_call_paths, dealias_call_path, match_call_path};
 use crate::ast::types::Range;
+use crate::ast::whitespace::indentation;
+use crate::autofix::Fix;
 use crate::checkers::ast::Checker;
 use crate::registry::Check;
 use crate::violations;
@@ -143,6 +145,59 @@ fn is_immutable_annotation(
     }
 }
 
+/// Return `true` if `stmt` is a standalone docstring expression.
+fn is_docstring_stmt(stmt: &Stmt) -> bool {
+    let StmtKind::Expr { value } = &stmt.node else {
+        return false;
+    };
+    matches!(
+        &value.node,
+        ExprKind::Constant {
+            value: Constant::Str(_),
+            ..
+        }
+    )
+}
+
+/// Generate a fix that replaces a mutable default with `None`, and instead initializes the
+/// argument at the top of the function body (immediately after the docstring, if any).
+fn move_initialization_into_body(checker: &Checker, arg: &Arg, default: &Expr) -> Option<Fix> {
+    // We can't rewrite annotated arguments, since `None` may not be assignable to the
+    // annotated type.
+    if arg.node.annotation.is_some() {
+        return None;
+    }
+
+    // Lambdas have no statement body to initialize the argument in.
+    let mut body = checker.flake8_bugbear_function_body?.iter();
+    let mut first = body.next()?;
+    if is_docstring_stmt(first) {
+        first = body.next()?;
+    }
+
+    let name = &arg.node.arg;
+    let default_source = checker
+        .locator
+        .slice_source_code_range(&Range::from_located(default));
+    let between = checker.locator.slice_source_code_range(&Range::new(
+        default.end_location.unwrap(),
+        first.location,
+    ));
+    let indent = indentation(checker, first);
+    let first_source = checker
+        .locator
+        .slice_source_code_range(&Range::from_located(first));
+
+    Some(Fix::replacement(
+        format!(
+            "None{between}if {name} is None:\n{indent}    {name} = {default_source}\n{indent}\
+             {first_source}"
+        ),
+        default.location,
+        first.end_location.unwrap(),
+    ))
+}
+
 /// B006
 pub fn mutable_argument_default(checker: &mut Checker, arguments: &Arguments) {
     // Scan in reverse order to right-align zip()
@@ -165,10 +220,16 @@ pub fn mutable_argument_default(checker: &mut Checker, arguments: &Arguments) {
                 !is_immutable_annotation(expr, &checker.from_imports, &checker.import_aliases)
             })
         {
-            checker.checks.push(Check::new(
+            let mut check = Check::new(
                 violations::MutableArgumentDefault,
                 Range::from_located(default),
-            ));
+            );
+            if checker.patch(check.kind.code()) {
+                if let Some(fix) = move_initialization_into_body(checker, arg, default) {
+                    check.amend(fix);
+                }
+            }
+            checker.checks.push(check);
         }
     }
 }
@@ -0,0 +1,46 @@
+use rustpython_ast::{Expr, ExprKind, Keyword};
+
+use crate::ast::helpers::{collect_call_paths, match_call_path};
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::violations;
+
+/// B028
+pub fn no_explicit_stacklevel(
+    checker: &mut Checker,
+    expr: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+) {
+    let ExprKind::Call { func, .. } = &expr.node else {
+        return;
+    };
+    if !match_call_path(
+        &collect_call_paths(func),
+        "warnings",
+        "warn",
+        &checker.from_imports,
+    ) {
+        return;
+    }
+
+    if args.len() > 2 {
+        return;
+    }
+
+    if keywords.iter().any(|keyword| {
+        keyword
+            .node
+            .arg
+            .as_ref()
+            .map_or(false, |arg| arg == "stacklevel")
+    }) {
+        return;
+    }
+
+    checker.checks.push(Check::new(
+        violations::NoExplicitStacklevel,
+        Range::from_located(expr),
+    ));
+}
@@ -0,0 +1,68 @@
+use rustpython_ast::{Expr, ExprContext, ExprKind, Stmt};
+
+use crate::ast::helpers::{collect_call_paths, match_call_path};
+use crate::ast::types::Range;
+use crate::ast::visitor;
+use crate::ast::visitor::Visitor;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::violations;
+
+#[derive(Default)]
+struct GroupNameUsesVisitor<'a> {
+    group_name: &'a str,
+    uses: Vec<&'a Expr>,
+}
+
+impl<'a> Visitor<'a> for GroupNameUsesVisitor<'a> {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        if let ExprKind::Name { id, ctx } = &expr.node {
+            if id == self.group_name && matches!(ctx, ExprContext::Load) {
+                self.uses.push(expr);
+            }
+        }
+        visitor::walk_expr(self, expr);
+    }
+}
+
+/// B031
+pub fn reuse_of_groupby_generator(
+    checker: &mut Checker,
+    target: &Expr,
+    iter: &Expr,
+    body: &[Stmt],
+) {
+    if !match_call_path(
+        &collect_call_paths(iter),
+        "itertools",
+        "groupby",
+        &checker.from_imports,
+    ) {
+        return;
+    }
+
+    let ExprKind::Tuple { elts, .. } = &target.node else {
+        return;
+    };
+    let [_, group] = &elts[..] else {
+        return;
+    };
+    let ExprKind::Name { id: group_name, .. } = &group.node else {
+        return;
+    };
+
+    let mut visitor = GroupNameUsesVisitor {
+        group_name,
+        uses: vec![],
+    };
+    for stmt in body {
+        visitor.visit_stmt(stmt);
+    }
+
+    for expr in visitor.uses.into_iter().skip(1) {
+        checker.checks.push(Check::new(
+            violations::ReuseOfGroupbyGenerator,
+            Range::from_located(expr),
+        ));
+    }
+}
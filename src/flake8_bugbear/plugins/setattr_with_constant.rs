@@ -3,7 +3,7 @@ use rustpython_ast::{Constant, Expr, ExprContext, ExprKind, Location, Stmt, Stmt
 use crate::ast::types::Range;
 use crate::autofix::Fix;
 use crate::checkers::ast::Checker;
-use crate::python::identifiers::IDENTIFIER_REGEX;
+use crate::python::identifiers::{is_dunder, IDENTIFIER_REGEX};
 use crate::python::keyword::KWLIST;
 use crate::registry::Check;
 use crate::source_code_generator::SourceCodeGenerator;
@@ -56,6 +56,9 @@ pub fn setattr_with_constant(checker: &mut Checker, expr: &Expr, func: &Expr, ar
     if KWLIST.contains(&name.as_str()) {
         return;
     }
+    if is_dunder(name) {
+        return;
+    }
     // We can only replace a `setattr` call (which is an `Expr`) with an assignment
     // (which is a `Stmt`) if the `Expr` is already being used as a `Stmt`
     // (i.e., it's directly within an `StmtKind::Expr`).
@@ -0,0 +1,25 @@
+use rustpython_ast::Stmt;
+
+use crate::ast::types::{BindingKind, Range};
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::violations;
+
+/// B029
+pub fn star_import_shadows_existing(checker: &mut Checker, stmt: &Stmt, module: Option<&str>) {
+    // Builtins are bound in the module scope up front, so they don't count as
+    // names defined by the module itself.
+    if checker
+        .current_scope()
+        .values
+        .values()
+        .all(|index| matches!(checker.bindings[*index].kind, BindingKind::Builtin))
+    {
+        return;
+    }
+
+    checker.checks.push(Check::new(
+        violations::StarImportShadowsExisting(module.unwrap_or("").to_string()),
+        Range::from_located(stmt),
+    ));
+}
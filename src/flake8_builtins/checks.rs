@@ -11,8 +11,9 @@ pub fn builtin_shadowing<T>(
     name: &str,
     located: &Located<T>,
     node_type: ShadowingType,
+    builtins_ignorelist: &[String],
 ) -> Option<Check> {
-    if BUILTINS.contains(&name) {
+    if BUILTINS.contains(&name) && !builtins_ignorelist.iter().any(|ignore| ignore == name) {
         Some(Check::new::<CheckKind>(
             match node_type {
                 ShadowingType::Variable => {
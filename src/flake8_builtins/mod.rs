@@ -1,4 +1,5 @@
 pub mod checks;
+pub mod settings;
 pub mod types;
 
 #[cfg(test)]
@@ -11,7 +12,7 @@ mod tests {
 
     use crate::linter::test_path;
     use crate::registry::CheckCode;
-    use crate::settings;
+    use crate::{flake8_builtins, settings};
 
     #[test_case(CheckCode::A001, Path::new("A001.py"); "A001")]
     #[test_case(CheckCode::A002, Path::new("A002.py"); "A002")]
@@ -27,4 +28,19 @@ mod tests {
         insta::assert_yaml_snapshot!(snapshot, checks);
         Ok(())
     }
+
+    #[test]
+    fn builtins_ignorelist() -> Result<()> {
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/flake8_builtins/A001.py"),
+            &settings::Settings {
+                flake8_builtins: flake8_builtins::settings::Settings {
+                    builtins_ignorelist: vec!["print".to_string()],
+                },
+                ..settings::Settings::for_rule(CheckCode::A001)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
 }
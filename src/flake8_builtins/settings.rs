@@ -0,0 +1,49 @@
+//! Settings for the `flake8-builtins` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "Flake8BuiltinsOptions"
+)]
+pub struct Options {
+    #[option(
+        default = r#"[]"#,
+        value_type = "Vec<String>",
+        example = r#"
+            # Allow shadowing the `id` and `type` builtins, which are commonly
+            # used as field names in ORM code.
+            builtins-ignorelist = ["id", "type"]
+        "#
+    )]
+    /// A list of builtins to ignore when considering `A001`, `A002`, and
+    /// `A003` violations.
+    pub builtins_ignorelist: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Hash)]
+pub struct Settings {
+    pub builtins_ignorelist: Vec<String>,
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            builtins_ignorelist: options.builtins_ignorelist.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            builtins_ignorelist: Some(settings.builtins_ignorelist),
+        }
+    }
+}
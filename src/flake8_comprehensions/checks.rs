@@ -577,3 +577,60 @@ pub fn unnecessary_map(func: &Expr, args: &[Expr], location: Range) -> Option<Ch
     }
     None
 }
+
+/// C418
+pub fn unnecessary_dict_passed_to_dict(
+    expr: &Expr,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+    locator: &SourceCodeLocator,
+    fix: bool,
+    location: Range,
+) -> Option<Check> {
+    let argument = exactly_one_argument_with_matching_function("dict", func, args, keywords)?;
+    if !matches!(argument, ExprKind::Dict { .. }) {
+        return None;
+    }
+    let mut check = Check::new(violations::UnnecessaryDictPassedToDict, location);
+    if fix {
+        match fixes::fix_unnecessary_dict_passed_to_dict(locator, expr) {
+            Ok(fix) => {
+                check.amend(fix);
+            }
+            Err(e) => error!("Failed to generate fix: {e}"),
+        }
+    }
+    Some(check)
+}
+
+/// C419
+pub fn unnecessary_comprehension_any_all(
+    expr: &Expr,
+    func: &Expr,
+    args: &[Expr],
+    locator: &SourceCodeLocator,
+    fix: bool,
+    location: Range,
+) -> Option<Check> {
+    let id = function_name(func)?;
+    if !(id == "any" || id == "all") {
+        return None;
+    }
+    if !matches!(
+        &args.first()?.node,
+        ExprKind::ListComp { .. } | ExprKind::SetComp { .. }
+    ) {
+        return None;
+    }
+    let mut check = Check::new(violations::UnnecessaryComprehensionAnyAll, location);
+    if fix {
+        match fixes::fix_unnecessary_comprehension_any_all(locator, expr) {
+            Ok(fix) => {
+                check.amend(fix);
+            }
+            Err(e) => error!("Failed to generate fix: {e}"),
+        }
+    }
+    Some(check)
+}
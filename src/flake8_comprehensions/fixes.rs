@@ -1,7 +1,7 @@
 use anyhow::{bail, Result};
 use libcst_native::{
     Arg, AssignEqual, Call, Codegen, CodegenState, Dict, DictComp, DictElement, Element, Expr,
-    Expression, LeftCurlyBrace, LeftParen, LeftSquareBracket, List, ListComp, Name,
+    Expression, GeneratorExp, LeftCurlyBrace, LeftParen, LeftSquareBracket, List, ListComp, Name,
     ParenthesizableWhitespace, RightCurlyBrace, RightParen, RightSquareBracket, Set, SetComp,
     SimpleString, SimpleWhitespace, Tuple,
 };
@@ -767,3 +767,68 @@ pub fn fix_unnecessary_comprehension(
         expr.end_location.unwrap(),
     ))
 }
+
+/// (C418) Convert `dict({"a": 1})` to `{"a": 1}`.
+pub fn fix_unnecessary_dict_passed_to_dict(
+    locator: &SourceCodeLocator,
+    expr: &rustpython_ast::Expr,
+) -> Result<Fix> {
+    // Expr(Call(Dict)))) -> Expr(Dict)))
+    let module_text = locator.slice_source_code_range(&Range::from_located(expr));
+    let mut tree = match_module(&module_text)?;
+    let mut body = match_expr(&mut tree)?;
+    let call = match_call(body)?;
+    let arg = match_arg(call)?;
+
+    body.value = arg.value.clone();
+
+    let mut state = CodegenState::default();
+    tree.codegen(&mut state);
+
+    Ok(Fix::replacement(
+        state.to_string(),
+        expr.location,
+        expr.end_location.unwrap(),
+    ))
+}
+
+/// (C419) Convert `any([x for x in y])` to `any(x for x in y)`.
+pub fn fix_unnecessary_comprehension_any_all(
+    locator: &SourceCodeLocator,
+    expr: &rustpython_ast::Expr,
+) -> Result<Fix> {
+    let module_text = locator.slice_source_code_range(&Range::from_located(expr));
+    let mut tree = match_module(&module_text)?;
+    let mut body = match_expr(&mut tree)?;
+    let call = match_call(body)?;
+    let arg = match_arg(call)?;
+
+    let (elt, for_in, lpar, rpar) = match &arg.value {
+        Expression::ListComp(inner) => (
+            inner.elt.clone(),
+            inner.for_in.clone(),
+            inner.lpar.clone(),
+            inner.rpar.clone(),
+        ),
+        Expression::SetComp(inner) => (
+            inner.elt.clone(),
+            inner.for_in.clone(),
+            inner.lpar.clone(),
+            inner.rpar.clone(),
+        ),
+        _ => {
+            bail!("Expected Expression::ListComp | Expression::SetComp");
+        }
+    };
+
+    call.args[0].value = Expression::GeneratorExp(Box::new(GeneratorExp { elt, for_in, lpar, rpar }));
+
+    let mut state = CodegenState::default();
+    tree.codegen(&mut state);
+
+    Ok(Fix::replacement(
+        state.to_string(),
+        expr.location,
+        expr.end_location.unwrap(),
+    ))
+}
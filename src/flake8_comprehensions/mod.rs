@@ -29,6 +29,8 @@ mod tests {
     #[test_case(CheckCode::C415, Path::new("C415.py"); "C415")]
     #[test_case(CheckCode::C416, Path::new("C416.py"); "C416")]
     #[test_case(CheckCode::C417, Path::new("C417.py"); "C417")]
+    #[test_case(CheckCode::C418, Path::new("C418.py"); "C418")]
+    #[test_case(CheckCode::C419, Path::new("C419.py"); "C419")]
 
     fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", check_code.as_ref(), path.to_string_lossy());
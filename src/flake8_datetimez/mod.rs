@@ -21,6 +21,8 @@ mod tests {
     #[test_case(CheckCode::DTZ007, Path::new("DTZ007.py"); "DTZ007")]
     #[test_case(CheckCode::DTZ011, Path::new("DTZ011.py"); "DTZ011")]
     #[test_case(CheckCode::DTZ012, Path::new("DTZ012.py"); "DTZ012")]
+    #[test_case(CheckCode::DTZ013, Path::new("DTZ013.py"); "DTZ013")]
+    #[test_case(CheckCode::DTZ014, Path::new("DTZ014.py"); "DTZ014")]
     fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", check_code.as_ref(), path.to_string_lossy());
         let checks = test_path(
@@ -1,35 +1,41 @@
-use rustpython_ast::{Constant, Expr, ExprKind, Keyword};
+use rustpython_ast::{Constant, Expr, ExprKind, Keyword, StmtKind};
 
 use crate::ast::helpers::{
     collect_call_paths, dealias_call_path, has_non_none_keyword, is_const_none, match_call_path,
 };
-use crate::ast::types::Range;
+use crate::ast::types::{BindingKind, Range};
 use crate::checkers::ast::Checker;
 use crate::registry::Check;
 use crate::violations;
 
-pub fn call_datetime_without_tzinfo(
-    checker: &mut Checker,
+/// Return `true` if `func`/`args`/`keywords` represent a naive (`tzinfo`-less)
+/// `datetime.datetime(...)` call.
+fn is_naive_datetime_call(
+    checker: &Checker,
     func: &Expr,
     args: &[Expr],
     keywords: &[Keyword],
-    location: Range,
-) {
+) -> bool {
     let call_path = dealias_call_path(collect_call_paths(func), &checker.import_aliases);
     if !match_call_path(&call_path, "datetime", "datetime", &checker.from_imports) {
-        return;
+        return false;
     }
 
-    // No positional arg: keyword is missing or constant None.
     if args.len() < 8 && !has_non_none_keyword(keywords, "tzinfo") {
-        checker
-            .checks
-            .push(Check::new(violations::CallDatetimeWithoutTzinfo, location));
-        return;
+        return true;
     }
 
-    // Positional arg: is constant None.
-    if args.len() >= 8 && is_const_none(&args[7]) {
+    args.len() >= 8 && is_const_none(&args[7])
+}
+
+pub fn call_datetime_without_tzinfo(
+    checker: &mut Checker,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+    location: Range,
+) {
+    if is_naive_datetime_call(checker, func, args, keywords) {
         checker
             .checks
             .push(Check::new(violations::CallDatetimeWithoutTzinfo, location));
@@ -251,3 +257,69 @@ pub fn call_date_fromtimestamp(checker: &mut Checker, func: &Expr, location: Ran
             .push(Check::new(violations::CallDateFromtimestamp, location));
     }
 }
+
+/// DTZ013
+pub fn call_datetime_time_without_tzinfo(
+    checker: &mut Checker,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+    location: Range,
+) {
+    let call_path = dealias_call_path(collect_call_paths(func), &checker.import_aliases);
+    if !match_call_path(&call_path, "datetime", "time", &checker.from_imports) {
+        return;
+    }
+
+    // No positional arg: keyword is missing or constant None.
+    if args.len() < 5 && !has_non_none_keyword(keywords, "tzinfo") {
+        checker.checks.push(Check::new(
+            violations::CallDatetimeTimeWithoutTzinfo,
+            location,
+        ));
+        return;
+    }
+
+    // Positional arg: is constant None.
+    if args.len() >= 5 && is_const_none(&args[4]) {
+        checker.checks.push(Check::new(
+            violations::CallDatetimeTimeWithoutTzinfo,
+            location,
+        ));
+    }
+}
+
+/// DTZ014
+pub fn call_datetime_astimezone_on_naive_datetime(
+    checker: &mut Checker,
+    value: &Expr,
+    location: Range,
+) {
+    let ExprKind::Name { id, .. } = &value.node else {
+        return;
+    };
+
+    let Some(binding) = checker.find_binding(id) else {
+        return;
+    };
+    if !matches!(binding.kind, BindingKind::Assignment) {
+        return;
+    }
+
+    let Some(source) = &binding.source else {
+        return;
+    };
+    let StmtKind::Assign { value, .. } = &source.0.node else {
+        return;
+    };
+    let ExprKind::Call { func, args, keywords } = &value.node else {
+        return;
+    };
+
+    if is_naive_datetime_call(checker, func, args, keywords) {
+        checker.checks.push(Check::new(
+            violations::CallDatetimeAstimezoneOnNaiveDatetime,
+            location,
+        ));
+    }
+}
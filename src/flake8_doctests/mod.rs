@@ -0,0 +1,22 @@
+pub mod plugins;
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::linter::test_path;
+    use crate::registry::CheckCode;
+    use crate::settings;
+
+    #[test]
+    fn doc001() -> Result<()> {
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/flake8_doctests/DOC001.py"),
+            &settings::Settings::for_rule(CheckCode::DOC001),
+        )?;
+        insta::assert_yaml_snapshot!("DOC001", checks);
+        Ok(())
+    }
+}
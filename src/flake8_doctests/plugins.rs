@@ -0,0 +1,58 @@
+use rustpython_ast::Location;
+use rustpython_parser::parser;
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::docstrings::definition::Docstring;
+use crate::registry::Check;
+use crate::violations;
+
+/// DOC001
+///
+/// Extract every doctest example (a `>>>` line and any `...` continuation
+/// lines that follow it) from a docstring's body and try to parse it as a
+/// standalone Python program, flagging any that don't parse. This only
+/// catches examples with invalid syntax; it doesn't execute them or check
+/// their expected output, since doing so would require importing the module
+/// under lint and running arbitrary code.
+pub fn doctest_syntax_errors(checker: &mut Checker, docstring: &Docstring) {
+    let body = docstring.body;
+    let lines: Vec<&str> = body.lines().collect();
+
+    let mut i = 0;
+    while i < lines.len() {
+        let Some(source_line) = strip_prompt(lines[i], ">>>") else {
+            i += 1;
+            continue;
+        };
+        let start = i;
+        let mut source = format!("{source_line}\n");
+        i += 1;
+        while i < lines.len() {
+            let Some(continuation) = strip_prompt(lines[i], "...") else {
+                break;
+            };
+            source.push_str(continuation);
+            source.push('\n');
+            i += 1;
+        }
+
+        if let Err(parse_error) = parser::parse_program(&source, "<doctest>") {
+            checker.checks.push(Check::new(
+                violations::SyntaxErrorInDoctest(parse_error.to_string()),
+                Range::new(
+                    Location::new(docstring.expr.location.row() + start, 0),
+                    Location::new(docstring.expr.location.row() + start, 0),
+                ),
+            ));
+        }
+    }
+}
+
+/// Strip a doctest prompt (`>>>` or `...`) from a line, along with the single
+/// space that conventionally follows it, returning the remaining source.
+fn strip_prompt<'a>(line: &'a str, prompt: &str) -> Option<&'a str> {
+    let trimmed = line.trim_start();
+    let rest = trimmed.strip_prefix(prompt)?;
+    Some(rest.strip_prefix(' ').unwrap_or(rest))
+}
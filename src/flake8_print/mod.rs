@@ -1,4 +1,5 @@
 pub mod plugins;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -25,4 +26,19 @@ mod tests {
         insta::assert_yaml_snapshot!(snapshot, checks);
         Ok(())
     }
+
+    #[test]
+    fn allow_print_to_stderr() -> Result<()> {
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/flake8_print/T201_stderr.py"),
+            &settings::Settings {
+                flake8_print: super::settings::Settings {
+                    allow_print_to_stderr: true,
+                },
+                ..settings::Settings::for_rule(CheckCode::T201)
+            },
+        )?;
+        assert!(checks.is_empty());
+        Ok(())
+    }
 }
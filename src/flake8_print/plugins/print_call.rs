@@ -13,16 +13,22 @@ pub fn print_call(checker: &mut Checker, func: &Expr, keywords: &[Keyword]) {
     let mut check = {
         let call_path = dealias_call_path(collect_call_paths(func), &checker.import_aliases);
         if match_call_path(&call_path, "", "print", &checker.from_imports) {
-            // If the print call has a `file=` argument (that isn't `None`, `"sys.stdout"`,
-            // or `"sys.stderr"`), don't trigger T201.
+            // If the print call has a `file=` argument (that isn't `None` or `sys.stdout`),
+            // don't trigger T201. If it's `sys.stderr`, only exempt it if the user has opted
+            // in via `allow-print-to-stderr`.
             if let Some(keyword) = keywords
                 .iter()
                 .find(|keyword| keyword.node.arg.as_ref().map_or(false, |arg| arg == "file"))
             {
                 if !is_const_none(&keyword.node.value) {
                     let call_path = collect_call_paths(&keyword.node.value);
-                    if !(match_call_path(&call_path, "sys", "stdout", &checker.from_imports)
-                        || match_call_path(&call_path, "sys", "stderr", &checker.from_imports))
+                    let is_stderr =
+                        match_call_path(&call_path, "sys", "stderr", &checker.from_imports);
+                    if is_stderr && checker.settings.flake8_print.allow_print_to_stderr {
+                        return;
+                    }
+                    if !is_stderr
+                        && !match_call_path(&call_path, "sys", "stdout", &checker.from_imports)
                     {
                         return;
                     }
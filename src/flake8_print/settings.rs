@@ -0,0 +1,50 @@
+//! Settings for the `flake8-print` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "Flake8PrintOptions"
+)]
+pub struct Options {
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            # Allow `print(..., file=sys.stderr)`, since it's commonly used for
+            # diagnostic output rather than debug leftovers.
+            allow-print-to-stderr = true
+        "#
+    )]
+    /// Whether to allow `print` and `pprint` calls that explicitly write to
+    /// `sys.stderr` (e.g., `print(..., file=sys.stderr)`), rather than flagging
+    /// them via `T201` and `T203`.
+    pub allow_print_to_stderr: Option<bool>,
+}
+
+#[derive(Debug, Default, Hash)]
+pub struct Settings {
+    pub allow_print_to_stderr: bool,
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            allow_print_to_stderr: options.allow_print_to_stderr.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            allow_print_to_stderr: Some(settings.allow_print_to_stderr),
+        }
+    }
+}
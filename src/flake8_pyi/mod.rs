@@ -0,0 +1,27 @@
+pub mod plugins;
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+    use test_case::test_case;
+
+    use crate::linter::test_path;
+    use crate::registry::CheckCode;
+    use crate::settings;
+
+    #[test_case(CheckCode::PYI001, Path::new("PYI001.pyi"); "PYI001")]
+    #[test_case(CheckCode::PYI002, Path::new("PYI002.pyi"); "PYI002")]
+    fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", check_code.as_ref(), path.to_string_lossy());
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/flake8_pyi")
+                .join(path)
+                .as_path(),
+            &settings::Settings::for_rule(check_code),
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, checks);
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+//! Checks from flake8-pyi: PYI001 (docstring in stub) and PYI002 (non-empty
+//! stub body). Checks for `typing.Text` usage, quoted annotations, and
+//! duplicate union members are not implemented.
+
+use rustpython_ast::{Constant, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::docstrings::extraction::docstring_from;
+use crate::registry::Check;
+use crate::violations;
+
+/// PYI001 - docstrings are not useful in stub files, since stubs are not
+/// introspected at runtime and documentation lives alongside the
+/// implementation.
+pub fn docstring_in_stub(checker: &mut Checker, body: &[Stmt]) {
+    if let Some(docstring) = docstring_from(body) {
+        checker.checks.push(Check::new(
+            violations::DocstringInStub,
+            Range::from_located(docstring),
+        ));
+    }
+}
+
+/// PYI002 - stub function bodies should contain nothing but `...`.
+pub fn non_empty_stub_body(checker: &mut Checker, body: &[Stmt]) {
+    for stmt in body {
+        if is_docstring(stmt) || is_ellipsis(stmt) {
+            continue;
+        }
+        checker
+            .checks
+            .push(Check::new(violations::NonEmptyStubBody, Range::from_located(stmt)));
+        return;
+    }
+}
+
+fn is_docstring(stmt: &Stmt) -> bool {
+    let StmtKind::Expr { value } = &stmt.node else {
+        return false;
+    };
+    matches!(
+        value.node,
+        ExprKind::Constant {
+            value: Constant::Str(_),
+            ..
+        }
+    )
+}
+
+fn is_ellipsis(stmt: &Stmt) -> bool {
+    let StmtKind::Expr { value } = &stmt.node else {
+        return false;
+    };
+    matches!(
+        value.node,
+        ExprKind::Constant {
+            value: Constant::Ellipsis,
+            ..
+        }
+    )
+}
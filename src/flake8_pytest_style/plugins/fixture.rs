@@ -1,8 +1,8 @@
 use rustpython_ast::{Arguments, Expr, ExprKind, Location, Stmt, StmtKind};
 
 use super::helpers::{
-    get_mark_decorators, get_mark_name, is_abstractmethod_decorator, is_pytest_fixture,
-    is_pytest_yield_fixture, keyword_is_literal,
+    contains_comment, get_mark_decorators, get_mark_name, is_abstractmethod_decorator,
+    is_pytest_fixture, is_pytest_yield_fixture, keyword_is_literal,
 };
 use crate::ast::helpers::{collect_arg_names, collect_call_paths};
 use crate::ast::types::Range;
@@ -75,7 +75,7 @@ fn has_abstractmethod_decorator(decorators: &[Expr], checker: &Checker) -> bool
 fn pytest_fixture_parentheses(
     checker: &mut Checker,
     decorator: &Expr,
-    fix: Fix,
+    fix: Option<Fix>,
     preferred: &str,
     actual: &str,
 ) {
@@ -84,7 +84,9 @@ fn pytest_fixture_parentheses(
         Range::from_located(decorator),
     );
     if checker.patch(check.kind.code()) {
-        check.amend(fix);
+        if let Some(fix) = fix {
+            check.amend(fix);
+        }
     }
     checker.checks.push(check);
 }
@@ -103,11 +105,13 @@ fn check_fixture_decorator(checker: &mut Checker, func_name: &str, decorator: &E
                 && args.is_empty()
                 && keywords.is_empty()
             {
-                let fix = Fix::replacement(
-                    String::new(),
-                    func.end_location.unwrap(),
-                    decorator.end_location.unwrap(),
-                );
+                let start = func.end_location.unwrap();
+                let end = decorator.end_location.unwrap();
+                let fix = if contains_comment(start, end, checker) {
+                    None
+                } else {
+                    Some(Fix::replacement(String::new(), start, end))
+                };
                 pytest_fixture_parentheses(checker, decorator, fix, "", "()");
             }
 
@@ -138,7 +142,7 @@ fn check_fixture_decorator(checker: &mut Checker, func_name: &str, decorator: &E
                 && checker.settings.flake8_pytest_style.fixture_parentheses
             {
                 let fix = Fix::insertion("()".to_string(), decorator.end_location.unwrap());
-                pytest_fixture_parentheses(checker, decorator, fix, "()", "");
+                pytest_fixture_parentheses(checker, decorator, Some(fix), "()", "");
             }
         }
     }
@@ -1,7 +1,8 @@
 use num_traits::identities::Zero;
-use rustpython_ast::{Constant, Expr, ExprKind, Keyword};
+use rustpython_ast::{Constant, Expr, ExprKind, Keyword, Location};
 
 use crate::ast::helpers::{collect_call_paths, compose_call_path, match_module_member};
+use crate::ast::types::Range;
 use crate::checkers::ast::Checker;
 
 const ITERABLE_INITIALIZERS: &[&str] = &["dict", "frozenset", "list", "tuple", "set"];
@@ -129,6 +130,17 @@ pub fn keyword_is_literal(kw: &Keyword, literal: &str) -> bool {
     }
 }
 
+/// Returns `true` if the given range (assumed to hold nothing but
+/// whitespace, since it's an empty argument list) contains a comment.
+/// Used to avoid autofixing parentheses styles when doing so would
+/// silently delete a comment sitting inside otherwise-empty `()`.
+pub fn contains_comment(start: Location, end: Location, checker: &Checker) -> bool {
+    checker
+        .locator
+        .slice_source_code_range(&Range::new(start, end))
+        .contains('#')
+}
+
 pub fn is_empty_or_null_string(expr: &Expr) -> bool {
     match &expr.node {
         ExprKind::Constant {
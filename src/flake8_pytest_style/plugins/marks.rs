@@ -1,6 +1,6 @@
 use rustpython_ast::{Expr, ExprKind, Location};
 
-use super::helpers::{get_mark_decorators, get_mark_name};
+use super::helpers::{contains_comment, get_mark_decorators, get_mark_name};
 use crate::ast::types::Range;
 use crate::autofix::Fix;
 use crate::checkers::ast::Checker;
@@ -10,7 +10,7 @@ use crate::violations;
 fn pytest_mark_parentheses(
     checker: &mut Checker,
     decorator: &Expr,
-    fix: Fix,
+    fix: Option<Fix>,
     preferred: &str,
     actual: &str,
 ) {
@@ -23,7 +23,9 @@ fn pytest_mark_parentheses(
         Range::from_located(decorator),
     );
     if checker.patch(check.kind.code()) {
-        check.amend(fix);
+        if let Some(fix) = fix {
+            check.amend(fix);
+        }
     }
     checker.checks.push(check);
 }
@@ -40,18 +42,20 @@ fn check_mark_parentheses(checker: &mut Checker, decorator: &Expr) {
                 && args.is_empty()
                 && keywords.is_empty()
             {
-                let fix = Fix::replacement(
-                    String::new(),
-                    func.end_location.unwrap(),
-                    decorator.end_location.unwrap(),
-                );
+                let start = func.end_location.unwrap();
+                let end = decorator.end_location.unwrap();
+                let fix = if contains_comment(start, end, checker) {
+                    None
+                } else {
+                    Some(Fix::replacement(String::new(), start, end))
+                };
                 pytest_mark_parentheses(checker, decorator, fix, "", "()");
             }
         }
         _ => {
             if checker.settings.flake8_pytest_style.mark_parentheses {
                 let fix = Fix::insertion("()".to_string(), decorator.end_location.unwrap());
-                pytest_mark_parentheses(checker, decorator, fix, "()", "");
+                pytest_mark_parentheses(checker, decorator, Some(fix), "()", "");
             }
         }
     }
@@ -1,10 +1,12 @@
 use rustpython_ast::Location;
 
 use crate::ast::types::Range;
-use crate::flake8_quotes::settings::{Quote, Settings};
+use crate::autofix::Fix;
+use crate::flake8_quotes::settings::Quote;
 use crate::registry::Check;
+use crate::settings::flags;
 use crate::source_code_locator::SourceCodeLocator;
-use crate::violations;
+use crate::{violations, Settings};
 
 fn good_single(quote: &Quote) -> char {
     match quote {
@@ -47,13 +49,15 @@ pub fn quotes(
     end: Location,
     is_docstring: bool,
     settings: &Settings,
+    autofix: flags::Autofix,
 ) -> Option<Check> {
+    let quotes_settings = &settings.flake8_quotes;
     let text = locator.slice_source_code_range(&Range::new(start, end));
 
     // Remove any prefixes (e.g., remove `u` from `u"foo"`).
     let last_quote_char = text.chars().last().unwrap();
     let first_quote_char = text.find(last_quote_char).unwrap();
-    let prefix = &text[..first_quote_char].to_lowercase();
+    let prefix = &text[..first_quote_char];
     let raw_text = &text[first_quote_char..];
 
     // Determine if the string is multiline-based.
@@ -68,54 +72,107 @@ pub fn quotes(
     };
 
     if is_docstring {
-        if raw_text.contains(good_docstring(&settings.docstring_quotes)) {
+        if raw_text.contains(good_docstring(&quotes_settings.docstring_quotes)) {
             return None;
         }
 
-        Some(Check::new(
-            violations::BadQuotesDocstring(settings.docstring_quotes.clone()),
+        let mut check = Check::new(
+            violations::BadQuotesDocstring(quotes_settings.docstring_quotes.clone()),
             Range::new(start, end),
-        ))
+        );
+        // Only offer a fix for triple-quoted docstrings; single/double-quote
+        // docstrings are rare enough, and their escaping rules different
+        // enough, that it's not worth the risk of rewriting them here.
+        if is_multiline
+            && matches!(autofix, flags::Autofix::Enabled)
+            && settings.fixable.contains(check.kind.code())
+        {
+            let middle = &raw_text[3..raw_text.len() - 3];
+            let quote = good_docstring(&quotes_settings.docstring_quotes);
+            check.amend(Fix::replacement(
+                format!("{prefix}{quote}{middle}{quote}"),
+                start,
+                end,
+            ));
+        }
+        Some(check)
     } else if is_multiline {
         // If our string is or contains a known good string, ignore it.
-        if raw_text.contains(good_multiline(&settings.multiline_quotes)) {
+        if raw_text.contains(good_multiline(&quotes_settings.multiline_quotes)) {
             return None;
         }
 
         // If our string ends with a known good ending, then ignore it.
-        if raw_text.ends_with(good_multiline_ending(&settings.multiline_quotes)) {
+        if raw_text.ends_with(good_multiline_ending(&quotes_settings.multiline_quotes)) {
             return None;
         }
 
-        Some(Check::new(
-            violations::BadQuotesMultilineString(settings.multiline_quotes.clone()),
+        let mut check = Check::new(
+            violations::BadQuotesMultilineString(quotes_settings.multiline_quotes.clone()),
             Range::new(start, end),
-        ))
+        );
+        if matches!(autofix, flags::Autofix::Enabled) && settings.fixable.contains(check.kind.code())
+        {
+            let middle = &raw_text[3..raw_text.len() - 3];
+            let quote = good_multiline(&quotes_settings.multiline_quotes);
+            check.amend(Fix::replacement(
+                format!("{prefix}{quote}{middle}{quote}"),
+                start,
+                end,
+            ));
+        }
+        Some(check)
     } else {
         let string_contents = &raw_text[1..raw_text.len() - 1];
 
         // If we're using the preferred quotation type, check for escapes.
-        if last_quote_char == good_single(&settings.inline_quotes) {
-            if !settings.avoid_escape || prefix.contains('r') {
+        if last_quote_char == good_single(&quotes_settings.inline_quotes) {
+            if !quotes_settings.avoid_escape || prefix.to_lowercase().contains('r') {
                 return None;
             }
-            if string_contents.contains(good_single(&settings.inline_quotes))
-                && !string_contents.contains(bad_single(&settings.inline_quotes))
+            if string_contents.contains(good_single(&quotes_settings.inline_quotes))
+                && !string_contents.contains(bad_single(&quotes_settings.inline_quotes))
             {
-                return Some(Check::new(
-                    violations::AvoidQuoteEscape,
-                    Range::new(start, end),
-                ));
+                let mut check = Check::new(violations::AvoidQuoteEscape, Range::new(start, end));
+                if matches!(autofix, flags::Autofix::Enabled)
+                    && settings.fixable.contains(check.kind.code())
+                {
+                    // Switch to the other quote style, which lets us drop the
+                    // now-unnecessary escapes on the preferred quote type.
+                    let good_quote = good_single(&quotes_settings.inline_quotes);
+                    let new_quote = bad_single(&quotes_settings.inline_quotes);
+                    let unescaped =
+                        string_contents.replace(&format!("\\{good_quote}"), &good_quote.to_string());
+                    check.amend(Fix::replacement(
+                        format!("{prefix}{new_quote}{unescaped}{new_quote}"),
+                        start,
+                        end,
+                    ));
+                }
+                return Some(check);
             }
             return None;
         }
 
         // If we're not using the preferred type, only allow use to avoid escapes.
-        if !string_contents.contains(good_single(&settings.inline_quotes)) {
-            return Some(Check::new(
-                violations::BadQuotesInlineString(settings.inline_quotes.clone()),
+        if !string_contents.contains(good_single(&quotes_settings.inline_quotes)) {
+            let mut check = Check::new(
+                violations::BadQuotesInlineString(quotes_settings.inline_quotes.clone()),
                 Range::new(start, end),
-            ));
+            );
+            if matches!(autofix, flags::Autofix::Enabled) && settings.fixable.contains(check.kind.code())
+            {
+                // The string doesn't contain the preferred quote character, so
+                // it's always safe to swap the delimiter without touching the
+                // contents.
+                let new_quote = good_single(&quotes_settings.inline_quotes);
+                check.amend(Fix::replacement(
+                    format!("{prefix}{new_quote}{string_contents}{new_quote}"),
+                    start,
+                    end,
+                ));
+            }
+            return Some(check);
         }
 
         None
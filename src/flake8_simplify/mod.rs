@@ -16,11 +16,14 @@ mod tests {
     #[test_case(CheckCode::SIM102, Path::new("SIM102.py"); "SIM102")]
     #[test_case(CheckCode::SIM103, Path::new("SIM103.py"); "SIM103")]
     #[test_case(CheckCode::SIM105, Path::new("SIM105.py"); "SIM105")]
+    #[test_case(CheckCode::SIM105, Path::new("SIM105_1.py"); "SIM105_1")]
     #[test_case(CheckCode::SIM107, Path::new("SIM107.py"); "SIM107")]
     #[test_case(CheckCode::SIM108, Path::new("SIM108.py"); "SIM108")]
     #[test_case(CheckCode::SIM109, Path::new("SIM109.py"); "SIM109")]
     #[test_case(CheckCode::SIM110, Path::new("SIM110.py"); "SIM110")]
     #[test_case(CheckCode::SIM111, Path::new("SIM111.py"); "SIM111")]
+    #[test_case(CheckCode::SIM112, Path::new("SIM112.py"); "SIM112")]
+    #[test_case(CheckCode::SIM115, Path::new("SIM115.py"); "SIM115")]
     #[test_case(CheckCode::SIM117, Path::new("SIM117.py"); "SIM117")]
     #[test_case(CheckCode::SIM201, Path::new("SIM201.py"); "SIM201")]
     #[test_case(CheckCode::SIM202, Path::new("SIM202.py"); "SIM202")]
@@ -34,6 +37,7 @@ mod tests {
     #[test_case(CheckCode::SIM222, Path::new("SIM222.py"); "SIM222")]
     #[test_case(CheckCode::SIM223, Path::new("SIM223.py"); "SIM223")]
     #[test_case(CheckCode::SIM300, Path::new("SIM300.py"); "SIM300")]
+    #[test_case(CheckCode::SIM401, Path::new("SIM401.py"); "SIM401")]
     fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", check_code.as_ref(), path.to_string_lossy());
         let checks = test_path(
@@ -0,0 +1,79 @@
+use rustpython_ast::{Constant, Expr, ExprKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::violations;
+
+/// Return `true` if `expr` is a reference to `os.environ`.
+fn is_os_environ(expr: &Expr) -> bool {
+    let ExprKind::Attribute { value, attr, .. } = &expr.node else {
+        return false;
+    };
+    if attr != "environ" {
+        return false;
+    }
+    matches!(&value.node, ExprKind::Name { id, .. } if id == "os")
+}
+
+/// Return `true` if `expr` is a reference to `os.getenv`.
+fn is_os_getenv(expr: &Expr) -> bool {
+    let ExprKind::Attribute { value, attr, .. } = &expr.node else {
+        return false;
+    };
+    if attr != "getenv" {
+        return false;
+    }
+    matches!(&value.node, ExprKind::Name { id, .. } if id == "os")
+}
+
+fn check_name(checker: &mut Checker, name_expr: &Expr) {
+    let ExprKind::Constant {
+        value: Constant::Str(name),
+        ..
+    } = &name_expr.node
+    else {
+        return;
+    };
+    if !name.chars().any(char::is_lowercase) {
+        return;
+    }
+    checker.checks.push(Check::new(
+        violations::UseCapitalEnvironmentVariables(name.clone(), name.to_uppercase()),
+        Range::from_located(name_expr),
+    ));
+}
+
+/// SIM112 in `os.environ[...]`
+pub fn use_capitalized_environment_variables_subscript(
+    checker: &mut Checker,
+    value: &Expr,
+    slice: &Expr,
+) {
+    if !is_os_environ(value) {
+        return;
+    }
+    check_name(checker, slice);
+}
+
+/// SIM112 in `os.environ.get(...)` and `os.getenv(...)`
+pub fn use_capitalized_environment_variables_call(
+    checker: &mut Checker,
+    func: &Expr,
+    args: &[Expr],
+) {
+    let Some(name) = args.first() else {
+        return;
+    };
+
+    if let ExprKind::Attribute { value, attr, .. } = &func.node {
+        if attr == "get" && is_os_environ(value) {
+            check_name(checker, name);
+        }
+        return;
+    }
+
+    if is_os_getenv(func) {
+        check_name(checker, name);
+    }
+}
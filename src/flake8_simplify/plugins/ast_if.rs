@@ -1,12 +1,23 @@
-use rustpython_ast::{Constant, Expr, ExprKind, Stmt, StmtKind};
+use rustpython_ast::{Boolop, Cmpop, Constant, Expr, ExprKind, Stmt, StmtKind};
+use rustpython_parser::lexer;
+use rustpython_parser::lexer::Tok;
 
 use crate::ast::helpers::{create_expr, create_stmt, unparse_expr, unparse_stmt};
 use crate::ast::types::Range;
 use crate::autofix::Fix;
 use crate::checkers::ast::Checker;
 use crate::registry::{Check, CheckCode};
+use crate::source_code_locator::SourceCodeLocator;
 use crate::violations;
 
+/// Return `true` if the range contains a comment token.
+fn has_comments(range: &Range, locator: &SourceCodeLocator) -> bool {
+    let contents = locator.slice_source_code_range(range);
+    lexer::make_tokenizer_located(&contents, range.location)
+        .flatten()
+        .any(|(_, tok, _)| matches!(tok, Tok::Comment(..)))
+}
+
 fn is_main_check(expr: &Expr) -> bool {
     if let ExprKind::Compare {
         left, comparators, ..
@@ -60,10 +71,38 @@ pub fn nested_if_statements(checker: &mut Checker, stmt: &Stmt) {
         return;
     }
 
-    checker.checks.push(Check::new(
-        violations::NestedIfStatements,
-        Range::from_located(stmt),
-    ));
+    let mut check = Check::new(violations::NestedIfStatements, Range::from_located(stmt));
+    if checker.patch(&CheckCode::SIM102) {
+        let inner_if = &body[0];
+        let StmtKind::If {
+            test: inner_test,
+            body: inner_body,
+            ..
+        } = &inner_if.node
+        else {
+            unreachable!("Expected StmtKind::If");
+        };
+
+        // Bail out if there's a comment anywhere in the statement, since we can't
+        // faithfully preserve it while collapsing the two `if`s into one.
+        if !has_comments(&Range::from_located(stmt), checker.locator) {
+            let collapsed_test = create_expr(ExprKind::BoolOp {
+                op: Boolop::And,
+                values: vec![test.clone(), inner_test.clone()],
+            });
+            let collapsed_if = create_stmt(StmtKind::If {
+                test: Box::new(collapsed_test),
+                body: inner_body.clone(),
+                orelse: vec![],
+            });
+            check.amend(Fix::replacement(
+                unparse_stmt(&collapsed_if, checker.style),
+                stmt.location,
+                stmt.end_location.unwrap(),
+            ));
+        }
+    }
+    checker.checks.push(check);
 }
 
 fn is_one_line_return_bool(stmts: &[Stmt]) -> bool {
@@ -191,3 +230,102 @@ pub fn use_ternary_operator(checker: &mut Checker, stmt: &Stmt, parent: Option<&
     }
     checker.checks.push(check);
 }
+
+/// SIM401
+pub fn use_dict_get_with_default(checker: &mut Checker, stmt: &Stmt) {
+    let StmtKind::If { test, body, orelse } = &stmt.node else {
+        return;
+    };
+    if body.len() != 1 || orelse.len() != 1 {
+        return;
+    }
+    let ExprKind::Compare { left: test_key, ops, comparators } = &test.node else {
+        return;
+    };
+    if ops.len() != 1 || comparators.len() != 1 {
+        return;
+    }
+    if !matches!(ops[0], Cmpop::In | Cmpop::NotIn) {
+        return;
+    }
+    let test_dict = &comparators[0];
+
+    let StmtKind::Assign { targets: body_targets, value: body_value, .. } = &body[0].node else {
+        return;
+    };
+    let StmtKind::Assign { targets: orelse_targets, value: orelse_value, .. } = &orelse[0].node else {
+        return;
+    };
+    if body_targets.len() != 1 || orelse_targets.len() != 1 {
+        return;
+    }
+    let ExprKind::Name { id: body_id, .. } = &body_targets[0].node else {
+        return;
+    };
+    let ExprKind::Name { id: orelse_id, .. } = &orelse_targets[0].node else {
+        return;
+    };
+    if body_id != orelse_id {
+        return;
+    }
+
+    // `if key in d: x = d[key] else: x = default`, or the `key not in d` mirror.
+    let (index_value, default_value) = if matches!(ops[0], Cmpop::In) {
+        (body_value, orelse_value)
+    } else {
+        (orelse_value, body_value)
+    };
+
+    let ExprKind::Subscript { value: index_dict, slice: index_key, .. } = &index_value.node else {
+        return;
+    };
+
+    let key_content = checker
+        .locator
+        .slice_source_code_range(&Range::from_located(test_key))
+        .to_string();
+    let dict_content = checker
+        .locator
+        .slice_source_code_range(&Range::from_located(test_dict))
+        .to_string();
+    if checker
+        .locator
+        .slice_source_code_range(&Range::from_located(index_key))
+        .as_ref()
+        != key_content.as_str()
+        || checker
+            .locator
+            .slice_source_code_range(&Range::from_located(index_dict))
+            .as_ref()
+            != dict_content.as_str()
+    {
+        return;
+    }
+
+    let target_var = checker
+        .locator
+        .slice_source_code_range(&Range::from_located(&body_targets[0]))
+        .to_string();
+    let default_content = checker
+        .locator
+        .slice_source_code_range(&Range::from_located(default_value))
+        .to_string();
+
+    let mut check = Check::new(
+        violations::UseDictGetWithDefault(
+            target_var.clone(),
+            dict_content.clone(),
+            key_content.clone(),
+            default_content.clone(),
+        ),
+        Range::from_located(stmt),
+    );
+    if checker.patch(&CheckCode::SIM401) {
+        check.amend(Fix::replacement(
+            format!("{target_var} = {dict_content}.get({key_content}, {default_content})"),
+            stmt.location,
+            stmt.end_location.unwrap(),
+        ));
+    }
+    checker.checks.push(check);
+}
@@ -0,0 +1,24 @@
+use rustpython_ast::{Expr, ExprKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::violations;
+
+/// SIM115
+pub fn open_file_with_context_handler(checker: &mut Checker, value: &Expr) {
+    let ExprKind::Call { func, .. } = &value.node else {
+        return;
+    };
+    let ExprKind::Name { id, .. } = &func.node else {
+        return;
+    };
+    if id != "open" {
+        return;
+    }
+
+    checker.checks.push(Check::new(
+        violations::UseContextManagerForOpen,
+        Range::from_located(value),
+    ));
+}
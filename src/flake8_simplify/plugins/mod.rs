@@ -1,11 +1,18 @@
 pub use ast_bool_op::{
     a_and_not_a, a_or_not_a, and_false, compare_with_tuple, duplicate_isinstance_call, or_true,
 };
+pub use ast_env::{
+    use_capitalized_environment_variables_call, use_capitalized_environment_variables_subscript,
+};
 pub use ast_for::convert_loop_to_any_all;
-pub use ast_if::{nested_if_statements, return_bool_condition_directly, use_ternary_operator};
+pub use ast_if::{
+    nested_if_statements, return_bool_condition_directly, use_dict_get_with_default,
+    use_ternary_operator,
+};
 pub use ast_ifexp::{
     explicit_false_true_in_ifexpr, explicit_true_false_in_ifexpr, twisted_arms_in_ifexpr,
 };
+pub use ast_open::open_file_with_context_handler;
 pub use ast_unary_op::{double_negation, negation_with_equal_op, negation_with_not_equal_op};
 pub use ast_with::multiple_with_statements;
 pub use key_in_dict::{key_in_dict_compare, key_in_dict_for};
@@ -14,9 +21,11 @@ pub use use_contextlib_suppress::use_contextlib_suppress;
 pub use yoda_conditions::yoda_conditions;
 
 mod ast_bool_op;
+mod ast_env;
 mod ast_for;
 mod ast_if;
 mod ast_ifexp;
+mod ast_open;
 mod ast_unary_op;
 mod ast_with;
 mod key_in_dict;
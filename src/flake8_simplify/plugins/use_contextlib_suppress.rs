@@ -1,11 +1,25 @@
 use rustpython_ast::{Excepthandler, ExcepthandlerKind, Stmt, StmtKind};
 
 use crate::ast::helpers;
-use crate::ast::types::Range;
+use crate::ast::types::{BindingKind, Range};
+use crate::ast::whitespace::indentation;
+use crate::autofix::Fix;
 use crate::checkers::ast::Checker;
-use crate::registry::Check;
+use crate::registry::{Check, CheckCode};
 use crate::violations;
 
+/// Return `true` if `contextlib` is bound in the current module, so that a
+/// `with contextlib.suppress(...)` replacement won't reference an
+/// unresolvable name.
+fn contextlib_is_imported(checker: &Checker) -> bool {
+    checker.find_binding("contextlib").map_or(false, |binding| {
+        matches!(
+            &binding.kind,
+            BindingKind::Importation(.., full_name) if full_name == "contextlib"
+        )
+    })
+}
+
 /// SIM105
 pub fn use_contextlib_suppress(
     checker: &mut Checker,
@@ -30,10 +44,27 @@ pub fn use_contextlib_suppress(
             } else {
                 handler_names.join(", ")
             };
-            let check = Check::new(
-                violations::UseContextlibSuppress(exception),
+            let mut check = Check::new(
+                violations::UseContextlibSuppress(exception.clone()),
                 Range::from_located(stmt),
             );
+            if checker.patch(&CheckCode::SIM105) && contextlib_is_imported(checker) {
+                let StmtKind::Try { body: try_body, .. } = &stmt.node else {
+                    unreachable!("Expected StmtKind::Try");
+                };
+                if let (Some(first), Some(last)) = (try_body.first(), try_body.last()) {
+                    let body_indent = indentation(checker, first);
+                    let body_range = Range::new(first.location, last.end_location.unwrap());
+                    let body_content = checker.locator.slice_source_code_range(&body_range);
+                    check.amend(Fix::replacement(
+                        format!(
+                            "with contextlib.suppress({exception}):\n{body_indent}{body_content}"
+                        ),
+                        stmt.location,
+                        stmt.end_location.unwrap(),
+                    ));
+                }
+            }
             checker.checks.push(check);
         }
     }
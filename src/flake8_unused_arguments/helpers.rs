@@ -1,4 +1,8 @@
-use rustpython_ast::{Constant, ExprKind, Stmt, StmtKind};
+use rustc_hash::FxHashSet;
+use rustpython_ast::{Constant, Expr, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::{BindingKind, ClassDef, Scope, ScopeKind};
+use crate::checkers::ast::Checker;
 
 pub fn is_empty(body: &[Stmt]) -> bool {
     match &body {
@@ -17,3 +21,45 @@ pub fn is_empty(body: &[Stmt]) -> bool {
         _ => false,
     }
 }
+
+/// Return the local name of a base class expression (e.g., `Base` for both
+/// `Base` and `module.Base`).
+fn base_class_name(expr: &Expr) -> Option<&str> {
+    match &expr.node {
+        ExprKind::Name { id, .. } => Some(id),
+        ExprKind::Attribute { attr, .. } => Some(attr),
+        _ => None,
+    }
+}
+
+/// Return `true` if `method_name` overrides a method defined on a base class
+/// within the same module, per the class hierarchy visible to the checker.
+/// Base classes that aren't defined in the current module (e.g., imports, or
+/// dynamically-constructed classes) are ignored.
+pub fn is_overridden_method(checker: &Checker, parent: &Scope, method_name: &str) -> bool {
+    let ScopeKind::Class(ClassDef { bases, .. }) = &parent.kind else {
+        return false;
+    };
+
+    let mut seen: FxHashSet<&str> = FxHashSet::default();
+    let mut queue: Vec<&str> = bases.iter().filter_map(base_class_name).collect();
+    while let Some(base_name) = queue.pop() {
+        if !seen.insert(base_name) {
+            continue;
+        }
+        let Some(base_scope) = checker.scopes.iter().find(|scope| {
+            matches!(&scope.kind, ScopeKind::Class(ClassDef { name, .. }) if *name == base_name)
+        }) else {
+            continue;
+        };
+        if base_scope.values.get(method_name).map_or(false, |index| {
+            matches!(checker.bindings[*index].kind, BindingKind::FunctionDefinition)
+        }) {
+            return true;
+        }
+        if let ScopeKind::Class(ClassDef { bases, .. }) = &base_scope.kind {
+            queue.extend(bases.iter().filter_map(base_class_name));
+        }
+    }
+    false
+}
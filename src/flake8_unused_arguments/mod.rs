@@ -53,6 +53,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn ignore_overridden_methods() -> Result<()> {
+        let checks = test_path(
+            Path::new(
+                "./resources/test/fixtures/flake8_unused_arguments/ignore_overridden_methods.py",
+            ),
+            &settings::Settings {
+                flake8_unused_arguments: flake8_unused_arguments::settings::Settings {
+                    ignore_overridden_methods: true,
+                    ..Default::default()
+                },
+                ..settings::Settings::for_rules(vec![CheckCode::ARG002])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
+
+    #[test]
+    fn enforce_overridden_methods() -> Result<()> {
+        let checks = test_path(
+            Path::new(
+                "./resources/test/fixtures/flake8_unused_arguments/ignore_overridden_methods.py",
+            ),
+            &settings::Settings {
+                flake8_unused_arguments: flake8_unused_arguments::settings::Settings {
+                    ignore_overridden_methods: false,
+                    ..Default::default()
+                },
+                ..settings::Settings::for_rules(vec![CheckCode::ARG002])
+            },
+        )?;
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
+
     #[test]
     fn enforce_variadic_names() -> Result<()> {
         let checks = test_path(
@@ -156,6 +156,11 @@ pub fn unused_arguments(
                         && !visibility::is_abstract(checker, decorator_list)
                         && !visibility::is_override(checker, decorator_list)
                         && !visibility::is_overload(checker, decorator_list)
+                        && !(checker
+                            .settings
+                            .flake8_unused_arguments
+                            .ignore_overridden_methods
+                            && helpers::is_overridden_method(checker, parent, name))
                     {
                         method(
                             &Argumentable::Method,
@@ -181,6 +186,11 @@ pub fn unused_arguments(
                         && !visibility::is_abstract(checker, decorator_list)
                         && !visibility::is_override(checker, decorator_list)
                         && !visibility::is_overload(checker, decorator_list)
+                        && !(checker
+                            .settings
+                            .flake8_unused_arguments
+                            .ignore_overridden_methods
+                            && helpers::is_overridden_method(checker, parent, name))
                     {
                         method(
                             &Argumentable::ClassMethod,
@@ -206,6 +216,11 @@ pub fn unused_arguments(
                         && !visibility::is_abstract(checker, decorator_list)
                         && !visibility::is_override(checker, decorator_list)
                         && !visibility::is_overload(checker, decorator_list)
+                        && !(checker
+                            .settings
+                            .flake8_unused_arguments
+                            .ignore_overridden_methods
+                            && helpers::is_overridden_method(checker, parent, name))
                     {
                         function(
                             &Argumentable::StaticMethod,
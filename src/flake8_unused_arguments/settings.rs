@@ -20,17 +20,29 @@ pub struct Options {
     )]
     /// Whether to allow unused variadic arguments, like `*args` and `**kwargs`.
     pub ignore_variadic_names: Option<bool>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = "ignore-overridden-methods = true"
+    )]
+    /// Whether to skip unused-argument checks (`ARG002`, `ARG003`) for
+    /// methods that override a method defined on a base class within the
+    /// same module, since such arguments are often required by the base
+    /// class's interface, even if the override doesn't use them.
+    pub ignore_overridden_methods: Option<bool>,
 }
 
 #[derive(Debug, Default, Hash)]
 pub struct Settings {
     pub ignore_variadic_names: bool,
+    pub ignore_overridden_methods: bool,
 }
 
 impl From<Options> for Settings {
     fn from(options: Options) -> Self {
         Self {
             ignore_variadic_names: options.ignore_variadic_names.unwrap_or_default(),
+            ignore_overridden_methods: options.ignore_overridden_methods.unwrap_or_default(),
         }
     }
 }
@@ -39,6 +51,7 @@ impl From<Settings> for Options {
     fn from(settings: Settings) -> Self {
         Self {
             ignore_variadic_names: Some(settings.ignore_variadic_names),
+            ignore_overridden_methods: Some(settings.ignore_overridden_methods),
         }
     }
 }
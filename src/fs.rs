@@ -3,12 +3,15 @@ use std::fs::File;
 use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use globset::GlobMatcher;
+use once_cell::sync::Lazy;
 use path_absolutize::{path_dedot, Absolutize};
+use regex::Regex;
 use rustc_hash::FxHashSet;
 
 use crate::registry::CheckCode;
+use crate::settings::Settings;
 
 /// Extract the absolute path and basename (as strings) from a Path.
 pub fn extract_path_names(path: &Path) -> Result<(&str, &str)> {
@@ -65,7 +68,56 @@ pub fn relativize_path(path: &Path) -> Cow<str> {
     path.to_string_lossy()
 }
 
-/// Read a file's contents from disk.
+/// A PEP 263 encoding declaration: `# -*- coding: <encoding-name> -*-`, or any
+/// of the other forms `source.encoding` in CPython recognizes (e.g., emacs'
+/// `# coding: <encoding-name>`).
+static CODING_COOKIE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[ \t\f]*#.*?coding[:=][ \t]*([-_.a-zA-Z0-9]+)").unwrap());
+
+/// Single-byte encodings for which decoding is a direct byte-to-codepoint
+/// mapping, so we can always decode them without a dedicated encoding crate.
+fn is_latin1_alias(name: &str) -> bool {
+    matches!(
+        name.to_lowercase().replace(['-', '_'], "").as_str(),
+        "latin1" | "iso88591" | "cp1252" | "windows1252" | "l1"
+    )
+}
+
+/// Return the encoding declared via a PEP 263 coding cookie on the first two
+/// lines of `bytes`, if any. The cookie itself is always ASCII, so this is
+/// safe to scan for even when the rest of the file isn't valid UTF-8.
+fn declared_encoding(bytes: &[u8]) -> Option<String> {
+    bytes
+        .split(|&b| b == b'\n')
+        .take(2)
+        .find_map(|line| CODING_COOKIE.captures(&String::from_utf8_lossy(line)))
+        .map(|captures| captures[1].to_string())
+}
+
+/// Decode `bytes` as source code, honoring a PEP 263 coding cookie and
+/// stripping a leading UTF-8 byte-order mark. Falls back to Latin-1 (which
+/// can decode any byte sequence) if `settings.latin1_fallback` is set and no
+/// coding cookie is present; a coding cookie that names Latin-1 (or an
+/// alias, like `cp1252`) is always honored, regardless of the setting.
+fn decode_source(bytes: &[u8], settings: &Settings) -> Result<String> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    if let Ok(contents) = std::str::from_utf8(bytes) {
+        return Ok(contents.to_string());
+    }
+    match declared_encoding(bytes) {
+        Some(encoding) if is_latin1_alias(&encoding) => {
+            Ok(bytes.iter().map(|&byte| byte as char).collect())
+        }
+        Some(encoding) => bail!(
+            "stream did not contain valid UTF-8, and declared encoding `{encoding}` isn't \
+             supported"
+        ),
+        None if settings.latin1_fallback => Ok(bytes.iter().map(|&byte| byte as char).collect()),
+        None => bail!("stream did not contain valid UTF-8"),
+    }
+}
+
+/// Read a file's contents from disk as UTF-8.
 pub(crate) fn read_file<P: AsRef<Path>>(path: P) -> Result<String> {
     let file = File::open(path)?;
     let mut buf_reader = BufReader::new(file);
@@ -73,3 +125,16 @@ pub(crate) fn read_file<P: AsRef<Path>>(path: P) -> Result<String> {
     buf_reader.read_to_string(&mut contents)?;
     Ok(contents)
 }
+
+/// Read a Python source file's contents from disk, honoring a PEP 263
+/// coding cookie (and an optional Latin-1 fallback) instead of failing
+/// outright on non-UTF-8 bytes. Distinct from `read_file`, which is used for
+/// reading Ruff's own configuration files and has no reason to guess at an
+/// encoding.
+pub(crate) fn read_python_source<P: AsRef<Path>>(path: P, settings: &Settings) -> Result<String> {
+    let file = File::open(path)?;
+    let mut buf_reader = BufReader::new(file);
+    let mut bytes = Vec::new();
+    buf_reader.read_to_end(&mut bytes)?;
+    decode_source(&bytes, settings)
+}
@@ -0,0 +1,28 @@
+pub mod plugins;
+
+#[cfg(test)]
+mod tests {
+    use std::convert::AsRef;
+    use std::path::Path;
+
+    use anyhow::Result;
+    use test_case::test_case;
+
+    use crate::linter::test_path;
+    use crate::registry::CheckCode;
+    use crate::settings;
+
+    #[test_case(CheckCode::FURB105, Path::new("FURB105.py"); "FURB105")]
+    #[test_case(CheckCode::FURB110, Path::new("FURB110.py"); "FURB110")]
+    fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", check_code.as_ref(), path.to_string_lossy());
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/furb")
+                .join(path)
+                .as_path(),
+            &settings::Settings::for_rule(check_code),
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, checks);
+        Ok(())
+    }
+}
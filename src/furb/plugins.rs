@@ -0,0 +1,69 @@
+use rustpython_ast::{Constant, Expr, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::autofix::Fix;
+use crate::checkers::ast::Checker;
+use crate::registry::{Check, CheckCode};
+use crate::violations;
+
+/// FURB105 - `float("inf")` and `float("-inf")` can be written as the
+/// `math.inf` constant.
+pub fn use_math_inf(checker: &mut Checker, expr: &Expr, func: &Expr, args: &[Expr]) {
+    let ExprKind::Name { id, .. } = &func.node else {
+        return;
+    };
+    if id != "float" || args.len() != 1 {
+        return;
+    }
+    let ExprKind::Constant {
+        value: Constant::Str(value),
+        ..
+    } = &args[0].node
+    else {
+        return;
+    };
+    let replacement = match value.as_str() {
+        "inf" => "math.inf",
+        "-inf" => "-math.inf",
+        _ => return,
+    };
+    let mut check = Check::new(violations::FloatInfLiteral, Range::from_located(expr));
+    if checker.patch(&CheckCode::FURB105) {
+        check.amend(Fix::replacement(
+            replacement.to_string(),
+            expr.location,
+            expr.end_location.unwrap(),
+        ));
+    }
+    checker.checks.push(check);
+}
+
+/// FURB110 - an `if`/`else` that only chooses between `d[key]` and a
+/// default value can be written as `d.get(key, default)`.
+pub fn if_else_dict_get(checker: &mut Checker, stmt: &Stmt) {
+    let StmtKind::If { test, body, orelse } = &stmt.node else {
+        return;
+    };
+    let ExprKind::Compare { .. } = &test.node else {
+        return;
+    };
+    let [Stmt { node: StmtKind::Assign { targets: then_targets, value: then_value, .. }, .. }] =
+        body.as_slice()
+    else {
+        return;
+    };
+    let [Stmt { node: StmtKind::Assign { targets: else_targets, .. }, .. }] = orelse.as_slice()
+    else {
+        return;
+    };
+    if then_targets.len() != 1 || else_targets.len() != 1 {
+        return;
+    }
+    if !matches!(&then_value.node, ExprKind::Subscript { .. }) {
+        return;
+    }
+    checker.checks.push(Check::new(
+        violations::IfElseDictGet,
+        Range::from_located(stmt),
+    ));
+}
@@ -24,6 +24,7 @@ pub mod cache;
 mod checkers;
 pub mod cli;
 mod cst;
+mod darglint;
 mod directives;
 mod docstrings;
 mod eradicate;
@@ -37,16 +38,19 @@ mod flake8_builtins;
 mod flake8_comprehensions;
 mod flake8_datetimez;
 mod flake8_debugger;
+mod flake8_doctests;
 pub mod flake8_errmsg;
 mod flake8_implicit_str_concat;
 mod flake8_import_conventions;
 mod flake8_print;
+mod flake8_pyi;
 pub mod flake8_pytest_style;
 pub mod flake8_quotes;
 mod flake8_return;
 mod flake8_simplify;
 pub mod flake8_tidy_imports;
 mod flake8_unused_arguments;
+mod furb;
 pub mod fs;
 mod isort;
 pub mod iterators;
@@ -55,15 +59,18 @@ pub mod linter;
 pub mod logging;
 pub mod mccabe;
 pub mod message;
+mod module_resolver;
 mod noqa;
+mod numpy;
 mod pandas_vet;
 pub mod pep8_naming;
+mod perflint;
 pub mod printer;
 mod pycodestyle;
 pub mod pydocstyle;
 mod pyflakes;
 mod pygrep_hooks;
-mod pylint;
+pub mod pylint;
 mod python;
 mod pyupgrade;
 pub mod registry;
@@ -74,6 +81,7 @@ pub mod settings;
 pub mod source_code_generator;
 pub mod source_code_locator;
 pub mod source_code_style;
+pub mod timing;
 mod vendor;
 mod violation;
 mod violations;
@@ -92,6 +92,7 @@ pub fn defaultSettings() -> Result<JsValue, JsValue> {
         external: Some(Vec::default()),
         ignore: Some(Vec::default()),
         line_length: Some(88),
+        tab_size: Some(8),
         select: Some(vec![CheckCodePrefix::E, CheckCodePrefix::F]),
         target_version: Some(PythonVersion::default()),
         // Ignore a bunch of options that don't make sense in a single-file editor.
@@ -3,10 +3,11 @@ use std::io;
 use std::io::Write;
 use std::ops::AddAssign;
 use std::path::Path;
+use std::sync::Arc;
 
 use anyhow::Result;
 use colored::Colorize;
-use log::debug;
+use log::{debug, warn};
 use rustpython_parser::lexer::LexResult;
 use similar::TextDiff;
 
@@ -25,7 +26,7 @@ use crate::registry::{Check, CheckCode, LintSource};
 use crate::settings::{flags, Settings};
 use crate::source_code_locator::SourceCodeLocator;
 use crate::source_code_style::SourceCodeStyleDetector;
-use crate::{cache, directives, fs, rustpython_helpers, violations};
+use crate::{cache, directives, fs, rustpython_helpers, timing, violations};
 
 const CARGO_PKG_NAME: &str = env!("CARGO_PKG_NAME");
 const CARGO_PKG_REPOSITORY: &str = env!("CARGO_PKG_REPOSITORY");
@@ -34,11 +35,19 @@ const CARGO_PKG_REPOSITORY: &str = env!("CARGO_PKG_REPOSITORY");
 pub struct Diagnostics {
     pub messages: Vec<Message>,
     pub fixed: usize,
+    /// One entry per file that couldn't be linted at all (I/O error, encoding
+    /// error, parse failure, or rule panic), describing why. Distinct from
+    /// `messages`, which holds violations found in files that _were_ linted.
+    pub failures: Vec<String>,
 }
 
 impl Diagnostics {
     pub fn new(messages: Vec<Message>) -> Self {
-        Self { messages, fixed: 0 }
+        Self {
+            messages,
+            fixed: 0,
+            failures: Vec::new(),
+        }
     }
 }
 
@@ -46,6 +55,7 @@ impl AddAssign for Diagnostics {
     fn add_assign(&mut self, other: Self) {
         self.messages.extend(other.messages);
         self.fixed += other.fixed;
+        self.failures.extend(other.failures);
     }
 }
 
@@ -76,7 +86,9 @@ pub(crate) fn check_path(
         .iter()
         .any(|check_code| matches!(check_code.lint_source(), LintSource::Tokens))
     {
-        checks.extend(check_tokens(locator, &tokens, settings, autofix));
+        checks.extend(timing::time(LintSource::Tokens, || {
+            check_tokens(locator, &tokens, settings, autofix)
+        }));
     }
 
     // Run the AST-based checks.
@@ -92,28 +104,32 @@ pub(crate) fn check_path(
         match rustpython_helpers::parse_program_tokens(tokens, "<filename>") {
             Ok(python_ast) => {
                 if use_ast {
-                    checks.extend(check_ast(
-                        &python_ast,
-                        locator,
-                        stylist,
-                        &directives.noqa_line_for,
-                        settings,
-                        autofix,
-                        noqa,
-                        path,
-                    ));
+                    checks.extend(timing::time(LintSource::AST, || {
+                        check_ast(
+                            &python_ast,
+                            locator,
+                            stylist,
+                            &directives.noqa_line_for,
+                            settings,
+                            autofix,
+                            noqa,
+                            path,
+                        )
+                    }));
                 }
                 if use_imports {
-                    checks.extend(check_imports(
-                        &python_ast,
-                        locator,
-                        &directives.isort,
-                        settings,
-                        stylist,
-                        autofix,
-                        path,
-                        package,
-                    ));
+                    checks.extend(timing::time(LintSource::Imports, || {
+                        check_imports(
+                            &python_ast,
+                            locator,
+                            &directives.isort,
+                            settings,
+                            stylist,
+                            autofix,
+                            path,
+                            package,
+                        )
+                    }));
                 }
             }
             Err(parse_error) => {
@@ -123,6 +139,49 @@ pub(crate) fn check_path(
                         Range::new(parse_error.location, parse_error.location),
                     ));
                 }
+
+                // `rustpython_parser` doesn't support error recovery, so a
+                // single syntax error otherwise means abandoning AST-based
+                // checks for the entire file. As a partial mitigation
+                // (most useful when linting a file mid-edit, e.g. via an
+                // LSP), retry against the prefix of the file that precedes
+                // the error, so we can still surface diagnostics for the
+                // unaffected portion of the file rather than none at all.
+                if let Some(prefix) = contents_before_line(contents, parse_error.location.row()) {
+                    let prefix_tokens = rustpython_helpers::tokenize(prefix);
+                    if let Ok(python_ast) =
+                        rustpython_helpers::parse_program_tokens(prefix_tokens, "<filename>")
+                    {
+                        if use_ast {
+                            checks.extend(timing::time(LintSource::AST, || {
+                                check_ast(
+                                    &python_ast,
+                                    locator,
+                                    stylist,
+                                    &directives.noqa_line_for,
+                                    settings,
+                                    autofix,
+                                    noqa,
+                                    path,
+                                )
+                            }));
+                        }
+                        if use_imports {
+                            checks.extend(timing::time(LintSource::Imports, || {
+                                check_imports(
+                                    &python_ast,
+                                    locator,
+                                    &directives.isort,
+                                    settings,
+                                    stylist,
+                                    autofix,
+                                    path,
+                                    package,
+                                )
+                            }));
+                        }
+                    }
+                }
             }
         }
     }
@@ -133,29 +192,29 @@ pub(crate) fn check_path(
         .iter()
         .any(|check_code| matches!(check_code.lint_source(), LintSource::Lines))
     {
-        checks.extend(check_lines(
-            contents,
-            &directives.commented_lines,
-            settings,
-            autofix,
-        ));
+        checks.extend(timing::time(LintSource::Lines, || {
+            check_lines(contents, &directives.commented_lines, settings, autofix)
+        }));
     }
 
     // Enforce `noqa` directives.
-    if matches!(noqa, flags::Noqa::Enabled)
+    if matches!(noqa, flags::Noqa::Enabled | flags::Noqa::Ignored)
         || settings
             .enabled
             .iter()
             .any(|check_code| matches!(check_code.lint_source(), LintSource::NoQA))
     {
-        check_noqa(
-            &mut checks,
-            contents,
-            &directives.commented_lines,
-            &directives.noqa_line_for,
-            settings,
-            autofix,
-        );
+        timing::time(LintSource::NoQA, || {
+            check_noqa(
+                &mut checks,
+                contents,
+                &directives.commented_lines,
+                &directives.noqa_line_for,
+                settings,
+                autofix,
+                noqa,
+            );
+        });
     }
 
     // Create path ignores.
@@ -172,6 +231,26 @@ pub(crate) fn check_path(
     Ok(checks)
 }
 
+/// Return the slice of `contents` consisting of every complete line before
+/// (1-indexed) line `row`, or `None` if there's no such non-empty prefix.
+/// The returned slice shares `contents`'s byte offsets, so locations
+/// computed by re-tokenizing it still line up with the original file.
+fn contents_before_line(contents: &str, row: usize) -> Option<&str> {
+    if row <= 1 {
+        return None;
+    }
+    let end: usize = contents
+        .split_inclusive('\n')
+        .take(row - 1)
+        .map(str::len)
+        .sum();
+    if end == 0 {
+        None
+    } else {
+        Some(&contents[..end])
+    }
+}
+
 const MAX_ITERATIONS: usize = 100;
 
 /// Lint the source code at the given `Path`.
@@ -180,6 +259,7 @@ pub fn lint_path(
     package: Option<&Path>,
     settings: &Settings,
     cache: flags::Cache,
+    noqa: flags::Noqa,
     autofix: fixer::Mode,
 ) -> Result<Diagnostics> {
     // Validate the `Settings` and return any errors.
@@ -205,7 +285,7 @@ pub fn lint_path(
     };
 
     // Read the file from disk.
-    let contents = fs::read_file(path)?;
+    let contents = fs::read_python_source(path, settings)?;
 
     // Lint the file.
     let (messages, fixed) = if matches!(autofix, fixer::Mode::Apply | fixer::Mode::Diff) {
@@ -225,7 +305,7 @@ pub fn lint_path(
         }
         (messages, fixed)
     } else {
-        let messages = lint_only(&contents, path, package, settings, autofix.into())?;
+        let messages = lint_only(&contents, path, package, settings, autofix.into(), noqa)?;
         let fixed = 0;
         (messages, fixed)
     };
@@ -235,7 +315,7 @@ pub fn lint_path(
         cache::set(path, &metadata, settings, autofix.into(), &messages);
     }
 
-    Ok(Diagnostics { messages, fixed })
+    Ok(Diagnostics { messages, fixed, failures: Vec::new() })
 }
 
 /// Add any missing `#noqa` pragmas to the source code at the given `Path`.
@@ -244,7 +324,7 @@ pub fn add_noqa_to_path(path: &Path, settings: &Settings) -> Result<usize> {
     settings.validate()?;
 
     // Read the file from disk.
-    let contents = fs::read_file(path)?;
+    let contents = fs::read_python_source(path, settings)?;
 
     // Tokenize once.
     let tokens: Vec<LexResult> = rustpython_helpers::tokenize(&contents);
@@ -290,6 +370,7 @@ pub fn lint_stdin(
     package: Option<&Path>,
     contents: &str,
     settings: &Settings,
+    noqa: flags::Noqa,
     autofix: fixer::Mode,
 ) -> Result<Diagnostics> {
     // Validate the `Settings` and return any errors.
@@ -331,12 +412,13 @@ pub fn lint_stdin(
             package,
             settings,
             autofix.into(),
+            noqa,
         )?;
         let fixed = 0;
         (messages, fixed)
     };
 
-    Ok(Diagnostics { messages, fixed })
+    Ok(Diagnostics { messages, fixed, failures: Vec::new() })
 }
 
 /// Generate a list of `Check` violations (optionally including any autofix
@@ -347,6 +429,7 @@ fn lint_only(
     package: Option<&Path>,
     settings: &Settings,
     autofix: flags::Autofix,
+    noqa: flags::Noqa,
 ) -> Result<Vec<Message>> {
     // Tokenize once.
     let tokens: Vec<LexResult> = rustpython_helpers::tokenize(contents);
@@ -372,11 +455,13 @@ fn lint_only(
         &directives,
         settings,
         autofix,
-        flags::Noqa::Enabled,
+        noqa,
     )?;
 
-    // Convert from checks to messages.
-    let path_lossy = path.to_string_lossy();
+    // Convert from checks to messages. Every message in this file shares the
+    // same filename, so intern it once rather than allocating a fresh copy
+    // per violation.
+    let filename: Arc<str> = Arc::from(path.to_string_lossy().as_ref());
     Ok(checks
         .into_iter()
         .map(|check| {
@@ -385,7 +470,7 @@ fn lint_only(
             } else {
                 None
             };
-            Message::from_check(check, path_lossy.to_string(), source)
+            Message::from_check(check, filename.clone(), source)
         })
         .collect())
 }
@@ -438,6 +523,37 @@ fn lint_fix(
         // Apply autofix.
         if let Some((fixed_contents, applied)) = fix_file(&checks, &locator) {
             if iterations < MAX_ITERATIONS {
+                // Guard against a fix introducing invalid syntax: re-parse the
+                // patched source before accepting it. If it no longer parses,
+                // stop fixing and keep the last-known-good `contents`, rather
+                // than risk compounding the damage on further iterations.
+                let fixed_tokens = rustpython_helpers::tokenize(&fixed_contents);
+                if let Err(parse_error) =
+                    rustpython_helpers::parse_program_tokens(fixed_tokens, "<filename>")
+                {
+                    let responsible = checks
+                        .iter()
+                        .filter(|check| check.fix.is_some())
+                        .min_by_key(|check| {
+                            check
+                                .location
+                                .row()
+                                .abs_diff(parse_error.location.row())
+                                .min(check.end_location.row().abs_diff(parse_error.location.row()))
+                        })
+                        .map(|check| check.kind.code());
+                    warn!(
+                        "Rolling back fixes for {}: patched source no longer parses ({}){}",
+                        fs::relativize_path(path),
+                        parse_error.error,
+                        match responsible {
+                            Some(code) => format!(", likely due to `{code}`'s fix"),
+                            None => String::new(),
+                        }
+                    );
+                    break;
+                }
+
                 // Count the number of fixed errors.
                 fixed += applied;
 
@@ -471,7 +587,7 @@ quoting the contents of `{}`, along with the `pyproject.toml` settings and execu
         }
 
         // Convert to messages.
-        let path_lossy = path.to_string_lossy();
+        let filename: Arc<str> = Arc::from(path.to_string_lossy().as_ref());
         let messages = checks
             .into_iter()
             .map(|check| {
@@ -480,7 +596,7 @@ quoting the contents of `{}`, along with the `pyproject.toml` settings and execu
                 } else {
                     None
                 };
-                Message::from_check(check, path_lossy.to_string(), source)
+                Message::from_check(check, filename.clone(), source)
             })
             .collect();
         return Ok((contents, fixed, messages));
@@ -489,7 +605,7 @@ quoting the contents of `{}`, along with the `pyproject.toml` settings and execu
 
 #[cfg(test)]
 pub fn test_path(path: &Path, settings: &Settings) -> Result<Vec<Check>> {
-    let contents = fs::read_file(path)?;
+    let contents = fs::read_python_source(path, settings)?;
     let tokens: Vec<LexResult> = rustpython_helpers::tokenize(&contents);
     let locator = SourceCodeLocator::new(&contents);
     let stylist = SourceCodeStyleDetector::from_contents(&contents, &locator);
@@ -4,13 +4,16 @@ use std::process::ExitCode;
 use std::sync::mpsc::channel;
 
 use ::ruff::autofix::fixer;
+use ::ruff::cache;
 use ::ruff::cli::{extract_log_level, Cli, Overrides};
 use ::ruff::logging::{set_up_logging, LogLevel};
 use ::ruff::printer::{Printer, Violations};
 use ::ruff::resolver::{resolve_settings, FileDiscovery, PyprojectDiscovery, Relativity};
 use ::ruff::settings::configuration::Configuration;
+use ::ruff::settings::flags;
 use ::ruff::settings::types::SerializationFormat;
 use ::ruff::settings::{pyproject, Settings};
+use ::ruff::timing;
 #[cfg(feature = "update-informer")]
 use ::ruff::updates;
 use ::ruff::{commands, one_time_warning};
@@ -77,6 +80,10 @@ pub(crate) fn inner_main() -> Result<ExitCode> {
     let log_level = extract_log_level(&cli);
     set_up_logging(&log_level)?;
 
+    if cli.timing {
+        timing::enable();
+    }
+
     if let Some(shell) = cli.generate_shell_completion {
         shell.generate(&mut Cli::command(), &mut io::stdout());
         return Ok(ExitCode::SUCCESS);
@@ -113,18 +120,20 @@ pub(crate) fn inner_main() -> Result<ExitCode> {
             PyprojectDiscovery::Hierarchical(settings) => settings.respect_gitignore,
         },
     };
-    let (fix, fix_only, format, update_check) = match &pyproject_strategy {
+    let (fix, fix_only, format, update_check, cache_dir) = match &pyproject_strategy {
         PyprojectDiscovery::Fixed(settings) => (
             settings.fix,
             settings.fix_only,
             settings.format,
             settings.update_check,
+            settings.cache_dir.clone(),
         ),
         PyprojectDiscovery::Hierarchical(settings) => (
             settings.fix,
             settings.fix_only,
             settings.format,
             settings.update_check,
+            settings.cache_dir.clone(),
         ),
     };
 
@@ -132,6 +141,14 @@ pub(crate) fn inner_main() -> Result<ExitCode> {
         commands::explain(&code, &format)?;
         return Ok(ExitCode::SUCCESS);
     }
+    if cli.explain_all {
+        commands::explain_all(&format)?;
+        return Ok(ExitCode::SUCCESS);
+    }
+    if cli.linter {
+        commands::show_linters(&format)?;
+        return Ok(ExitCode::SUCCESS);
+    }
     if cli.show_settings {
         commands::show_settings(&cli.files, &pyproject_strategy, &file_strategy, &overrides)?;
         return Ok(ExitCode::SUCCESS);
@@ -165,7 +182,14 @@ pub(crate) fn inner_main() -> Result<ExitCode> {
     } else {
         Violations::Show
     };
-    let cache = !cli.no_cache;
+    // `--ignore-noqa` reports suppressed violations rather than hiding them, so a
+    // cache entry written with one mode enabled can't be reused for the other.
+    let cache = !cli.no_cache && !cli.ignore_noqa;
+    let noqa = if cli.ignore_noqa {
+        flags::Noqa::Ignored
+    } else {
+        flags::Noqa::Enabled
+    };
 
     #[cfg(debug_assertions)]
     if cache {
@@ -208,7 +232,9 @@ pub(crate) fn inner_main() -> Result<ExitCode> {
             &file_strategy,
             &overrides,
             cache.into(),
+            noqa,
             fixer::Mode::None,
+            (!cli.no_sort).into(),
         )?;
         printer.write_continuously(&messages)?;
 
@@ -238,7 +264,9 @@ pub(crate) fn inner_main() -> Result<ExitCode> {
                             &file_strategy,
                             &overrides,
                             cache.into(),
+                            noqa,
                             fixer::Mode::None,
+                            (!cli.no_sort).into(),
                         )?;
                         printer.write_continuously(&messages)?;
                     }
@@ -256,12 +284,13 @@ pub(crate) fn inner_main() -> Result<ExitCode> {
         let is_stdin = cli.files == vec![PathBuf::from("-")];
 
         // Generate lint violations.
-        let diagnostics = if is_stdin {
+        let mut diagnostics = if is_stdin {
             commands::run_stdin(
                 cli.stdin_filename.as_deref(),
                 &pyproject_strategy,
                 &file_strategy,
                 &overrides,
+                noqa,
                 autofix,
             )?
         } else {
@@ -271,9 +300,25 @@ pub(crate) fn inner_main() -> Result<ExitCode> {
                 &file_strategy,
                 &overrides,
                 cache.into(),
+                noqa,
                 autofix,
+                (!cli.no_sort).into(),
             )?
         };
+        if let Some(max_violations) = cli.max_violations {
+            if diagnostics.messages.len() > max_violations {
+                let omitted = diagnostics.messages.len() - max_violations;
+                diagnostics.messages.truncate(max_violations);
+                if log_level >= LogLevel::Default {
+                    one_time_warning!(
+                        "{}{} {}",
+                        "warning".yellow().bold(),
+                        ":".bold(),
+                        format!("--max-violations reached; omitting {omitted} further violation(s).").bold()
+                    );
+                }
+            }
+        }
 
         // Always try to print violations (the printer itself may suppress output),
         // unless we're writing fixes via stdin (in which case, the transformed
@@ -282,6 +327,24 @@ pub(crate) fn inner_main() -> Result<ExitCode> {
             printer.write_once(&diagnostics)?;
         }
 
+        if !diagnostics.failures.is_empty() && log_level >= LogLevel::Default {
+            eprintln!(
+                "{}",
+                format!(
+                    "{} file{} failed to lint",
+                    diagnostics.failures.len(),
+                    if diagnostics.failures.len() == 1 { "" } else { "s" }
+                )
+                .red()
+                .bold()
+            );
+            if log_level >= LogLevel::Verbose {
+                for failure in &diagnostics.failures {
+                    eprintln!("  {failure}");
+                }
+            }
+        }
+
         // Check for updates if we're in a non-silent log level.
         #[cfg(feature = "update-informer")]
         if update_check
@@ -292,12 +355,22 @@ pub(crate) fn inner_main() -> Result<ExitCode> {
             drop(updates::check_for_updates());
         }
 
+        if cli.timing {
+            timing::report();
+        }
+
+        if cli.cache_info {
+            cache::print_info(&cache_dir);
+        }
+
         if !cli.exit_zero {
             if cli.diff || fix_only {
                 if diagnostics.fixed > 0 {
                     return Ok(ExitCode::FAILURE);
                 }
-            } else if !diagnostics.messages.is_empty() {
+            } else if !diagnostics.messages.is_empty()
+                || (cli.exit_non_zero_on_fix && diagnostics.fixed > 0)
+            {
                 return Ok(ExitCode::FAILURE);
             }
         }
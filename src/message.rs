@@ -1,4 +1,5 @@
 use std::cmp::Ordering;
+use std::sync::Arc;
 
 use rustpython_parser::ast::Location;
 use serde::{Deserialize, Serialize};
@@ -14,12 +15,19 @@ pub struct Message {
     pub location: Location,
     pub end_location: Location,
     pub fix: Option<Fix>,
-    pub filename: String,
+    /// The name of the file this message applies to. An `Arc` because a
+    /// single file's checks (potentially thousands, for a large file) all
+    /// share the same filename, and cloning that once per message is
+    /// significantly cheaper than allocating a fresh `String` each time.
+    pub filename: Arc<str>,
     pub source: Option<Source>,
+    /// Whether this violation would normally have been suppressed by a `noqa`
+    /// directive, but is being surfaced anyway (e.g., via `--ignore-noqa`).
+    pub is_suppressed: bool,
 }
 
 impl Message {
-    pub fn from_check(check: Check, filename: String, source: Option<Source>) -> Self {
+    pub fn from_check(check: Check, filename: Arc<str>, source: Option<Source>) -> Self {
         Self {
             kind: check.kind,
             location: Location::new(check.location.row(), check.location.column() + 1),
@@ -27,6 +35,7 @@ impl Message {
             fix: check.fix,
             filename,
             source,
+            is_suppressed: check.is_suppressed,
         }
     }
 }
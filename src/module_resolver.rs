@@ -0,0 +1,69 @@
+//! Best-effort resolution of Python import statements to first-party
+//! source files on disk, using the configured `src` roots.
+//!
+//! This is shared infrastructure: any check that needs to know whether an
+//! import refers to a first-party module, and if so where that module
+//! lives, can call [`resolve`] rather than re-implementing filesystem
+//! lookup. `pyflakes` uses it today (see [`crate::pyflakes::module`]) to
+//! resolve `from x import *`; `flake8_tidy_imports` and future
+//! type-checking rules are expected to grow into it as they need the same
+//! first-party/third-party distinction.
+
+use std::path::{Path, PathBuf};
+
+/// Resolve a (possibly relative) import to a first-party `.py` file on
+/// disk.
+///
+/// `path` is the file containing the import, used as the base for
+/// relative imports. `src` is the list of configured source roots, used
+/// for absolute imports. `level` and `module` mirror the fields of an
+/// `ImportFrom` AST node (e.g. `from ..pkg.mod import x` has `level = 2`
+/// and `module = Some("pkg.mod")`).
+pub fn resolve(
+    path: &Path,
+    src: &[PathBuf],
+    level: Option<usize>,
+    module: Option<&str>,
+) -> Option<PathBuf> {
+    let parts: Vec<&str> = module.map_or_else(Vec::new, |module| module.split('.').collect());
+
+    match level {
+        Some(level) if level > 0 => {
+            // A relative import: walk up `level - 1` directories from the
+            // importing module's own directory, then descend into `module`.
+            let mut base = path.parent()?.to_path_buf();
+            for _ in 1..level {
+                base = base.parent()?.to_path_buf();
+            }
+            for part in &parts {
+                base = base.join(part);
+            }
+            resolve_candidate(&base)
+        }
+        _ => {
+            // An absolute import: look for `module` under one of the
+            // configured `src` roots.
+            src.iter().find_map(|root| {
+                let mut base = root.clone();
+                for part in &parts {
+                    base = base.join(part);
+                }
+                resolve_candidate(&base)
+            })
+        }
+    }
+}
+
+/// Given a path without an extension (e.g. `src/pkg/foo`), return the
+/// corresponding module file, checking both `foo.py` and `foo/__init__.py`.
+fn resolve_candidate(base: &Path) -> Option<PathBuf> {
+    let module_file = base.with_extension("py");
+    if module_file.is_file() {
+        return Some(module_file);
+    }
+    let package_init = base.join("__init__.py");
+    if package_init.is_file() {
+        return Some(package_init);
+    }
+    None
+}
@@ -13,11 +13,23 @@ use crate::source_code_style::LineEnding;
 
 static NOQA_LINE_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(
-        r"(?P<spaces>\s*)(?P<noqa>(?i:# noqa)(?::\s?(?P<codes>([A-Z]+[0-9]+(?:[,\s]+)?)+))?)",
+        r"(?P<spaces>\s*)(?P<noqa>(?i:# noqa)(?::\s?(?P<codes>([A-Z]+[0-9]+(?:[,\s]+)?)*))?)",
     )
     .unwrap()
 });
 static SPLIT_COMMA_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[,\s]").unwrap());
+static CODE_SEPARATOR_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[A-Z]+[0-9]+([,\s]+)").unwrap());
+
+/// Return the delimiter used between codes in a raw (unsplit) codes string,
+/// e.g. `", "` for `"F401, E501"` or `","` for `"F401,E501"`. Falls back to
+/// `", "`, the canonical style, if there's nothing to infer it from.
+fn detect_code_separator(raw_codes: &str) -> &str {
+    CODE_SEPARATOR_REGEX
+        .captures(raw_codes)
+        .and_then(|caps| caps.get(1))
+        .map_or(", ", |sep| sep.as_str())
+}
 
 /// Return `true` if a file is exempt from checking based on the contents of the
 /// given line.
@@ -35,7 +47,13 @@ pub fn is_file_exempt(line: &str) -> bool {
 pub enum Directive<'a> {
     None,
     All(usize, usize, usize),
-    Codes(usize, usize, usize, Vec<&'a str>),
+    // The last field is the delimiter originally used between codes (e.g.
+    // `", "` or `","`), so that a partial-unused rewrite can preserve it.
+    Codes(usize, usize, usize, Vec<&'a str>, &'a str),
+    // A directive that looks like a `noqa` comment (e.g. `# noqa:`) but
+    // doesn't parse as either a bare directive or a valid code list, so
+    // it's most likely a typo rather than an intentional "ignore all".
+    Invalid(usize, usize, usize),
 }
 
 /// Extract the noqa `Directive` from a line of Python source code.
@@ -44,16 +62,28 @@ pub fn extract_noqa_directive(line: &str) -> Directive {
         Some(caps) => match caps.name("spaces") {
             Some(spaces) => match caps.name("noqa") {
                 Some(noqa) => match caps.name("codes") {
-                    Some(codes) => Directive::Codes(
-                        spaces.as_str().chars().count(),
-                        noqa.start(),
-                        noqa.end(),
-                        SPLIT_COMMA_REGEX
-                            .split(codes.as_str())
+                    Some(raw_codes) => {
+                        let codes: Vec<&str> = SPLIT_COMMA_REGEX
+                            .split(raw_codes.as_str())
                             .map(str::trim)
                             .filter(|code| !code.is_empty())
-                            .collect(),
-                    ),
+                            .collect();
+                        if codes.is_empty() {
+                            Directive::Invalid(
+                                spaces.as_str().chars().count(),
+                                noqa.start(),
+                                noqa.end(),
+                            )
+                        } else {
+                            Directive::Codes(
+                                spaces.as_str().chars().count(),
+                                noqa.start(),
+                                noqa.end(),
+                                codes,
+                                detect_code_separator(raw_codes.as_str()),
+                            )
+                        }
+                    }
                     None => {
                         Directive::All(spaces.as_str().chars().count(), noqa.start(), noqa.end())
                     }
@@ -152,7 +182,7 @@ fn add_noqa_inner(
                         output.push_str(line_ending);
                         count += 1;
                     }
-                    Directive::All(_, start, _) => {
+                    Directive::All(_, start, _) | Directive::Invalid(_, start, _) => {
                         // Add existing content.
                         output.push_str(line[..start].trim_end());
 
@@ -167,7 +197,7 @@ fn add_noqa_inner(
                         output.push_str(line_ending);
                         count += 1;
                     }
-                    Directive::Codes(_, start, _, existing) => {
+                    Directive::Codes(_, start, _, existing, _) => {
                         // Reconstruct the line based on the preserved check codes.
                         // This enables us to tally the number of edits.
                         let mut formatted = String::new();
@@ -212,7 +242,7 @@ mod tests {
     use rustpython_parser::ast::Location;
 
     use crate::ast::types::Range;
-    use crate::noqa::{add_noqa_inner, NOQA_LINE_REGEX};
+    use crate::noqa::{add_noqa_inner, extract_noqa_directive, Directive, NOQA_LINE_REGEX};
     use crate::registry::Check;
     use crate::source_code_style::LineEnding;
     use crate::violations;
@@ -231,6 +261,44 @@ mod tests {
         assert!(NOQA_LINE_REGEX.is_match("# noqa:F401, E501"));
     }
 
+    #[test]
+    fn extract_invalid_directive() {
+        assert!(matches!(
+            extract_noqa_directive("x = 1  # noqa:"),
+            Directive::Invalid(..)
+        ));
+        assert!(matches!(
+            extract_noqa_directive("x = 1  # noqa:   "),
+            Directive::Invalid(..)
+        ));
+        assert!(matches!(
+            extract_noqa_directive("x = 1  # noqa"),
+            Directive::All(..)
+        ));
+        assert!(matches!(
+            extract_noqa_directive("x = 1  # noqa: F401"),
+            Directive::Codes(..)
+        ));
+    }
+
+    #[test]
+    fn extract_code_separator() {
+        let Directive::Codes(.., sep) = extract_noqa_directive("x = 1  # noqa: F401,E501") else {
+            panic!("expected a Codes directive");
+        };
+        assert_eq!(sep, ",");
+
+        let Directive::Codes(.., sep) = extract_noqa_directive("x = 1  # noqa: F401, E501") else {
+            panic!("expected a Codes directive");
+        };
+        assert_eq!(sep, ", ");
+
+        let Directive::Codes(.., sep) = extract_noqa_directive("x = 1  # noqa: F401") else {
+            panic!("expected a Codes directive");
+        };
+        assert_eq!(sep, ", ");
+    }
+
     #[test]
     fn modification() {
         let checks = vec![];
@@ -0,0 +1,85 @@
+use rustpython_ast::{Expr, ExprKind};
+
+use crate::ast::types::Range;
+use crate::autofix::Fix;
+use crate::registry::Check;
+use crate::violations;
+
+const DEPRECATED_ALIASES: &[(&str, &str)] = &[
+    ("bool", "bool"),
+    ("int", "int"),
+    ("float", "float"),
+    ("complex", "complex"),
+    ("object", "object"),
+    ("str", "str"),
+    ("long", "int"),
+    ("unicode", "str"),
+];
+
+const LEGACY_RANDOM_FUNCTIONS: &[&str] = &[
+    "beta", "binomial", "bytes", "chisquare", "choice", "dirichlet", "exponential", "f", "gamma",
+    "geometric", "gumbel", "hypergeometric", "laplace", "logistic", "lognormal", "logseries",
+    "multinomial", "multivariate_normal", "negative_binomial", "noncentral_chisquare",
+    "noncentral_f", "normal", "pareto", "permutation", "poisson", "power", "rand", "randint",
+    "randn", "random", "random_integers", "random_sample", "ranf", "rayleigh", "sample", "seed",
+    "shuffle", "standard_cauchy", "standard_exponential", "standard_gamma", "standard_normal",
+    "triangular", "uniform", "vonmises", "wald", "weibull", "zipf",
+];
+
+/// NPY001 - deprecated NumPy type aliases (e.g., `np.int`, `np.bool`).
+pub fn deprecated_type_alias(expr: &Expr, patch: bool) -> Option<Check> {
+    let ExprKind::Attribute { value, attr, .. } = &expr.node else {
+        return None;
+    };
+    let ExprKind::Name { id, .. } = &value.node else {
+        return None;
+    };
+    if id != "np" && id != "numpy" {
+        return None;
+    }
+    let (_, replacement) = DEPRECATED_ALIASES
+        .iter()
+        .find(|(deprecated, _)| deprecated == attr)?;
+
+    let mut check = Check::new(
+        violations::DeprecatedTypeAlias(format!("{id}.{attr}")),
+        Range::from_located(expr),
+    );
+    if patch {
+        check.amend(Fix::replacement(
+            (*replacement).to_string(),
+            expr.location,
+            expr.end_location.unwrap(),
+        ));
+    }
+    Some(check)
+}
+
+/// NPY002 - legacy `numpy.random` entry points; prefer the `Generator` API.
+pub fn legacy_random(expr: &Expr) -> Option<Check> {
+    let ExprKind::Attribute { value, attr, .. } = &expr.node else {
+        return None;
+    };
+    let ExprKind::Attribute {
+        value: base,
+        attr: mod_attr,
+        ..
+    } = &value.node
+    else {
+        return None;
+    };
+    let ExprKind::Name { id, .. } = &base.node else {
+        return None;
+    };
+    if (id != "np" && id != "numpy") || mod_attr != "random" {
+        return None;
+    }
+    if !LEGACY_RANDOM_FUNCTIONS.contains(&attr.as_str()) {
+        return None;
+    }
+    Some(Check::new(
+        violations::LegacyNumpyRandom(attr.to_string()),
+        Range::from_located(expr),
+    ))
+}
+
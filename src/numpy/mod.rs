@@ -0,0 +1,28 @@
+pub mod checks;
+
+#[cfg(test)]
+mod tests {
+    use std::convert::AsRef;
+    use std::path::Path;
+
+    use anyhow::Result;
+    use test_case::test_case;
+
+    use crate::linter::test_path;
+    use crate::registry::CheckCode;
+    use crate::settings;
+
+    #[test_case(CheckCode::NPY001, Path::new("NPY001.py"); "NPY001")]
+    #[test_case(CheckCode::NPY002, Path::new("NPY002.py"); "NPY002")]
+    fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
+        let snapshot = format!("{}_{}", check_code.as_ref(), path.to_string_lossy());
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/numpy")
+                .join(path)
+                .as_path(),
+            &settings::Settings::for_rule(check_code),
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, checks);
+        Ok(())
+    }
+}
@@ -4,6 +4,8 @@ use crate::ast::types::Range;
 use crate::registry::Check;
 use crate::violations;
 
+use super::helpers::is_dataframe_candidate;
+
 /// PD002
 pub fn inplace_argument(keywords: &[Keyword]) -> Option<Check> {
     for keyword in keywords {
@@ -43,8 +45,37 @@ pub fn use_of_pd_merge(func: &Expr) -> Option<Check> {
     None
 }
 
+/// PD101
+pub fn use_of_len_and_unique(expr: &Expr, func: &Expr, args: &[Expr]) -> Option<Check> {
+    let ExprKind::Name { id, .. } = &func.node else {
+        return None;
+    };
+    if id != "len" {
+        return None;
+    }
+    let [arg] = args else {
+        return None;
+    };
+    let ExprKind::Call { func: inner_func, .. } = &arg.node else {
+        return None;
+    };
+    let ExprKind::Attribute { attr, value, .. } = &inner_func.node else {
+        return None;
+    };
+    if attr != "unique" {
+        return None;
+    }
+    if !is_dataframe_candidate(value) {
+        return None;
+    }
+    Some(Check::new(
+        violations::UseOfLenAndUnique,
+        Range::from_located(expr),
+    ))
+}
+
 /// PD901
-pub fn assignment_to_df(targets: &[Expr]) -> Option<Check> {
+pub fn assignment_to_df(targets: &[Expr], banned_variable_names: &[String]) -> Option<Check> {
     if targets.len() != 1 {
         return None;
     }
@@ -52,11 +83,11 @@ pub fn assignment_to_df(targets: &[Expr]) -> Option<Check> {
     let ExprKind::Name { id, .. } = &target.node else {
         return None;
     };
-    if id != "df" {
+    if !banned_variable_names.iter().any(|name| name == id) {
         return None;
     }
     Some(Check::new(
-        violations::DfIsABadVariableName,
+        violations::DfIsABadVariableName(id.to_string()),
         Range::from_located(target),
     ))
 }
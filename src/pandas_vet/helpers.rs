@@ -1,4 +1,6 @@
-use rustpython_ast::{Expr, ExprKind};
+use rustpython_ast::{Expr, ExprKind, StmtKind};
+
+use crate::ast::types::Binding;
 
 /// Return `true` if an `Expr` _could_ be a `DataFrame`. This rules out
 /// obviously-wrong cases, like constants and literals.
@@ -16,3 +18,30 @@ pub fn is_dataframe_candidate(expr: &Expr) -> bool {
             | ExprKind::GeneratorExp { .. }
     )
 }
+
+/// Return `true` if `binding` was assigned a literal (or an obvious builtin
+/// constructor call) that's clearly not a `DataFrame`, e.g. `x = {"a": 1}`
+/// or `x = dict()`. This is a best-effort, syntactic check: it only looks at
+/// the single statement that defined the binding, so it won't catch a
+/// variable that's reassigned to a `DataFrame` afterwards, but it's enough
+/// to rule out the common false positive of a plain dict/list/set/tuple
+/// being mistaken for a `DataFrame`.
+pub fn is_non_dataframe_literal_binding(binding: &Binding) -> bool {
+    let Some(source) = &binding.source else {
+        return false;
+    };
+    let StmtKind::Assign { value, .. } = &source.0.node else {
+        return false;
+    };
+    match &value.node {
+        ExprKind::Dict { .. }
+        | ExprKind::List { .. }
+        | ExprKind::Set { .. }
+        | ExprKind::Tuple { .. } => true,
+        ExprKind::Call { func, .. } => matches!(
+            &func.node,
+            ExprKind::Name { id, .. } if matches!(id.as_str(), "dict" | "list" | "set" | "tuple")
+        ),
+        _ => false,
+    }
+}
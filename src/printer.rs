@@ -41,6 +41,7 @@ struct ExpandedMessage<'a> {
     location: Location,
     end_location: Location,
     filename: &'a str,
+    is_suppressed: bool,
 }
 
 pub struct Printer<'a> {
@@ -144,6 +145,7 @@ impl<'a> Printer<'a> {
                                 location: message.location,
                                 end_location: message.end_location,
                                 filename: &message.filename,
+                                is_suppressed: message.is_suppressed,
                             })
                             .collect::<Vec<_>>()
                     )?
@@ -278,6 +280,68 @@ impl<'a> Printer<'a> {
                     )?
                 );
             }
+            SerializationFormat::Sarif => {
+                // Generate a SARIF 2.1.0 log, including fix replacements, so that
+                // editor plugins can apply fixes without re-invoking Ruff.
+                // See: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+                let results = diagnostics
+                    .messages
+                    .iter()
+                    .map(|message| {
+                        json!({
+                            "ruleId": message.kind.code(),
+                            "level": "error",
+                            "message": {"text": message.kind.body()},
+                            "locations": [{
+                                "physicalLocation": {
+                                    "artifactLocation": {
+                                        "uri": relativize_path(Path::new(&message.filename))
+                                    },
+                                    "region": {
+                                        "startLine": message.location.row(),
+                                        "startColumn": message.location.column(),
+                                        "endLine": message.end_location.row(),
+                                        "endColumn": message.end_location.column()
+                                    }
+                                }
+                            }],
+                            "fixes": message.fix.as_ref().map(|fix| vec![json!({
+                                "artifactChanges": [{
+                                    "artifactLocation": {
+                                        "uri": relativize_path(Path::new(&message.filename))
+                                    },
+                                    "replacements": [{
+                                        "deletedRegion": {
+                                            "startLine": fix.location.row(),
+                                            "startColumn": fix.location.column(),
+                                            "endLine": fix.end_location.row(),
+                                            "endColumn": fix.end_location.column()
+                                        },
+                                        "insertedContent": {"text": fix.content}
+                                    }]
+                                }]
+                            })]).unwrap_or_default()
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&json!({
+                        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+                        "version": "2.1.0",
+                        "runs": [{
+                            "tool": {
+                                "driver": {
+                                    "name": "ruff",
+                                    "informationUri": "https://github.com/charliermarsh/ruff",
+                                    "version": env!("CARGO_PKG_VERSION")
+                                }
+                            },
+                            "results": results
+                        }]
+                    }))?
+                );
+            }
         }
 
         Ok(())
@@ -314,11 +378,11 @@ impl<'a> Printer<'a> {
     }
 }
 
-fn group_messages_by_filename(messages: &[Message]) -> BTreeMap<&String, Vec<&Message>> {
+fn group_messages_by_filename(messages: &[Message]) -> BTreeMap<&str, Vec<&Message>> {
     let mut grouped_messages = BTreeMap::default();
     for message in messages {
         grouped_messages
-            .entry(&message.filename)
+            .entry(message.filename.as_ref())
             .or_insert_with(Vec::new)
             .push(message);
     }
@@ -335,7 +399,7 @@ fn num_digits(n: usize) -> usize {
 /// Print a single `Message` with full details.
 fn print_message(message: &Message) {
     let label = format!(
-        "{}{}{}{}{}{} {} {}",
+        "{}{}{}{}{}{} {} {}{}",
         relativize_path(Path::new(&message.filename)).bold(),
         ":".cyan(),
         message.location.row(),
@@ -344,6 +408,11 @@ fn print_message(message: &Message) {
         ":".cyan(),
         message.kind.code().as_ref().red().bold(),
         message.kind.body(),
+        if message.is_suppressed {
+            " (suppressed)".dimmed().to_string()
+        } else {
+            String::new()
+        },
     );
     println!("{label}");
     if let Some(source) = &message.source {
@@ -395,7 +464,7 @@ fn print_message(message: &Message) {
 /// the same file.
 fn print_grouped_message(message: &Message, row_length: usize, column_length: usize) {
     let label = format!(
-        "  {}{}{}{}{}  {}  {}",
+        "  {}{}{}{}{}  {}  {}{}",
         " ".repeat(row_length - num_digits(message.location.row())),
         message.location.row(),
         ":".cyan(),
@@ -403,6 +472,11 @@ fn print_grouped_message(message: &Message, row_length: usize, column_length: us
         " ".repeat(column_length - num_digits(message.location.column())),
         message.kind.code().as_ref().red().bold(),
         message.kind.body(),
+        if message.is_suppressed {
+            " (suppressed)".dimmed().to_string()
+        } else {
+            String::new()
+        },
     );
     println!("{label}");
     if let Some(source) = &message.source {
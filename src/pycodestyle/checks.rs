@@ -1,34 +1,60 @@
-use itertools::izip;
+use itertools::{izip, Itertools};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rustpython_ast::{Constant, Excepthandler, Location, Stmt, StmtKind};
 use rustpython_parser::ast::{Cmpop, Expr, ExprKind};
+use rustpython_parser::lexer::{LexResult, Tok};
 
 use crate::ast::helpers::except_range;
 use crate::ast::types::Range;
 use crate::autofix::Fix;
-use crate::registry::Check;
-use crate::settings::Settings;
+use crate::registry::{Check, CheckCode};
+use crate::settings::{flags, Settings};
 use crate::source_code_locator::SourceCodeLocator;
 use crate::violations;
 
 static URL_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^https?://\S+$").unwrap());
 
-/// E501
-pub fn line_too_long(lineno: usize, line: &str, settings: &Settings) -> Option<Check> {
-    let line_length = line.chars().count();
-
-    if line_length <= settings.line_length {
-        return None;
+/// Return the display width of `line`, expanding tabs to the next multiple
+/// of `tab_size` rather than counting each as a single column.
+pub fn expanded_line_width(line: &str, tab_size: usize) -> usize {
+    if tab_size == 0 {
+        return line.chars().count();
     }
+    let mut width = 0;
+    for char in line.chars() {
+        if char == '\t' {
+            width += tab_size - (width % tab_size);
+        } else {
+            width += 1;
+        }
+    }
+    width
+}
 
+/// E501
+pub fn line_too_long(lineno: usize, line: &str, settings: &Settings) -> Option<Check> {
     let mut chunks = line.split_whitespace();
     let (Some(first), Some(second)) = (chunks.next(), chunks.next()) else {
         // Single word / no printable chars - no way to make the line shorter
         return None;
     };
 
-    if first == "#" {
+    // Comments get their own (typically shorter) length limit, since they're
+    // rarely reflowed and tend to dominate `noqa`d E501s.
+    let is_comment = first == "#";
+    let limit = if is_comment && settings.pycodestyle.max_doc_length > 0 {
+        settings.pycodestyle.max_doc_length
+    } else {
+        settings.line_length
+    };
+
+    let line_length = expanded_line_width(line, settings.tab_size);
+    if line_length <= limit {
+        return None;
+    }
+
+    if is_comment {
         if settings.pycodestyle.ignore_overlong_task_comments {
             let second = second.trim_end_matches(':');
             if settings.task_tags.iter().any(|tag| tag == second) {
@@ -44,9 +70,9 @@ pub fn line_too_long(lineno: usize, line: &str, settings: &Settings) -> Option<C
     }
 
     Some(Check::new(
-        violations::LineTooLong(line_length, settings.line_length),
+        violations::LineTooLong(line_length, limit),
         Range::new(
-            Location::new(lineno + 1, settings.line_length),
+            Location::new(lineno + 1, limit),
             Location::new(lineno + 1, line_length),
         ),
     ))
@@ -102,16 +128,29 @@ pub fn do_not_use_bare_except(
     body: &[Stmt],
     handler: &Excepthandler,
     locator: &SourceCodeLocator,
+    autofix: bool,
+    use_base_exception: bool,
 ) -> Option<Check> {
     if type_.is_none()
         && !body
             .iter()
             .any(|stmt| matches!(stmt.node, StmtKind::Raise { exc: None, .. }))
     {
-        Some(Check::new(
-            violations::DoNotUseBareExcept,
-            except_range(handler, locator),
-        ))
+        let range = except_range(handler, locator);
+        let mut check = Check::new(violations::DoNotUseBareExcept, range);
+        if autofix {
+            let exception = if use_base_exception {
+                "BaseException"
+            } else {
+                "Exception"
+            };
+            check.amend(Fix::replacement(
+                format!("except {exception}"),
+                range.location,
+                range.end_location,
+            ));
+        }
+        Some(check)
     } else {
         None
     }
@@ -122,6 +161,10 @@ fn is_ambiguous_name(name: &str) -> bool {
 }
 
 /// E741
+///
+/// Callers apply this at every binding position for a name, not just plain
+/// assignments: function and lambda parameters, `global`/`nonlocal`
+/// declarations, comprehension targets, `with` items, and `except ... as`.
 pub fn ambiguous_variable_name(name: &str, range: Range) -> Option<Check> {
     if is_ambiguous_name(name) {
         Some(Check::new(
@@ -184,6 +227,47 @@ pub fn no_newline_at_end_of_file(contents: &str, autofix: bool) -> Option<Check>
     None
 }
 
+/// W291, W293
+pub fn trailing_whitespace(
+    lineno: usize,
+    line: &str,
+    settings: &Settings,
+    autofix: flags::Autofix,
+) -> Option<Check> {
+    let trimmed = line.trim_end();
+    if trimmed.len() == line.len() {
+        return None;
+    }
+
+    let location = Location::new(lineno + 1, trimmed.len());
+    let end_location = Location::new(lineno + 1, line.len());
+
+    let mut check = if trimmed.is_empty() {
+        if !settings.enabled.contains(&CheckCode::W293) {
+            return None;
+        }
+        Check::new(
+            violations::WhitespaceOnBlankLine,
+            Range::new(location, end_location),
+        )
+    } else {
+        if !settings.enabled.contains(&CheckCode::W291) {
+            return None;
+        }
+        Check::new(
+            violations::TrailingWhitespace,
+            Range::new(location, end_location),
+        )
+    };
+
+    if matches!(autofix, flags::Autofix::Enabled) && settings.fixable.contains(check.kind.code())
+    {
+        check.amend(Fix::deletion(location, end_location));
+    }
+
+    Some(check)
+}
+
 // See: https://docs.python.org/3/reference/lexical_analysis.html#string-and-bytes-literals
 const VALID_ESCAPE_SEQUENCES: &[char; 23] = &[
     '\n', '\\', '\'', '"', 'a', 'b', 'f', 'n', 'r', 't', 'v', '0', '1', '2', '3', '4', '5', '6',
@@ -202,6 +286,29 @@ fn extract_quote(text: &str) -> &str {
     panic!("Unable to find quotation mark for String token")
 }
 
+/// Return `true` if `body` contains at least one valid escape sequence.
+fn has_valid_escape_sequence(body: &str) -> bool {
+    for line in body.lines() {
+        let chars: Vec<char> = line.chars().collect();
+        let mut col_offset = 0;
+        while col_offset < chars.len() {
+            if chars[col_offset] != '\\' {
+                col_offset += 1;
+                continue;
+            }
+            // A trailing backslash is a line continuation, not an escape.
+            if col_offset == chars.len() - 1 {
+                break;
+            }
+            if VALID_ESCAPE_SEQUENCES.contains(&chars[col_offset + 1]) {
+                return true;
+            }
+            col_offset += 2;
+        }
+    }
+    false
+}
+
 /// W605
 pub fn invalid_escape_sequence(
     locator: &SourceCodeLocator,
@@ -220,6 +327,11 @@ pub fn invalid_escape_sequence(
     let body = &text[(quote_pos + quote.len())..(text.len() - quote.len())];
 
     if !prefix.contains('r') {
+        // If the string contains no other, valid escape sequences, it's safe to make
+        // it a raw string instead of doubling up every invalid backslash. (A `u`
+        // prefix can't be combined with `r`, so leave those alone.)
+        let use_raw_prefix = !prefix.contains('u') && !has_valid_escape_sequence(body);
+
         for (row_offset, line) in body.lines().enumerate() {
             let chars: Vec<char> = line.chars().collect();
             for col_offset in 0..chars.len() {
@@ -257,7 +369,11 @@ pub fn invalid_escape_sequence(
                     Range::new(location, end_location),
                 );
                 if autofix {
-                    check.amend(Fix::insertion(r"\".to_string(), location));
+                    if use_raw_prefix {
+                        check.amend(Fix::insertion("r".to_string(), start));
+                    } else {
+                        check.amend(Fix::insertion(r"\".to_string(), location));
+                    }
                 }
                 checks.push(check);
             }
@@ -266,3 +382,245 @@ pub fn invalid_escape_sequence(
 
     checks
 }
+
+/// E201, E202, E211, E231
+///
+/// E203 (whitespace before punctuation) and the E221-E228 operator-whitespace
+/// family aren't covered here: unlike the brackets/comma cases above, they need
+/// to distinguish binary operators from unary ones (`-1` vs. `x - 1`) and from
+/// keyword-argument/default-value `=`, which this token-pair scan has no way to
+/// do without effectively becoming a logical-line parser.
+pub fn extraneous_whitespace(
+    tokens: &[LexResult],
+    settings: &Settings,
+    autofix: flags::Autofix,
+) -> Vec<Check> {
+    let mut checks = vec![];
+    let mut prev_tok: Option<&Tok> = None;
+    for ((_, a_tok, a_end), (b_start, b_tok, _)) in tokens.iter().flatten().tuple_windows() {
+        if a_end.row() != b_start.row() {
+            prev_tok = Some(a_tok);
+            continue;
+        }
+        let has_whitespace = b_start.column() > a_end.column();
+
+        // E201
+        if settings.enabled.contains(&CheckCode::E201)
+            && has_whitespace
+            && matches!(a_tok, Tok::Lpar | Tok::Lsqb | Tok::Lbrace)
+        {
+            let mut check = Check::new(
+                violations::WhitespaceAfterOpenBracket,
+                Range::new(*a_end, *b_start),
+            );
+            if matches!(autofix, flags::Autofix::Enabled)
+                && settings.fixable.contains(&CheckCode::E201)
+            {
+                check.amend(Fix::deletion(*a_end, *b_start));
+            }
+            checks.push(check);
+        }
+
+        // E202
+        if settings.enabled.contains(&CheckCode::E202)
+            && has_whitespace
+            && matches!(b_tok, Tok::Rpar | Tok::Rsqb | Tok::Rbrace)
+        {
+            let mut check = Check::new(
+                violations::WhitespaceBeforeCloseBracket,
+                Range::new(*a_end, *b_start),
+            );
+            if matches!(autofix, flags::Autofix::Enabled)
+                && settings.fixable.contains(&CheckCode::E202)
+            {
+                check.amend(Fix::deletion(*a_end, *b_start));
+            }
+            checks.push(check);
+        }
+
+        // E211
+        if settings.enabled.contains(&CheckCode::E211)
+            && has_whitespace
+            && matches!(a_tok, Tok::Name { .. } | Tok::Rpar | Tok::Rsqb | Tok::String { .. })
+            && matches!(b_tok, Tok::Lpar | Tok::Lsqb)
+            // Exempt `class Foo (Base):`, which pycodestyle explicitly allows: the
+            // space isn't introducing a call/subscript, it's just style around the
+            // base-class list.
+            && !matches!(prev_tok, Some(Tok::Class))
+        {
+            let mut check = Check::new(
+                violations::WhitespaceBeforeParameters,
+                Range::new(*a_end, *b_start),
+            );
+            if matches!(autofix, flags::Autofix::Enabled)
+                && settings.fixable.contains(&CheckCode::E211)
+            {
+                check.amend(Fix::deletion(*a_end, *b_start));
+            }
+            checks.push(check);
+        }
+
+        // E231
+        if settings.enabled.contains(&CheckCode::E231)
+            && !has_whitespace
+            && matches!(a_tok, Tok::Comma)
+            && !matches!(b_tok, Tok::Rpar | Tok::Rsqb | Tok::Rbrace | Tok::Comma)
+        {
+            let mut check = Check::new(
+                violations::MissingWhitespaceAfterComma,
+                Range::new(*a_end, *b_start),
+            );
+            if matches!(autofix, flags::Autofix::Enabled)
+                && settings.fixable.contains(&CheckCode::E231)
+            {
+                check.amend(Fix::insertion(" ".to_string(), *a_end));
+            }
+            checks.push(check);
+        }
+
+        prev_tok = Some(a_tok);
+    }
+    checks
+}
+
+const INDENT_SIZE: usize = 4;
+
+/// E111, E117
+pub fn indentation(tokens: &[LexResult], settings: &Settings) -> Vec<Check> {
+    let mut checks = vec![];
+    let mut levels: Vec<usize> = vec![0];
+    for &(start, ref tok, end) in tokens.iter().flatten() {
+        match tok {
+            Tok::Indent => {
+                let indent = end.column();
+                let prev = *levels.last().unwrap();
+
+                if settings.enabled.contains(&CheckCode::E111) && indent % INDENT_SIZE != 0 {
+                    checks.push(Check::new(
+                        violations::IndentationWithInvalidMultiple(INDENT_SIZE),
+                        Range::new(start, end),
+                    ));
+                } else if settings.enabled.contains(&CheckCode::E117)
+                    && indent > prev + INDENT_SIZE
+                {
+                    checks.push(Check::new(violations::OverIndented, Range::new(start, end)));
+                }
+
+                levels.push(indent);
+            }
+            Tok::Dedent => {
+                if levels.len() > 1 {
+                    levels.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+    checks
+}
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum BinaryOperatorKind {
+    /// Can only be used in a binary position (e.g. `==`, `and`).
+    AlwaysBinary,
+    /// May also appear in a unary position (e.g. `-`, `*`); only treated as
+    /// binary when the preceding token looks like the end of an operand.
+    MaybeUnary,
+}
+
+fn binary_operator_kind(tok: &Tok) -> Option<BinaryOperatorKind> {
+    match tok {
+        Tok::Plus | Tok::Minus | Tok::Star | Tok::DoubleStar | Tok::At => {
+            Some(BinaryOperatorKind::MaybeUnary)
+        }
+        Tok::Slash
+        | Tok::DoubleSlash
+        | Tok::Percent
+        | Tok::Amper
+        | Tok::Vbar
+        | Tok::CircumFlex
+        | Tok::LeftShift
+        | Tok::RightShift
+        | Tok::Less
+        | Tok::Greater
+        | Tok::LessEqual
+        | Tok::GreaterEqual
+        | Tok::EqEqual
+        | Tok::NotEqual
+        | Tok::And
+        | Tok::Or
+        | Tok::In
+        | Tok::Is => Some(BinaryOperatorKind::AlwaysBinary),
+        _ => None,
+    }
+}
+
+/// Whether `tok` could plausibly end an operand, so that a following
+/// `MaybeUnary` operator is being used in a binary (rather than prefix)
+/// position.
+fn is_operand_end(tok: &Tok) -> bool {
+    matches!(
+        tok,
+        Tok::Name { .. } | Tok::String { .. } | Tok::Rpar | Tok::Rsqb | Tok::Rbrace
+    )
+}
+
+fn is_binary_operator_use(before: &Tok, op: &Tok) -> bool {
+    match binary_operator_kind(op) {
+        Some(BinaryOperatorKind::AlwaysBinary) => true,
+        Some(BinaryOperatorKind::MaybeUnary) => is_operand_end(before),
+        None => false,
+    }
+}
+
+/// W503, W504
+///
+/// These are mutually-exclusive, default-off rules for teams that want to
+/// enforce one style of line-breaking around binary operators; see
+/// `INCOMPATIBLE_CODES`. Detection is scoped to the common arithmetic,
+/// bitwise, comparison, and `and`/`or`/`in`/`is` operators, and only
+/// disambiguates unary usage via the single preceding token (so, e.g., a
+/// numeric literal immediately before a `MaybeUnary` operator is not
+/// recognized as an operand).
+pub fn break_around_binary_operators(tokens: &[LexResult], settings: &Settings) -> Vec<Check> {
+    let mut checks = vec![];
+    if !(settings.enabled.contains(&CheckCode::W503)
+        || settings.enabled.contains(&CheckCode::W504))
+    {
+        return checks;
+    }
+
+    let significant: Vec<(Location, &Tok, Location)> = tokens
+        .iter()
+        .flatten()
+        .filter(|(_, tok, _)| {
+            !matches!(tok, Tok::Comment(_) | Tok::Indent | Tok::Dedent | Tok::Newline)
+        })
+        .map(|&(start, ref tok, end)| (start, tok, end))
+        .collect();
+
+    for window in significant.windows(3) {
+        let (_, before_tok, before_end) = window[0];
+        let (op_start, op_tok, op_end) = window[1];
+        let (next_start, _, _) = window[2];
+
+        if !is_binary_operator_use(before_tok, op_tok) {
+            continue;
+        }
+
+        if settings.enabled.contains(&CheckCode::W503) && before_end.row() != op_start.row() {
+            checks.push(Check::new(
+                violations::LineBreakBeforeBinaryOperator,
+                Range::new(op_start, op_end),
+            ));
+        }
+
+        if settings.enabled.contains(&CheckCode::W504) && op_end.row() != next_start.row() {
+            checks.push(Check::new(
+                violations::LineBreakAfterBinaryOperator,
+                Range::new(op_start, op_end),
+            ));
+        }
+    }
+    checks
+}
@@ -15,6 +15,16 @@ mod tests {
     use crate::registry::CheckCode;
     use crate::settings;
 
+    #[test_case(CheckCode::E201, Path::new("E20.py"))]
+    #[test_case(CheckCode::E202, Path::new("E20.py"))]
+    #[test_case(CheckCode::E211, Path::new("E211.py"))]
+    #[test_case(CheckCode::E111, Path::new("E11.py"))]
+    #[test_case(CheckCode::E117, Path::new("E11.py"))]
+    #[test_case(CheckCode::E231, Path::new("E231.py"))]
+    #[test_case(CheckCode::E301, Path::new("E30.py"))]
+    #[test_case(CheckCode::E302, Path::new("E30.py"))]
+    #[test_case(CheckCode::E303, Path::new("E30.py"))]
+    #[test_case(CheckCode::E306, Path::new("E30.py"))]
     #[test_case(CheckCode::E401, Path::new("E40.py"))]
     #[test_case(CheckCode::E402, Path::new("E40.py"))]
     #[test_case(CheckCode::E402, Path::new("E402.py"))]
@@ -30,11 +40,15 @@ mod tests {
     #[test_case(CheckCode::E742, Path::new("E742.py"))]
     #[test_case(CheckCode::E743, Path::new("E743.py"))]
     #[test_case(CheckCode::E999, Path::new("E999.py"))]
+    #[test_case(CheckCode::W291, Path::new("W291_W293.py"))]
+    #[test_case(CheckCode::W293, Path::new("W291_W293.py"))]
     #[test_case(CheckCode::W292, Path::new("W292_0.py"))]
     #[test_case(CheckCode::W292, Path::new("W292_1.py"))]
     #[test_case(CheckCode::W292, Path::new("W292_2.py"))]
     #[test_case(CheckCode::W292, Path::new("W292_3.py"))]
     #[test_case(CheckCode::W292, Path::new("W292_4.py"))]
+    #[test_case(CheckCode::W503, Path::new("W503_W504.py"))]
+    #[test_case(CheckCode::W504, Path::new("W503_W504.py"))]
     #[test_case(CheckCode::W605, Path::new("W605_0.py"))]
     #[test_case(CheckCode::W605, Path::new("W605_1.py"))]
     fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
@@ -68,6 +82,7 @@ mod tests {
             &settings::Settings {
                 pycodestyle: Settings {
                     ignore_overlong_task_comments,
+                    ..Settings::default()
                 },
                 ..settings::Settings::for_rule(CheckCode::E501)
             },
@@ -75,4 +90,56 @@ mod tests {
         insta::assert_yaml_snapshot!(snapshot, checks);
         Ok(())
     }
+
+    #[test_case(0)]
+    #[test_case(50)]
+    fn max_doc_length(max_doc_length: usize) -> Result<()> {
+        let snapshot = format!("max_doc_length_{max_doc_length}");
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/pycodestyle/E501_2.py"),
+            &settings::Settings {
+                pycodestyle: Settings {
+                    max_doc_length,
+                    ..Settings::default()
+                },
+                ..settings::Settings::for_rule(CheckCode::E501)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, checks);
+        Ok(())
+    }
+
+    #[test_case(1)]
+    #[test_case(8)]
+    fn tab_size(tab_size: usize) -> Result<()> {
+        let snapshot = format!("tab_size_{tab_size}");
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/pycodestyle/E501_3.py"),
+            &settings::Settings {
+                tab_size,
+                line_length: 65,
+                ..settings::Settings::for_rule(CheckCode::E501)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, checks);
+        Ok(())
+    }
+
+    #[test_case(false)]
+    #[test_case(true)]
+    fn allow_sys_path_manipulation(allow_sys_path_manipulation: bool) -> Result<()> {
+        let snapshot = format!("allow_sys_path_manipulation_{allow_sys_path_manipulation}");
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/pycodestyle/E402_sys_path.py"),
+            &settings::Settings {
+                pycodestyle: Settings {
+                    allow_sys_path_manipulation,
+                    ..Settings::default()
+                },
+                ..settings::Settings::for_rule(CheckCode::E402)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(snapshot, checks);
+        Ok(())
+    }
 }
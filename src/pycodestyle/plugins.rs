@@ -11,11 +11,97 @@ use crate::ast::types::Range;
 use crate::ast::whitespace::leading_space;
 use crate::autofix::Fix;
 use crate::checkers::ast::Checker;
-use crate::registry::Check;
+use crate::registry::{Check, CheckCode};
 use crate::source_code_generator::SourceCodeGenerator;
 use crate::source_code_style::SourceCodeStyleDetector;
 use crate::violations;
 
+/// Count the number of blank lines, and the indentation of the last non-blank
+/// line, that immediately precede `location`.
+fn blank_lines_before(checker: &Checker, location: Location) -> (usize, Option<usize>) {
+    let mut blank_lines = 0;
+    let mut prev_indent = None;
+    // Walk backwards from the line before `location`, one line at a time, so
+    // that files with many such lookups don't each re-slice everything that
+    // precedes `location`.
+    for row in (0..location.row() - 1).rev() {
+        let line = checker.locator.line(row);
+        if line.trim().is_empty() {
+            blank_lines += 1;
+        } else {
+            prev_indent = Some(leading_space(&line).len());
+            break;
+        }
+    }
+    (blank_lines, prev_indent)
+}
+
+/// E301, E302, E303, E306
+///
+/// E304 (blank lines found after a function decorator) isn't covered either:
+/// it needs the blank-line count *between* the last decorator and the
+/// `def`/`class` line that follows it, whereas `blank_lines_before` here always
+/// counts backward from the first decorator (or the statement itself, if
+/// there's no decorator) - the wrong end of the decorator list for that check.
+pub fn blank_lines(checker: &mut Checker, stmt: &Stmt, decorator_list: &[Expr]) {
+    let location = decorator_list
+        .first()
+        .map_or(stmt.location, |decorator| decorator.location);
+    let (blank_lines, prev_indent) = blank_lines_before(checker, location);
+
+    if checker.settings.enabled.contains(&CheckCode::E303) && blank_lines > 2 {
+        checker.checks.push(Check::new(
+            violations::TooManyBlankLines(blank_lines),
+            Range::new(location, location),
+        ));
+    }
+
+    // If this is the first statement in its enclosing block, no blank line is
+    // required before it (there's nothing to separate it from).
+    let Some(prev_indent) = prev_indent else {
+        return;
+    };
+    if prev_indent < location.column() {
+        return;
+    }
+
+    let depth = *checker.depths.get(checker.current_stmt()).unwrap_or(&0);
+
+    if depth == 0 {
+        if checker.settings.enabled.contains(&CheckCode::E302) && blank_lines < 2 {
+            checker.checks.push(Check::new(
+                violations::BlankLinesTopLevel(blank_lines),
+                Range::new(location, location),
+            ));
+        }
+        return;
+    }
+
+    if blank_lines > 0 {
+        return;
+    }
+
+    match checker.current_stmt_parent().map(|parent| &parent.0.node) {
+        Some(StmtKind::ClassDef { .. }) => {
+            if checker.settings.enabled.contains(&CheckCode::E301) {
+                checker.checks.push(Check::new(
+                    violations::BlankLineBetweenMethods(blank_lines),
+                    Range::new(location, location),
+                ));
+            }
+        }
+        Some(StmtKind::FunctionDef { .. } | StmtKind::AsyncFunctionDef { .. }) => {
+            if checker.settings.enabled.contains(&CheckCode::E306) {
+                checker.checks.push(Check::new(
+                    violations::BlankLineBeforeNestedDefinition(blank_lines),
+                    Range::new(location, location),
+                ));
+            }
+        }
+        _ => {}
+    }
+}
+
 pub fn compare(
     left: &Expr,
     ops: &[Cmpop],
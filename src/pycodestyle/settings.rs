@@ -20,11 +20,46 @@ pub struct Options {
     /// comments starting with `task-tags` (by default: ["TODO", "FIXME",
     /// and "XXX"]).
     pub ignore_overlong_task_comments: Option<bool>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            allow-sys-path-manipulation = true
+        "#
+    )]
+    /// Whether or not `sys.path.insert`, `.append`, and `.extend` calls
+    /// should be treated as setup code that's allowed to precede imports,
+    /// rather than triggering `E402`.
+    pub allow_sys_path_manipulation: Option<bool>,
+    #[option(
+        default = "0",
+        value_type = "usize",
+        example = r#"
+            max-doc-length = 79
+        "#
+    )]
+    /// A separate, typically shorter, line-length limit (`E501`) to apply to
+    /// comments. A value of `0` (the default) applies the same limit as
+    /// `line-length` to comments.
+    pub max_doc_length: Option<usize>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            bare-except-use-base-exception = true
+        "#
+    )]
+    /// Whether the `E722` autofix should rewrite bare `except:` clauses to
+    /// `except BaseException:` rather than `except Exception:`.
+    pub bare_except_use_base_exception: Option<bool>,
 }
 
 #[derive(Debug, Default, Hash)]
 pub struct Settings {
     pub ignore_overlong_task_comments: bool,
+    pub allow_sys_path_manipulation: bool,
+    pub max_doc_length: usize,
+    pub bare_except_use_base_exception: bool,
 }
 
 impl From<Options> for Settings {
@@ -33,6 +68,13 @@ impl From<Options> for Settings {
             ignore_overlong_task_comments: options
                 .ignore_overlong_task_comments
                 .unwrap_or_default(),
+            allow_sys_path_manipulation: options
+                .allow_sys_path_manipulation
+                .unwrap_or_default(),
+            max_doc_length: options.max_doc_length.unwrap_or_default(),
+            bare_except_use_base_exception: options
+                .bare_except_use_base_exception
+                .unwrap_or_default(),
         }
     }
 }
@@ -41,6 +83,9 @@ impl From<Settings> for Options {
     fn from(settings: Settings) -> Self {
         Self {
             ignore_overlong_task_comments: Some(settings.ignore_overlong_task_comments),
+            allow_sys_path_manipulation: Some(settings.allow_sys_path_manipulation),
+            max_doc_length: Some(settings.max_doc_length),
+            bare_except_use_base_exception: Some(settings.bare_except_use_base_exception),
         }
     }
 }
@@ -16,6 +16,7 @@ mod tests {
     use crate::settings;
 
     #[test_case(CheckCode::D100, Path::new("D.py"); "D100")]
+    #[test_case(CheckCode::D100, Path::new("D.pyi"); "D100_pyi")]
     #[test_case(CheckCode::D101, Path::new("D.py"); "D101")]
     #[test_case(CheckCode::D102, Path::new("D.py"); "D102")]
     #[test_case(CheckCode::D103, Path::new("D.py"); "D103")]
@@ -60,6 +61,7 @@ mod tests {
     #[test_case(CheckCode::D417, Path::new("canonical_google_examples.py"); "D417_2")]
     #[test_case(CheckCode::D417, Path::new("canonical_numpy_examples.py"); "D417_1")]
     #[test_case(CheckCode::D417, Path::new("sections.py"); "D417_0")]
+    #[test_case(CheckCode::D417, Path::new("D417_numpy.py"); "D417_3")]
     #[test_case(CheckCode::D418, Path::new("D.py"); "D418")]
     #[test_case(CheckCode::D419, Path::new("D.py"); "D419")]
     #[test_case(CheckCode::D104, Path::new("D104/__init__.py"); "D104_1")]
@@ -82,7 +84,10 @@ mod tests {
             &settings::Settings {
                 // When inferring the convention, we'll see a few false negatives.
                 // See: https://github.com/PyCQA/pydocstyle/issues/459.
-                pydocstyle: Settings { convention: None },
+                pydocstyle: Settings {
+                    convention: None,
+                    ignore_init_module_reexports: false,
+                },
                 ..settings::Settings::for_rule(CheckCode::D417)
             },
         )?;
@@ -98,6 +103,7 @@ mod tests {
                 // With explicit Google convention, we should flag every function.
                 pydocstyle: Settings {
                     convention: Some(Convention::Google),
+                    ignore_init_module_reexports: false,
                 },
                 ..settings::Settings::for_rule(CheckCode::D417)
             },
@@ -114,6 +120,7 @@ mod tests {
                 // With explicit Google convention, we shouldn't flag anything.
                 pydocstyle: Settings {
                     convention: Some(Convention::Numpy),
+                    ignore_init_module_reexports: false,
                 },
                 ..settings::Settings::for_rule(CheckCode::D417)
             },
@@ -121,4 +128,30 @@ mod tests {
         insta::assert_yaml_snapshot!(checks);
         Ok(())
     }
+
+    #[test]
+    fn ignore_init_module_reexports() -> Result<()> {
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/pydocstyle/D104_reexport/__init__.py"),
+            &settings::Settings {
+                pydocstyle: Settings {
+                    convention: None,
+                    ignore_init_module_reexports: true,
+                },
+                ..settings::Settings::for_rule(CheckCode::D104)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
+
+    #[test]
+    fn enforce_init_module_reexports() -> Result<()> {
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/pydocstyle/D104_reexport/__init__.py"),
+            &settings::Settings::for_rule(CheckCode::D104),
+        )?;
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
 }
@@ -20,6 +20,14 @@ use crate::registry::{Check, CheckCode};
 use crate::violations;
 use crate::visibility::{is_init, is_magic, is_overload, is_override, is_staticmethod, Visibility};
 
+/// Returns `true` if a module body consists solely of `import` and
+/// `from ... import ...` statements, as is common for `__init__.py` files
+/// that exist only to re-export names from submodules.
+fn is_reexport_module(body: &[rustpython_ast::Stmt]) -> bool {
+    body.iter()
+        .all(|stmt| matches!(stmt.node, StmtKind::Import { .. } | StmtKind::ImportFrom { .. }))
+}
+
 /// D100, D101, D102, D103, D104, D105, D106, D107
 pub fn not_missing(
     checker: &mut Checker,
@@ -40,8 +48,11 @@ pub fn not_missing(
             }
             false
         }
-        DefinitionKind::Package => {
-            if checker.settings.enabled.contains(&CheckCode::D104) {
+        DefinitionKind::Package(body) => {
+            if checker.settings.enabled.contains(&CheckCode::D104)
+                && !(checker.settings.pydocstyle.ignore_init_module_reexports
+                    && is_reexport_module(body))
+            {
                 checker.checks.push(Check::new(
                     violations::PublicPackage,
                     Range::new(Location::new(1, 0), Location::new(1, 0)),
@@ -131,10 +142,29 @@ pub fn one_liner(checker: &mut Checker, docstring: &Docstring) {
     }
 
     if non_empty_line_count == 1 && line_count > 1 {
-        checker.checks.push(Check::new(
+        let mut check = Check::new(
             violations::FitsOnOneLine,
             Range::from_located(docstring.expr),
-        ));
+        );
+        if checker.patch(check.kind.code()) {
+            if let Some(pattern) = leading_quote(docstring.contents) {
+                if let Some(line) = body.lines().find(|line| !line.trim().is_empty()) {
+                    let trimmed = line.trim();
+                    check.amend(Fix::replacement(
+                        trimmed.to_string(),
+                        Location::new(
+                            docstring.expr.location.row(),
+                            docstring.expr.location.column() + pattern.len(),
+                        ),
+                        Location::new(
+                            docstring.expr.end_location.unwrap().row(),
+                            docstring.expr.end_location.unwrap().column() - "\"\"\"".len(),
+                        ),
+                    ));
+                }
+            }
+        }
+        checker.checks.push(check);
     }
 }
 
@@ -341,17 +371,23 @@ pub fn blank_after_summary(checker: &mut Checker, docstring: &Docstring) {
             Range::from_located(docstring.expr),
         );
         if checker.patch(check.kind.code()) {
-            if blanks_count > 1 {
-                // Find the "summary" line (defined as the first non-blank line).
-                let mut summary_line = 0;
-                for line in body.lines() {
-                    if line.trim().is_empty() {
-                        summary_line += 1;
-                    } else {
-                        break;
-                    }
+            // Find the "summary" line (defined as the first non-blank line).
+            let mut summary_line = 0;
+            for line in body.lines() {
+                if line.trim().is_empty() {
+                    summary_line += 1;
+                } else {
+                    break;
                 }
+            }
 
+            if blanks_count == 0 {
+                // Insert a blank line after the summary.
+                check.amend(Fix::insertion(
+                    "\n".to_string(),
+                    Location::new(docstring.expr.location.row() + summary_line + 1, 0),
+                ));
+            } else if blanks_count > 1 {
                 // Insert one blank line after the summary (replacing any existing lines).
                 check.amend(Fix::replacement(
                     "\n".to_string(),
@@ -778,10 +814,26 @@ pub fn capitalized(checker: &mut Checker, docstring: &Docstring) {
     if first_char.is_uppercase() {
         return;
     };
-    checker.checks.push(Check::new(
+    let mut check = Check::new(
         violations::FirstLineCapitalized,
         Range::from_located(docstring.expr),
-    ));
+    );
+    if checker.patch(check.kind.code()) {
+        if let Some(pattern) = leading_quote(docstring.contents) {
+            check.amend(Fix::replacement(
+                first_char.to_uppercase().to_string(),
+                Location::new(
+                    docstring.expr.location.row(),
+                    docstring.expr.location.column() + pattern.len(),
+                ),
+                Location::new(
+                    docstring.expr.location.row(),
+                    docstring.expr.location.column() + pattern.len() + 1,
+                ),
+            ));
+        }
+    }
+    checker.checks.push(check);
 }
 
 /// D404
@@ -1208,16 +1260,12 @@ fn blanks_and_section_underline(
     }
 }
 
-fn common_section(
-    checker: &mut Checker,
-    docstring: &Docstring,
-    context: &SectionContext,
-    style: &SectionStyle,
-) {
+fn common_section(checker: &mut Checker, docstring: &Docstring, context: &SectionContext) {
     if checker.settings.enabled.contains(&CheckCode::D405) {
-        if !style.section_names().contains(&context.section_name) {
+        if !context.style.section_names().contains(&context.section_name) {
             let capitalized_section_name = titlecase::titlecase(context.section_name);
-            if style
+            if context
+                .style
                 .section_names()
                 .contains(capitalized_section_name.as_str())
             {
@@ -1251,7 +1299,7 @@ fn common_section(
     }
 
     if checker.settings.enabled.contains(&CheckCode::D214) {
-        let leading_space = whitespace::leading_space(context.line);
+        let leading_space = context.indentation;
         if leading_space.len() > docstring.indentation.len() {
             let mut check = Check::new(
                 violations::SectionNotOverIndented(context.section_name.to_string()),
@@ -1471,19 +1519,18 @@ fn args_section(checker: &mut Checker, docstring: &Docstring, context: &SectionC
 fn parameters_section(checker: &mut Checker, docstring: &Docstring, context: &SectionContext) {
     // Collect the list of arguments documented in the docstring.
     let mut docstring_args: FxHashSet<&str> = FxHashSet::default();
-    let section_level_indent = whitespace::leading_space(context.line);
+    let section_level_indent = context.indentation;
 
     // Join line continuations, then resplit by line.
     let adjusted_following_lines = context.following_lines.join("\n").replace("\\\n", "");
     let lines: Vec<&str> = LinesWithTrailingNewline::from(&adjusted_following_lines).collect();
 
-    for i in 1..lines.len() {
-        let current_line = lines[i - 1];
-        let current_leading_space = whitespace::leading_space(current_line);
-        let next_line = lines[i];
-        if current_leading_space == section_level_indent
-            && (whitespace::leading_space(next_line).len() > current_leading_space.len())
-            && !next_line.trim().is_empty()
+    for &current_line in &lines {
+        // A parameter is declared on a line at the section's own indentation level,
+        // whether or not it's followed by a description (NumPy allows omitting the
+        // description entirely).
+        if whitespace::leading_space(current_line) == section_level_indent
+            && !current_line.trim().is_empty()
         {
             let parameters = if let Some(semi_index) = current_line.find(':') {
                 // If the parameter has a type annotation, exclude it.
@@ -1504,7 +1551,7 @@ fn parameters_section(checker: &mut Checker, docstring: &Docstring, context: &Se
 }
 
 fn numpy_section(checker: &mut Checker, docstring: &Docstring, context: &SectionContext) {
-    common_section(checker, docstring, context, &SectionStyle::Numpy);
+    common_section(checker, docstring, context);
 
     if checker.settings.enabled.contains(&CheckCode::D406) {
         let suffix = context
@@ -1550,7 +1597,7 @@ fn numpy_section(checker: &mut Checker, docstring: &Docstring, context: &Section
 }
 
 fn google_section(checker: &mut Checker, docstring: &Docstring, context: &SectionContext) {
-    common_section(checker, docstring, context, &SectionStyle::Google);
+    common_section(checker, docstring, context);
 
     if checker.settings.enabled.contains(&CheckCode::D416) {
         let suffix = context
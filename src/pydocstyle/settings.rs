@@ -88,17 +88,32 @@ pub struct Options {
     /// Whether to use Google-style or NumPy-style conventions or the PEP257
     /// defaults when analyzing docstring sections.
     pub convention: Option<Convention>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            # Don't require docstrings for `__init__.py` files that only
+            # re-export names from submodules.
+            ignore-init-module-reexports = true
+        "#
+    )]
+    /// Whether to ignore missing docstrings (`D104`) for `__init__.py`
+    /// files that consist solely of `import` statements, since such files
+    /// exist only to re-export names from submodules.
+    pub ignore_init_module_reexports: Option<bool>,
 }
 
 #[derive(Debug, Default, Hash)]
 pub struct Settings {
     pub convention: Option<Convention>,
+    pub ignore_init_module_reexports: bool,
 }
 
 impl From<Options> for Settings {
     fn from(options: Options) -> Self {
         Self {
             convention: options.convention,
+            ignore_init_module_reexports: options.ignore_init_module_reexports.unwrap_or_default(),
         }
     }
 }
@@ -107,6 +122,7 @@ impl From<Settings> for Options {
     fn from(settings: Settings) -> Self {
         Self {
             convention: settings.convention,
+            ignore_init_module_reexports: Some(settings.ignore_init_module_reexports),
         }
     }
 }
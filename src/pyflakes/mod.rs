@@ -2,7 +2,9 @@ pub mod cformat;
 pub mod checks;
 pub mod fixes;
 pub mod format;
+pub(crate) mod module;
 pub mod plugins;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -30,6 +32,7 @@ mod tests {
     #[test_case(CheckCode::F401, Path::new("F401_5.py"); "F401_5")]
     #[test_case(CheckCode::F401, Path::new("F401_6.py"); "F401_6")]
     #[test_case(CheckCode::F401, Path::new("F401_7.py"); "F401_7")]
+    #[test_case(CheckCode::F401, Path::new("F401_8.pyi"); "F401_8")]
     #[test_case(CheckCode::F402, Path::new("F402.py"); "F402")]
     #[test_case(CheckCode::F403, Path::new("F403.py"); "F403")]
     #[test_case(CheckCode::F404, Path::new("F404.py"); "F404")]
@@ -104,6 +107,7 @@ mod tests {
     #[test_case(CheckCode::F841, Path::new("F841_1.py"); "F841_1")]
     #[test_case(CheckCode::F841, Path::new("F841_2.py"); "F841_2")]
     #[test_case(CheckCode::F841, Path::new("F841_3.py"); "F841_3")]
+    #[test_case(CheckCode::F841, Path::new("F841_4.py"); "F841_4")]
     #[test_case(CheckCode::F842, Path::new("F842.py"); "F842")]
     #[test_case(CheckCode::F901, Path::new("F901.py"); "F901")]
     fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
@@ -131,6 +135,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn f841_flag_unused_unpacked_variables() -> Result<()> {
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/pyflakes/F841_4.py"),
+            &settings::Settings {
+                pyflakes: super::settings::Settings {
+                    flag_unused_unpacked_variables: true,
+                },
+                ..settings::Settings::for_rule(CheckCode::F841)
+            },
+        )?;
+        insta::assert_yaml_snapshot!(checks);
+        Ok(())
+    }
+
+    #[test]
+    fn f841_dummy_variable_rgx_exception_handler() -> Result<()> {
+        // A dummy exception name (matching `dummy_variable_rgx`, e.g. `_`)
+        // shouldn't be flagged as unused, same as any other dummy binding.
+        flakes(
+            r#"
+        try:
+            pass
+        except ValueError as _:
+            pass
+        "#,
+            &[],
+        )?;
+        Ok(())
+    }
+
     #[test]
     fn init() -> Result<()> {
         let checks = test_path(
@@ -2555,6 +2590,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn append_and_extend() -> Result<()> {
+        // The C{__all__} variable is defined incrementally via `.append()` and
+        // `.extend()` calls.
+        flakes(
+            r#"
+        import a
+        import b
+        import c
+        __all__ = ['a']
+        __all__.append('b')
+        __all__.extend(['c'])
+        "#,
+            &[],
+        )?;
+
+        Ok(())
+    }
+
     #[test]
     fn list_concatenation_assignment() -> Result<()> {
         // The C{__all__} variable is defined through list concatenation.
@@ -2680,6 +2734,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn resolves_star_import_from_first_party_module() -> Result<()> {
+        // `_impl.py` is a sibling module we can resolve on disk, so `helper`
+        // and `CONST` (which it exports) satisfy `__all__`, while `missing`
+        // still triggers F822.
+        let checks = test_path(
+            Path::new("./resources/test/fixtures/pyflakes/star_import_resolution/main.py"),
+            &settings::Settings::for_rule(CheckCode::F822),
+        )?;
+        let messages: Vec<String> = checks.iter().map(|check| check.kind.body()).collect();
+        assert_eq!(messages, vec!["Undefined name `missing` in `__all__`".to_string()]);
+        Ok(())
+    }
+
     #[ignore]
     #[test]
     fn import_star_not_exported() -> Result<()> {
@@ -2975,6 +3043,49 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn redefined_if_platform_conditional() -> Result<()> {
+        // Definitions on different branches of a platform check (e.g. on
+        // `sys.version_info` or `sys.platform`) are not "redefinitions", since
+        // only one branch ever actually executes.
+        flakes(
+            r#"
+        import sys
+
+        if sys.version_info >= (3, 8):
+            def fn():
+                pass
+        else:
+            def fn():
+                pass
+
+        fn()
+        "#,
+            &[],
+        )?;
+
+        flakes(
+            r#"
+        import sys
+
+        if sys.platform == "win32":
+            def fn():
+                pass
+        elif sys.platform == "darwin":
+            def fn():
+                pass
+        else:
+            def fn():
+                pass
+
+        fn()
+        "#,
+            &[],
+        )?;
+
+        Ok(())
+    }
+
     #[test]
     fn variable_annotations() -> Result<()> {
         flakes(
@@ -3742,6 +3853,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn self_referential_forward_annotation_in_class_body() -> Result<()> {
+        // A class can reference itself in a quoted (or, with `__future__`
+        // annotations, unquoted) attribute annotation, since annotations are
+        // resolved after the entire module has been parsed.
+        flakes(
+            r#"
+        from typing import List, Optional
+
+        class Node:
+            parent: "Node"
+            children: "List[Node]"
+            next_sibling: Optional["Node"] = None
+        "#,
+            &[],
+        )?;
+
+        flakes(
+            r#"
+        from __future__ import annotations
+
+        from typing import List, Optional
+
+        class Node:
+            parent: Node
+            children: List[Node]
+            next_sibling: Optional[Node] = None
+        "#,
+            &[],
+        )?;
+        Ok(())
+    }
+
     #[test]
     fn idiomiatic_typing_guards() -> Result<()> {
         // typing.TYPE_CHECKING: python3.5.3+.
@@ -0,0 +1,100 @@
+//! Best-effort resolution of `from x import *` to a first-party module on
+//! disk, so that `F822` can check the names exported via `__all__` against
+//! that module's actual top-level bindings, rather than unconditionally
+//! giving up whenever a star import is present.
+//!
+//! The filesystem lookup itself lives in [`crate::module_resolver`], which
+//! is shared infrastructure rather than pyflakes-specific; this module
+//! layers pyflakes' own `__all__`/public-name extraction on top of it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rustpython_parser::ast::{Constant, ExprKind, StmtKind, Suite};
+use rustpython_parser::parser;
+
+use crate::module_resolver;
+
+/// Return the names that `from <module> import *` (relative to `path`, with
+/// the given `level` and `src` roots) would bind, or `None` if the module
+/// can't be resolved to a first-party file on disk or can't be parsed.
+pub fn star_import_names(
+    path: &Path,
+    src: &[PathBuf],
+    level: Option<usize>,
+    module: Option<&str>,
+) -> Option<Vec<String>> {
+    let module_path = module_resolver::resolve(path, src, level, module)?;
+    let contents = fs::read_to_string(module_path).ok()?;
+    let python_ast = parser::parse_program(&contents, "<filename>").ok()?;
+    Some(collect_exported_names(&python_ast))
+}
+
+/// Collect the names that `from module import *` would bind: the contents
+/// of `__all__`, if defined, or else every public (non-underscore-prefixed)
+/// top-level name otherwise.
+fn collect_exported_names(python_ast: &Suite) -> Vec<String> {
+    let mut all_names: Vec<String> = vec![];
+    let mut has_all = false;
+    let mut public_names: Vec<String> = vec![];
+
+    for stmt in python_ast {
+        match &stmt.node {
+            StmtKind::Assign { targets, value, .. } => {
+                if let [target] = targets.as_slice() {
+                    if let ExprKind::Name { id, .. } = &target.node {
+                        if id == "__all__" {
+                            has_all = true;
+                            if let ExprKind::List { elts, .. } | ExprKind::Tuple { elts, .. } =
+                                &value.node
+                            {
+                                for elt in elts {
+                                    if let ExprKind::Constant {
+                                        value: Constant::Str(value),
+                                        ..
+                                    } = &elt.node
+                                    {
+                                        all_names.push(value.clone());
+                                    }
+                                }
+                            }
+                            continue;
+                        }
+                        push_public(&mut public_names, id);
+                    }
+                }
+            }
+            StmtKind::FunctionDef { name, .. } | StmtKind::AsyncFunctionDef { name, .. } => {
+                push_public(&mut public_names, name);
+            }
+            StmtKind::ClassDef { name, .. } => {
+                push_public(&mut public_names, name);
+            }
+            StmtKind::Import { names } => {
+                for alias in names {
+                    let name = alias.node.asname.as_ref().unwrap_or(&alias.node.name);
+                    push_public(&mut public_names, name.split('.').next().unwrap_or(name));
+                }
+            }
+            StmtKind::ImportFrom { names, .. } => {
+                for alias in names {
+                    let name = alias.node.asname.as_ref().unwrap_or(&alias.node.name);
+                    push_public(&mut public_names, name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if has_all {
+        all_names
+    } else {
+        public_names
+    }
+}
+
+fn push_public(names: &mut Vec<String>, name: &str) {
+    if !name.starts_with('_') {
+        names.push(name.to_string());
+    }
+}
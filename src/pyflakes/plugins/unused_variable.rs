@@ -157,13 +157,17 @@ pub fn unused_variable(checker: &mut Checker, scope: usize) {
         return;
     }
 
+    let extra_binding_kinds = checker.settings.pyflakes.flag_unused_unpacked_variables;
+
     for (name, binding) in scope
         .values
         .iter()
         .map(|(name, index)| (name, &checker.bindings[*index]))
     {
         if binding.used.is_none()
-            && matches!(binding.kind, BindingKind::Assignment)
+            && (matches!(binding.kind, BindingKind::Assignment)
+                || (extra_binding_kinds
+                    && matches!(binding.kind, BindingKind::Binding | BindingKind::LoopVar)))
             && !checker.settings.dummy_variable_rgx.is_match(name)
             && name != &"__tracebackhide__"
             && name != &"__traceback_info__"
@@ -0,0 +1,56 @@
+//! Settings for the `pyflakes` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(
+    deny_unknown_fields,
+    rename_all = "kebab-case",
+    rename = "PyflakesOptions"
+)]
+pub struct Options {
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            # Flag `x, y = func()` and `for x in ...` targets as unused
+            # variables too, rather than only simple assignments.
+            flag-unused-unpacked-variables = true
+        "#
+    )]
+    /// Whether `F841` should also flag unused variables bound via
+    /// tuple-unpacking assignment (e.g., `x, y = func()`) or `for` loop
+    /// targets, in addition to simple assignments (e.g., `x = func()`).
+    /// Pyflakes ignores these bindings by default, since they're often kept
+    /// for the sake of the other names they're unpacked alongside, or to
+    /// document a loop's shape; enable this to match flake8's behavior
+    /// during a migration from a stricter unused-variable checker.
+    pub flag_unused_unpacked_variables: Option<bool>,
+}
+
+#[derive(Debug, Default, Hash)]
+pub struct Settings {
+    pub flag_unused_unpacked_variables: bool,
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            flag_unused_unpacked_variables: options
+                .flag_unused_unpacked_variables
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            flag_unused_unpacked_variables: Some(settings.flag_unused_unpacked_variables),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use rustpython_ast::Location;
+
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+static INVALID_MOCK_ACCESS_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\.(called_once_with|called_once|called_with|not_called|has_calls|any_call|called)\(").unwrap()
+});
+
+/// PGH005 - invalid mock methods
+pub fn invalid_mock_access(lineno: usize, line: &str) -> Option<Check> {
+    let capture = INVALID_MOCK_ACCESS_REGEX.captures(line)?;
+    let method = capture.get(1)?;
+    Some(Check::new(
+        violations::InvalidMockAccess(method.as_str().to_string()),
+        Range::new(
+            Location::new(lineno + 1, method.start()),
+            Location::new(lineno + 1, method.end()),
+        ),
+    ))
+}
@@ -1,9 +1,11 @@
 pub use blanket_noqa::blanket_noqa;
 pub use blanket_type_ignore::blanket_type_ignore;
 pub use deprecated_log_warn::deprecated_log_warn;
+pub use invalid_mock_access::invalid_mock_access;
 pub use no_eval::no_eval;
 
 mod blanket_noqa;
 mod blanket_type_ignore;
 mod deprecated_log_warn;
+mod invalid_mock_access;
 mod no_eval;
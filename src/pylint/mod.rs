@@ -1,4 +1,5 @@
 pub mod plugins;
+pub mod settings;
 
 #[cfg(test)]
 mod tests {
@@ -12,14 +13,28 @@ mod tests {
     use crate::Settings;
 
     #[test_case(CheckCode::PLC0414, Path::new("import_aliasing.py"); "PLC0414")]
+    #[test_case(CheckCode::PLC2701, Path::new("import_private_name.py"); "PLC2701")]
+    #[test_case(CheckCode::PLC2801, Path::new("unnecessary_dunder_call.py"); "PLC2801")]
     #[test_case(CheckCode::PLC2201, Path::new("misplaced_comparison_constant.py"); "PLC2201")]
     #[test_case(CheckCode::PLC3002, Path::new("unnecessary_direct_lambda_call.py"); "PLC3002")]
+    #[test_case(CheckCode::PLE0101, Path::new("return_in_init.py"); "PLE0101")]
     #[test_case(CheckCode::PLE0117, Path::new("nonlocal_without_binding.py"); "PLE0117")]
+    #[test_case(CheckCode::PLE0241, Path::new("duplicate_bases.py"); "PLE0241")]
+    #[test_case(CheckCode::PLE0302, Path::new("bad_dunder_method_signature.py"); "PLE0302")]
+    #[test_case(CheckCode::PLR0124, Path::new("comparison_with_itself.py"); "PLR0124")]
+    #[test_case(CheckCode::PLR0133, Path::new("comparison_of_constants.py"); "PLR0133")]
     #[test_case(CheckCode::PLE0118, Path::new("used_prior_global_declaration.py"); "PLE0118")]
+    #[test_case(CheckCode::PLE1132, Path::new("repeated_keyword_argument.py"); "PLE1132")]
     #[test_case(CheckCode::PLE1142, Path::new("await_outside_async.py"); "PLE1142")]
     #[test_case(CheckCode::PLR0206, Path::new("property_with_parameters.py"); "PLR0206")]
     #[test_case(CheckCode::PLR0402, Path::new("import_aliasing.py"); "PLR0402")]
+    #[test_case(CheckCode::PLR0911, Path::new("too_many_return_statements.py"); "PLR0911")]
+    #[test_case(CheckCode::PLR0912, Path::new("too_many_branches.py"); "PLR0912")]
+    #[test_case(CheckCode::PLR0913, Path::new("too_many_arguments.py"); "PLR0913")]
+    #[test_case(CheckCode::PLR0915, Path::new("too_many_statements.py"); "PLR0915")]
+    #[test_case(CheckCode::PLR2004, Path::new("magic_value_comparison.py"); "PLR2004")]
     #[test_case(CheckCode::PLR1701, Path::new("consider_merging_isinstance.py"); "PLR1701")]
+    #[test_case(CheckCode::PLR1711, Path::new("useless_return.py"); "PLR1711")]
     #[test_case(CheckCode::PLR1722, Path::new("consider_using_sys_exit_0.py"); "PLR1722_0")]
     #[test_case(CheckCode::PLR1722, Path::new("consider_using_sys_exit_1.py"); "PLR1722_1")]
     #[test_case(CheckCode::PLR1722, Path::new("consider_using_sys_exit_2.py"); "PLR1722_2")]
@@ -27,8 +42,14 @@ mod tests {
     #[test_case(CheckCode::PLR1722, Path::new("consider_using_sys_exit_4.py"); "PLR1722_4")]
     #[test_case(CheckCode::PLR1722, Path::new("consider_using_sys_exit_5.py"); "PLR1722_5")]
     #[test_case(CheckCode::PLR1722, Path::new("consider_using_sys_exit_6.py"); "PLR1722_6")]
+    #[test_case(CheckCode::PLR5501, Path::new("collapsible_else_if.py"); "PLR5501")]
+    #[test_case(CheckCode::PLW0101, Path::new("unreachable_code.py"); "PLW0101")]
     #[test_case(CheckCode::PLW0120, Path::new("useless_else_on_loop.py"); "PLW0120")]
+    #[test_case(CheckCode::PLW0125, Path::new("using_constant_test.py"); "PLW0125")]
+    #[test_case(CheckCode::PLW0406, Path::new("import_self.py"); "PLW0406")]
     #[test_case(CheckCode::PLW0602, Path::new("global_variable_not_assigned.py"); "PLW0602")]
+    #[test_case(CheckCode::PLW2901, Path::new("redefined_loop_name.py"); "PLW2901")]
+    #[test_case(CheckCode::PLW3301, Path::new("nested_min_max.py"); "PLW3301")]
     fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", check_code.as_ref(), path.to_string_lossy());
         let checks = test_path(
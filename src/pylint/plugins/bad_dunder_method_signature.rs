@@ -0,0 +1,70 @@
+use rustpython_ast::{Arguments, Stmt};
+
+use crate::ast::helpers::identifier_range;
+use crate::checkers::ast::Checker;
+use crate::{violations, Check};
+
+struct DunderSpec {
+    name: &'static str,
+    min_args: usize,
+    max_args: usize,
+}
+
+/// The number of arguments, beyond `self`, that each dunder method is expected to accept.
+const DUNDER_SPECS: &[DunderSpec] = &[
+    DunderSpec { name: "__repr__", min_args: 0, max_args: 0 },
+    DunderSpec { name: "__str__", min_args: 0, max_args: 0 },
+    DunderSpec { name: "__bool__", min_args: 0, max_args: 0 },
+    DunderSpec { name: "__len__", min_args: 0, max_args: 0 },
+    DunderSpec { name: "__hash__", min_args: 0, max_args: 0 },
+    DunderSpec { name: "__iter__", min_args: 0, max_args: 0 },
+    DunderSpec { name: "__next__", min_args: 0, max_args: 0 },
+    DunderSpec { name: "__del__", min_args: 0, max_args: 0 },
+    DunderSpec { name: "__enter__", min_args: 0, max_args: 0 },
+    DunderSpec { name: "__exit__", min_args: 3, max_args: 3 },
+    DunderSpec { name: "__eq__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__ne__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__lt__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__le__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__gt__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__ge__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__add__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__sub__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__mul__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__getitem__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__setitem__", min_args: 2, max_args: 2 },
+    DunderSpec { name: "__delitem__", min_args: 1, max_args: 1 },
+    DunderSpec { name: "__contains__", min_args: 1, max_args: 1 },
+];
+
+/// PLE0302
+pub fn bad_dunder_method_signature(
+    checker: &mut Checker,
+    stmt: &Stmt,
+    name: &str,
+    args: &Arguments,
+) {
+    let Some(spec) = DUNDER_SPECS.iter().find(|spec| spec.name == name) else {
+        return;
+    };
+
+    // A `*args` or `**kwargs` can absorb any number of arguments, so we can't flag a mismatch.
+    if args.vararg.is_some() || args.kwarg.is_some() {
+        return;
+    }
+
+    let positional = args.posonlyargs.len() + args.args.len();
+    let Some(num_args) = positional.checked_sub(1) else {
+        // Missing even a `self` parameter; left to other checks.
+        return;
+    };
+    let num_defaults = args.defaults.len();
+    let min_args = num_args.saturating_sub(num_defaults);
+
+    if num_args < spec.min_args || min_args > spec.max_args {
+        checker.checks.push(Check::new(
+            violations::BadDunderMethodSignature(name.to_string(), spec.max_args),
+            identifier_range(stmt, checker.locator),
+        ));
+    }
+}
@@ -0,0 +1,44 @@
+use rustpython_ast::{Stmt, StmtKind};
+
+use crate::ast::helpers::else_range;
+use crate::autofix::Fix;
+use crate::checkers::ast::Checker;
+use crate::registry::{Check, CheckCode};
+use crate::violations;
+
+/// PLR5501
+pub fn collapsible_else_if(checker: &mut Checker, stmt: &Stmt) {
+    let StmtKind::If { orelse, .. } = &stmt.node else {
+        return;
+    };
+    let [inner] = orelse.as_slice() else {
+        return;
+    };
+    if !matches!(inner.node, StmtKind::If { .. }) {
+        return;
+    }
+
+    // A "real" `elif` parses to the same AST shape as `else: if ...`, so the only
+    // way to tell them apart is by comparing indentation: an `elif` sits at the
+    // same column as the `if` it follows, whereas a nested `if` is indented one
+    // level deeper than the enclosing `else`.
+    if inner.location.column() <= stmt.location.column() {
+        return;
+    }
+
+    let Some(range) = else_range(stmt, checker.locator) else {
+        return;
+    };
+
+    let mut check = Check::new(violations::CollapsibleElseIf, range);
+    if checker.patch(&CheckCode::PLR5501) {
+        // Collapse `else:` followed by a lone nested `if` into `elif`, leaving the
+        // nested `if`'s test, body, and any further branches untouched.
+        check.amend(Fix::replacement(
+            "el".to_string(),
+            range.location,
+            inner.location,
+        ));
+    }
+    checker.checks.push(check);
+}
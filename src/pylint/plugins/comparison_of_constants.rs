@@ -0,0 +1,22 @@
+use rustpython_ast::{Expr, ExprKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::{violations, Check};
+
+/// PLR0133
+pub fn comparison_of_constants(checker: &mut Checker, left: &Expr, comparators: &[Expr]) {
+    let mut operands = std::iter::once(left).chain(comparators.iter());
+    let mut previous = operands.next().unwrap();
+    for next in operands {
+        if matches!(&previous.node, ExprKind::Constant { .. })
+            && matches!(&next.node, ExprKind::Constant { .. })
+        {
+            checker.checks.push(Check::new(
+                violations::ComparisonOfConstant,
+                Range::new(previous.location, next.end_location.unwrap()),
+            ));
+        }
+        previous = next;
+    }
+}
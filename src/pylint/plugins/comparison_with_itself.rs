@@ -0,0 +1,23 @@
+use rustpython_ast::Expr;
+
+use crate::ast::comparable::ComparableExpr;
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::{violations, Check};
+
+/// PLR0124
+pub fn comparison_with_itself(checker: &mut Checker, left: &Expr, comparators: &[Expr]) {
+    let mut operands = std::iter::once(left).chain(comparators.iter());
+    let mut previous = operands.next().unwrap();
+    for next in operands {
+        let previous_comparable: ComparableExpr = previous.into();
+        let next_comparable: ComparableExpr = next.into();
+        if previous_comparable == next_comparable {
+            checker.checks.push(Check::new(
+                violations::ComparisonWithItself,
+                Range::new(previous.location, next.end_location.unwrap()),
+            ));
+        }
+        previous = next;
+    }
+}
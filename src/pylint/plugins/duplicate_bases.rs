@@ -0,0 +1,22 @@
+use rustc_hash::FxHashSet;
+use rustpython_ast::{Expr, ExprKind, Stmt};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::{violations, Check};
+
+/// PLE0241
+pub fn duplicate_bases(checker: &mut Checker, stmt: &Stmt, bases: &[Expr]) {
+    let mut seen: FxHashSet<&str> = FxHashSet::default();
+    for base in bases {
+        let ExprKind::Name { id, .. } = &base.node else {
+            continue;
+        };
+        if !seen.insert(id.as_str()) {
+            checker.checks.push(Check::new(
+                violations::DuplicateBases(id.to_string()),
+                Range::from_located(stmt),
+            ));
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use rustpython_ast::Alias;
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::{violations, Check};
+
+/// Returns `true` if `name` looks like a private name, e.g. `_foo`, but not a
+/// dunder, e.g. `__foo__`.
+fn is_private_name(name: &str) -> bool {
+    name.starts_with('_') && !name.starts_with("__")
+}
+
+/// PLC2701
+pub fn import_private_name(checker: &mut Checker, module: &str, alias: &Alias) {
+    if alias.node.name == "*" {
+        return;
+    }
+    if !is_private_name(&alias.node.name) {
+        return;
+    }
+    checker.checks.push(Check::new(
+        violations::ImportPrivateName(module.to_string(), alias.node.name.to_string()),
+        Range::from_located(alias),
+    ));
+}
@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use rustpython_ast::Stmt;
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::{violations, Check};
+
+/// Return the unqualified (last-component) module name that `path` itself
+/// represents, if one can be inferred (e.g. `foo/bar.py` => `bar`, `foo/bar/__init__.py` => `bar`).
+fn own_module_name(path: &Path) -> Option<&str> {
+    let stem = path.file_stem()?.to_str()?;
+    if stem == "__init__" {
+        path.parent()?.file_name()?.to_str()
+    } else {
+        Some(stem)
+    }
+}
+
+/// PLW0406
+pub fn import_self(checker: &mut Checker, stmt: &Stmt, name: &str) {
+    let Some(own_name) = own_module_name(checker.path) else {
+        return;
+    };
+    let first_component = name.split('.').next().unwrap_or(name);
+    if first_component == own_name {
+        checker.checks.push(Check::new(
+            violations::ImportSelf(own_name.to_string()),
+            Range::from_located(stmt),
+        ));
+    }
+}
+
+/// PLW0406 (`from` variant)
+pub fn import_from_self(checker: &mut Checker, stmt: &Stmt, level: Option<&usize>, module: &str) {
+    if level.map_or(false, |level| *level > 0) {
+        return;
+    }
+    let Some(own_name) = own_module_name(checker.path) else {
+        return;
+    };
+    let first_component = module.split('.').next().unwrap_or(module);
+    if first_component == own_name {
+        checker.checks.push(Check::new(
+            violations::ImportSelf(own_name.to_string()),
+            Range::from_located(stmt),
+        ));
+    }
+}
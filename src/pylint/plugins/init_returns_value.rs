@@ -0,0 +1,50 @@
+use rustpython_ast::{Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::{violations, Check};
+
+fn find_returns<'a>(body: &'a [Stmt], returns: &mut Vec<&'a Stmt>) {
+    for stmt in body {
+        match &stmt.node {
+            StmtKind::Return { value: Some(_) } => returns.push(stmt),
+            StmtKind::If { body, orelse, .. }
+            | StmtKind::For { body, orelse, .. }
+            | StmtKind::AsyncFor { body, orelse, .. }
+            | StmtKind::While { body, orelse, .. } => {
+                find_returns(body, returns);
+                find_returns(orelse, returns);
+            }
+            StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => {
+                find_returns(body, returns);
+            }
+            StmtKind::Try {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+            } => {
+                find_returns(body, returns);
+                find_returns(orelse, returns);
+                find_returns(finalbody, returns);
+                for handler in handlers {
+                    let rustpython_ast::ExcepthandlerKind::ExceptHandler { body, .. } =
+                        &handler.node;
+                    find_returns(body, returns);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// PLE0101
+pub fn init_returns_value(checker: &mut Checker, body: &[Stmt]) {
+    let mut returns = Vec::new();
+    find_returns(body, &mut returns);
+    for stmt in returns {
+        checker
+            .checks
+            .push(Check::new(violations::ReturnInInit, Range::from_located(stmt)));
+    }
+}
@@ -0,0 +1,103 @@
+use rustc_hash::FxHashSet;
+use rustpython_ast::{Expr, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::{violations, Check};
+
+/// Collect the `ExprKind::Name` leaves of an assignment target, e.g. `a` and `b` in `a, b = ...`.
+fn collect_target<'a>(target: &'a Expr, names: &mut Vec<(&'a str, &'a Expr)>) {
+    match &target.node {
+        ExprKind::Name { id, .. } => names.push((id, target)),
+        ExprKind::Tuple { elts, .. } | ExprKind::List { elts, .. } => {
+            for elt in elts {
+                collect_target(elt, names);
+            }
+        }
+        ExprKind::Starred { value, .. } => collect_target(value, names),
+        _ => {}
+    }
+}
+
+/// Collect every name reassigned anywhere in `body`, skipping nested scopes (functions and
+/// classes introduce their own bindings).
+fn reassigned_names<'a>(body: &'a [Stmt], reassigned: &mut Vec<(&'a str, &'a Expr)>) {
+    for stmt in body {
+        match &stmt.node {
+            StmtKind::Assign { targets, .. } => {
+                for target in targets {
+                    collect_target(target, reassigned);
+                }
+            }
+            StmtKind::AugAssign { target, .. } | StmtKind::AnnAssign { target, .. } => {
+                collect_target(target, reassigned);
+            }
+            StmtKind::For {
+                target,
+                body,
+                orelse,
+                ..
+            }
+            | StmtKind::AsyncFor {
+                target,
+                body,
+                orelse,
+                ..
+            } => {
+                collect_target(target, reassigned);
+                reassigned_names(body, reassigned);
+                reassigned_names(orelse, reassigned);
+            }
+            StmtKind::While { body, orelse, .. } | StmtKind::If { body, orelse, .. } => {
+                reassigned_names(body, reassigned);
+                reassigned_names(orelse, reassigned);
+            }
+            StmtKind::With { items, body, .. } | StmtKind::AsyncWith { items, body, .. } => {
+                for item in items {
+                    if let Some(optional_vars) = &item.optional_vars {
+                        collect_target(optional_vars, reassigned);
+                    }
+                }
+                reassigned_names(body, reassigned);
+            }
+            StmtKind::Try {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+            } => {
+                reassigned_names(body, reassigned);
+                reassigned_names(orelse, reassigned);
+                reassigned_names(finalbody, reassigned);
+                for handler in handlers {
+                    let rustpython_ast::ExcepthandlerKind::ExceptHandler { body, .. } =
+                        &handler.node;
+                    reassigned_names(body, reassigned);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// PLW2901
+pub fn loop_variable_overwritten(checker: &mut Checker, target: &Expr, body: &[Stmt]) {
+    let mut control_names = Vec::new();
+    collect_target(target, &mut control_names);
+    if control_names.is_empty() {
+        return;
+    }
+    let control_names: FxHashSet<&str> = control_names.into_iter().map(|(name, _)| name).collect();
+
+    let mut reassigned = Vec::new();
+    reassigned_names(body, &mut reassigned);
+
+    for (name, expr) in reassigned {
+        if control_names.contains(name) {
+            checker.checks.push(Check::new(
+                violations::RedefinedLoopName(name.to_string()),
+                Range::from_located(expr),
+            ));
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use rustpython_ast::{Cmpop, Constant, Expr, ExprKind};
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::{violations, Check};
+
+fn magic_value_repr(constant: &Constant) -> Option<String> {
+    match constant {
+        Constant::Int(value) => Some(value.to_string()),
+        Constant::Float(value) => Some(value.to_string()),
+        Constant::Str(value) => Some(format!("{value:?}")),
+        _ => None,
+    }
+}
+
+fn is_magic_value<'a>(expr: &'a Expr, allowed_values: &[String]) -> Option<(&'a Expr, String)> {
+    let ExprKind::Constant { value, .. } = &expr.node else {
+        return None;
+    };
+    let repr = magic_value_repr(value)?;
+    if allowed_values.contains(&repr) {
+        None
+    } else {
+        Some((expr, repr))
+    }
+}
+
+/// PLR2004
+pub fn magic_value_comparison(
+    checker: &mut Checker,
+    left: &Expr,
+    ops: &[Cmpop],
+    comparators: &[Expr],
+) {
+    let allowed_values = &checker.settings.pylint.allowed_magic_values;
+
+    let mut operands = std::iter::once(left).chain(comparators.iter());
+    let mut previous = operands.next().unwrap();
+    for (op, next) in ops.iter().zip(operands) {
+        if matches!(op, Cmpop::Eq | Cmpop::NotEq) {
+            let constant_operand = match (
+                matches!(previous.node, ExprKind::Constant { .. }),
+                matches!(next.node, ExprKind::Constant { .. }),
+            ) {
+                (true, false) => is_magic_value(previous, allowed_values),
+                (false, true) => is_magic_value(next, allowed_values),
+                _ => None,
+            };
+            if let Some((value, repr)) = constant_operand {
+                checker.checks.push(Check::new(
+                    violations::MagicValueComparison(repr),
+                    Range::from_located(value),
+                ));
+            }
+        }
+        previous = next;
+    }
+}
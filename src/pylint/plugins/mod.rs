@@ -1,21 +1,61 @@
 pub use await_outside_async::await_outside_async;
+pub use bad_dunder_method_signature::bad_dunder_method_signature;
+pub use collapsible_else_if::collapsible_else_if;
+pub use comparison_of_constants::comparison_of_constants;
+pub use comparison_with_itself::comparison_with_itself;
+pub use duplicate_bases::duplicate_bases;
+pub use import_private_name::import_private_name;
+pub use import_self::{import_from_self, import_self};
+pub use init_returns_value::init_returns_value;
+pub use loop_variable_overwritten::loop_variable_overwritten;
+pub use magic_value_comparison::magic_value_comparison;
 pub use merge_isinstance::merge_isinstance;
 pub use misplaced_comparison_constant::misplaced_comparison_constant;
+pub use nested_min_max::nested_min_max;
 pub use property_with_parameters::property_with_parameters;
+pub use repeated_keyword_argument::repeated_keyword_argument;
+pub use too_many_arguments::too_many_arguments;
+pub use too_many_branches::too_many_branches;
+pub use too_many_return_statements::too_many_return_statements;
+pub use too_many_statements::too_many_statements;
 pub use unnecessary_direct_lambda_call::unnecessary_direct_lambda_call;
+pub use unnecessary_dunder_call::unnecessary_dunder_call;
+pub use unreachable_code::unreachable_code;
 pub use use_from_import::use_from_import;
 pub use use_sys_exit::use_sys_exit;
 pub use used_prior_global_declaration::used_prior_global_declaration;
 pub use useless_else_on_loop::useless_else_on_loop;
 pub use useless_import_alias::useless_import_alias;
+pub use useless_return::useless_return;
+pub use using_constant_test::using_constant_test;
 
 mod await_outside_async;
+mod bad_dunder_method_signature;
+mod collapsible_else_if;
+mod comparison_of_constants;
+mod comparison_with_itself;
+mod duplicate_bases;
+mod import_private_name;
+mod import_self;
+mod init_returns_value;
+mod loop_variable_overwritten;
+mod magic_value_comparison;
 mod merge_isinstance;
 mod misplaced_comparison_constant;
+mod nested_min_max;
 mod property_with_parameters;
+mod repeated_keyword_argument;
+mod too_many_arguments;
+mod too_many_branches;
+mod too_many_return_statements;
+mod too_many_statements;
 mod unnecessary_direct_lambda_call;
+mod unnecessary_dunder_call;
+mod unreachable_code;
 mod use_from_import;
 mod use_sys_exit;
 mod used_prior_global_declaration;
 mod useless_else_on_loop;
 mod useless_import_alias;
+mod useless_return;
+mod using_constant_test;
@@ -0,0 +1,85 @@
+use rustpython_ast::{Expr, ExprKind, Keyword};
+
+use crate::ast::helpers::{create_expr, unparse_expr};
+use crate::ast::types::Range;
+use crate::autofix::Fix;
+use crate::checkers::ast::Checker;
+use crate::registry::{Check, CheckCode};
+use crate::violations;
+
+fn min_max_name(expr: &Expr) -> Option<&str> {
+    let ExprKind::Call { func, keywords, .. } = &expr.node else {
+        return None;
+    };
+    if !keywords.is_empty() {
+        return None;
+    }
+    let ExprKind::Name { id, .. } = &func.node else {
+        return None;
+    };
+    if id == "min" || id == "max" {
+        Some(id.as_str())
+    } else {
+        None
+    }
+}
+
+/// Recursively flatten any nested call to the same `min`/`max` function into a
+/// single, flat argument list.
+fn flatten(name: &str, args: &[Expr]) -> Vec<Expr> {
+    let mut flattened = Vec::with_capacity(args.len());
+    for arg in args {
+        if min_max_name(arg) == Some(name) {
+            let ExprKind::Call { args, .. } = &arg.node else {
+                unreachable!()
+            };
+            flattened.extend(flatten(name, args));
+        } else {
+            flattened.push(arg.clone());
+        }
+    }
+    flattened
+}
+
+/// PLW3301
+pub fn nested_min_max(
+    checker: &mut Checker,
+    expr: &Expr,
+    func: &Expr,
+    args: &[Expr],
+    keywords: &[Keyword],
+) {
+    if !keywords.is_empty() {
+        return;
+    }
+    let ExprKind::Name { id: name, .. } = &func.node else {
+        return;
+    };
+    if name != "min" && name != "max" {
+        return;
+    }
+    if !checker.is_builtin(name) {
+        return;
+    }
+    if !args.iter().any(|arg| min_max_name(arg) == Some(name)) {
+        return;
+    }
+
+    let mut check = Check::new(
+        violations::NestedMinMax(name.to_string()),
+        Range::from_located(expr),
+    );
+    if checker.patch(&CheckCode::PLW3301) {
+        let flattened = create_expr(ExprKind::Call {
+            func: Box::new(func.clone()),
+            args: flatten(name, args),
+            keywords: keywords.to_vec(),
+        });
+        check.amend(Fix::replacement(
+            unparse_expr(&flattened, checker.style),
+            expr.location,
+            expr.end_location.unwrap(),
+        ));
+    }
+    checker.checks.push(check);
+}
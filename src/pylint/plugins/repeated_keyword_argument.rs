@@ -0,0 +1,22 @@
+use rustc_hash::FxHashSet;
+use rustpython_ast::Keyword;
+
+use crate::ast::types::Range;
+use crate::checkers::ast::Checker;
+use crate::{violations, Check};
+
+/// PLE1132
+pub fn repeated_keyword_argument(checker: &mut Checker, keywords: &[Keyword]) {
+    let mut seen: FxHashSet<&str> = FxHashSet::default();
+    for keyword in keywords {
+        let Some(arg) = &keyword.node.arg else {
+            continue;
+        };
+        if !seen.insert(arg.as_str()) {
+            checker.checks.push(Check::new(
+                violations::RepeatedKeywordArgument(arg.to_string()),
+                Range::from_located(keyword),
+            ));
+        }
+    }
+}
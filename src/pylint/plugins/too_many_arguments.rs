@@ -0,0 +1,30 @@
+use rustpython_ast::{Arguments, Stmt};
+
+use crate::ast::helpers::identifier_range;
+use crate::registry::Check;
+use crate::source_code_locator::SourceCodeLocator;
+use crate::violations;
+
+/// PLR0913
+pub fn too_many_arguments(
+    stmt: &Stmt,
+    args: &Arguments,
+    max_args: usize,
+    locator: &SourceCodeLocator,
+) -> Option<Check> {
+    let num_args = args
+        .args
+        .iter()
+        .chain(args.posonlyargs.iter())
+        .chain(args.kwonlyargs.iter())
+        .filter(|arg| arg.node.arg != "self" && arg.node.arg != "cls")
+        .count();
+    if num_args > max_args {
+        Some(Check::new(
+            violations::TooManyArguments(num_args, max_args),
+            identifier_range(stmt, locator),
+        ))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,68 @@
+use rustpython_ast::{Stmt, StmtKind};
+
+use crate::ast::helpers::identifier_range;
+use crate::registry::Check;
+use crate::source_code_locator::SourceCodeLocator;
+use crate::violations;
+
+fn num_branches(stmts: &[Stmt]) -> usize {
+    let mut count = 0;
+    for stmt in stmts {
+        match &stmt.node {
+            StmtKind::If { body, orelse, .. } => {
+                count += 1;
+                count += num_branches(body);
+                count += num_branches(orelse);
+            }
+            StmtKind::For { body, orelse, .. } | StmtKind::AsyncFor { body, orelse, .. } => {
+                count += 1;
+                count += num_branches(body);
+                count += num_branches(orelse);
+            }
+            StmtKind::While { body, orelse, .. } => {
+                count += 1;
+                count += num_branches(body);
+                count += num_branches(orelse);
+            }
+            StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => {
+                count += num_branches(body);
+            }
+            StmtKind::Try {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+            } => {
+                count += num_branches(body);
+                count += num_branches(orelse);
+                count += num_branches(finalbody);
+                for handler in handlers {
+                    count += 1;
+                    let rustpython_ast::ExcepthandlerKind::ExceptHandler { body, .. } =
+                        &handler.node;
+                    count += num_branches(body);
+                }
+            }
+            _ => {}
+        }
+    }
+    count
+}
+
+/// PLR0912
+pub fn too_many_branches(
+    stmt: &Stmt,
+    body: &[Stmt],
+    max_branches: usize,
+    locator: &SourceCodeLocator,
+) -> Option<Check> {
+    let branches = num_branches(body);
+    if branches > max_branches {
+        Some(Check::new(
+            violations::TooManyBranches(branches, max_branches),
+            identifier_range(stmt, locator),
+        ))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,65 @@
+use rustpython_ast::{Stmt, StmtKind};
+
+use crate::ast::helpers::identifier_range;
+use crate::registry::Check;
+use crate::source_code_locator::SourceCodeLocator;
+use crate::violations;
+
+fn num_returns(stmts: &[Stmt]) -> usize {
+    let mut count = 0;
+    for stmt in stmts {
+        match &stmt.node {
+            StmtKind::Return { .. } => count += 1,
+            StmtKind::If { body, orelse, .. } => {
+                count += num_returns(body);
+                count += num_returns(orelse);
+            }
+            StmtKind::For { body, orelse, .. } | StmtKind::AsyncFor { body, orelse, .. } => {
+                count += num_returns(body);
+                count += num_returns(orelse);
+            }
+            StmtKind::While { body, orelse, .. } => {
+                count += num_returns(body);
+                count += num_returns(orelse);
+            }
+            StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => {
+                count += num_returns(body);
+            }
+            StmtKind::Try {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+            } => {
+                count += num_returns(body);
+                count += num_returns(orelse);
+                count += num_returns(finalbody);
+                for handler in handlers {
+                    let rustpython_ast::ExcepthandlerKind::ExceptHandler { body, .. } =
+                        &handler.node;
+                    count += num_returns(body);
+                }
+            }
+            _ => {}
+        }
+    }
+    count
+}
+
+/// PLR0911
+pub fn too_many_return_statements(
+    stmt: &Stmt,
+    body: &[Stmt],
+    max_returns: usize,
+    locator: &SourceCodeLocator,
+) -> Option<Check> {
+    let returns = num_returns(body);
+    if returns > max_returns {
+        Some(Check::new(
+            violations::TooManyReturnStatements(returns, max_returns),
+            identifier_range(stmt, locator),
+        ))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,70 @@
+use rustpython_ast::{Stmt, StmtKind};
+
+use crate::ast::helpers::identifier_range;
+use crate::registry::Check;
+use crate::source_code_locator::SourceCodeLocator;
+use crate::violations;
+
+fn num_statements(stmts: &[Stmt]) -> usize {
+    let mut count = 0;
+    for stmt in stmts {
+        count += 1;
+        match &stmt.node {
+            StmtKind::If { body, orelse, .. } => {
+                count += num_statements(body);
+                count += num_statements(orelse);
+            }
+            StmtKind::For { body, orelse, .. } | StmtKind::AsyncFor { body, orelse, .. } => {
+                count += num_statements(body);
+                count += num_statements(orelse);
+            }
+            StmtKind::While { body, orelse, .. } => {
+                count += num_statements(body);
+                count += num_statements(orelse);
+            }
+            StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => {
+                count += num_statements(body);
+            }
+            StmtKind::Try {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+            } => {
+                count += num_statements(body);
+                count += num_statements(orelse);
+                count += num_statements(finalbody);
+                for handler in handlers {
+                    let rustpython_ast::ExcepthandlerKind::ExceptHandler { body, .. } =
+                        &handler.node;
+                    count += num_statements(body);
+                }
+            }
+            // Nested functions and classes are counted as a single statement of their own,
+            // but their bodies are not counted towards the enclosing function.
+            StmtKind::FunctionDef { .. }
+            | StmtKind::AsyncFunctionDef { .. }
+            | StmtKind::ClassDef { .. } => {}
+            _ => {}
+        }
+    }
+    count
+}
+
+/// PLR0915
+pub fn too_many_statements(
+    stmt: &Stmt,
+    body: &[Stmt],
+    max_statements: usize,
+    locator: &SourceCodeLocator,
+) -> Option<Check> {
+    let statements = num_statements(body);
+    if statements > max_statements {
+        Some(Check::new(
+            violations::TooManyStatements(statements, max_statements),
+            identifier_range(stmt, locator),
+        ))
+    } else {
+        None
+    }
+}
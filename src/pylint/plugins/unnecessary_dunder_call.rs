@@ -0,0 +1,71 @@
+use rustpython_ast::{Expr, ExprContext, ExprKind, Location};
+
+use crate::ast::types::Range;
+use crate::autofix::Fix;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::source_code_generator::SourceCodeGenerator;
+use crate::violations;
+
+/// Dunder methods with a builtin-function equivalent, e.g. `x.__len__()` => `len(x)`.
+const DUNDER_TO_BUILTIN: &[(&str, &str)] = &[
+    ("__len__", "len"),
+    ("__str__", "str"),
+    ("__repr__", "repr"),
+    ("__bool__", "bool"),
+    ("__hash__", "hash"),
+    ("__iter__", "iter"),
+    ("__next__", "next"),
+    ("__reversed__", "reversed"),
+    ("__abs__", "abs"),
+];
+
+fn call(func_name: &str, arg: &Expr) -> Expr {
+    Expr::new(
+        Location::default(),
+        Location::default(),
+        ExprKind::Call {
+            func: Box::new(Expr::new(
+                Location::default(),
+                Location::default(),
+                ExprKind::Name {
+                    id: func_name.to_string(),
+                    ctx: ExprContext::Load,
+                },
+            )),
+            args: vec![arg.clone()],
+            keywords: vec![],
+        },
+    )
+}
+
+/// PLC2801
+pub fn unnecessary_dunder_call(checker: &mut Checker, expr: &Expr, func: &Expr, args: &[Expr]) {
+    if !args.is_empty() {
+        return;
+    }
+    let ExprKind::Attribute { value, attr, .. } = &func.node else {
+        return;
+    };
+    let Some((_, builtin)) = DUNDER_TO_BUILTIN
+        .iter()
+        .find(|(dunder, _)| *dunder == attr.as_str())
+    else {
+        return;
+    };
+
+    let mut check = Check::new(
+        violations::UnnecessaryDunderCall(attr.to_string(), (*builtin).to_string()),
+        Range::from_located(expr),
+    );
+    if checker.patch(check.kind.code()) {
+        let mut generator: SourceCodeGenerator = checker.style.into();
+        generator.unparse_expr(&call(builtin, value), 0);
+        check.amend(Fix::replacement(
+            generator.generate(),
+            expr.location,
+            expr.end_location.unwrap(),
+        ));
+    }
+    checker.checks.push(check);
+}
@@ -0,0 +1,123 @@
+use rustpython_ast::{Constant, Expr, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+/// Return `true` if `expr` is the constant `False` or `None`. Numeric
+/// constants (e.g. `0`) are not matched.
+fn is_const_false(expr: &Expr) -> bool {
+    matches!(
+        &expr.node,
+        ExprKind::Constant {
+            value: Constant::Bool(false) | Constant::None,
+            ..
+        }
+    )
+}
+
+/// Return `true` if `expr` is the constant `True`. Numeric constants (e.g.
+/// a nonzero number) are not matched.
+fn is_const_true(expr: &Expr) -> bool {
+    matches!(
+        &expr.node,
+        ExprKind::Constant {
+            value: Constant::Bool(true),
+            ..
+        }
+    )
+}
+
+/// Return `true` if `stmt` unconditionally transfers control out of the
+/// current block.
+fn is_terminal(stmt: &Stmt) -> bool {
+    matches!(
+        &stmt.node,
+        StmtKind::Return { .. } | StmtKind::Raise { .. } | StmtKind::Continue | StmtKind::Break
+    )
+}
+
+/// Recurse into `stmts`, flagging the first statement of any block that
+/// follows a `return`/`raise`/`continue`/`break`, or that's guarded by a
+/// constant-false condition.
+fn find_unreachable(stmts: &[Stmt], checks: &mut Vec<Check>) {
+    for (index, stmt) in stmts.iter().enumerate() {
+        match &stmt.node {
+            StmtKind::If { test, body, orelse } => {
+                if is_const_false(test) {
+                    if let Some(first) = body.first() {
+                        checks.push(Check::new(
+                            violations::UnreachableCode,
+                            Range::from_located(first),
+                        ));
+                    }
+                    find_unreachable(orelse, checks);
+                } else if is_const_true(test) {
+                    if let Some(first) = orelse.first() {
+                        checks.push(Check::new(
+                            violations::UnreachableCode,
+                            Range::from_located(first),
+                        ));
+                    }
+                    find_unreachable(body, checks);
+                } else {
+                    find_unreachable(body, checks);
+                    find_unreachable(orelse, checks);
+                }
+            }
+            StmtKind::For { body, orelse, .. } | StmtKind::AsyncFor { body, orelse, .. } => {
+                find_unreachable(body, checks);
+                find_unreachable(orelse, checks);
+            }
+            StmtKind::While { test, body, orelse } => {
+                if is_const_false(test) {
+                    if let Some(first) = body.first() {
+                        checks.push(Check::new(
+                            violations::UnreachableCode,
+                            Range::from_located(first),
+                        ));
+                    }
+                } else {
+                    find_unreachable(body, checks);
+                }
+                find_unreachable(orelse, checks);
+            }
+            StmtKind::With { body, .. } | StmtKind::AsyncWith { body, .. } => {
+                find_unreachable(body, checks);
+            }
+            StmtKind::Try {
+                body,
+                handlers,
+                orelse,
+                finalbody,
+            } => {
+                find_unreachable(body, checks);
+                for handler in handlers {
+                    let rustpython_ast::ExcepthandlerKind::ExceptHandler { body, .. } =
+                        &handler.node;
+                    find_unreachable(body, checks);
+                }
+                find_unreachable(orelse, checks);
+                find_unreachable(finalbody, checks);
+            }
+            _ => {}
+        }
+
+        if is_terminal(stmt) {
+            if let Some(first_unreachable) = stmts.get(index + 1) {
+                checks.push(Check::new(
+                    violations::UnreachableCode,
+                    Range::from_located(first_unreachable),
+                ));
+            }
+            break;
+        }
+    }
+}
+
+/// PLW0101
+pub fn unreachable_code(body: &[Stmt]) -> Vec<Check> {
+    let mut checks = Vec::new();
+    find_unreachable(body, &mut checks);
+    checks
+}
@@ -1,5 +1,6 @@
 use rustpython_ast::{Expr, ExprKind};
 
+use crate::ast::helpers::insert_import;
 use crate::ast::types::{BindingKind, Range};
 use crate::autofix::Fix;
 use crate::checkers::ast::Checker;
@@ -86,6 +87,19 @@ pub fn use_sys_exit(checker: &mut Checker, func: &Expr) {
                     func.location,
                     func.end_location.unwrap(),
                 ));
+            } else {
+                // `sys` isn't imported yet, so insert `import sys` above the call and
+                // rewrite the call in the same edit (a `Check` can only carry a single,
+                // contiguous `Fix`).
+                let (import_location, import_text) = insert_import("sys", checker.python_ast);
+                let prefix = checker
+                    .locator
+                    .slice_source_code_range(&Range::new(import_location, func.location));
+                check.amend(Fix::replacement(
+                    format!("{import_text}{prefix}sys.exit"),
+                    import_location,
+                    func.end_location.unwrap(),
+                ));
             }
         }
         checker.checks.push(check);
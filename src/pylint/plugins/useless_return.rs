@@ -0,0 +1,39 @@
+use rustpython_ast::{Constant, Expr, ExprKind, Stmt, StmtKind};
+
+use crate::ast::types::Range;
+use crate::autofix::Fix;
+use crate::checkers::ast::Checker;
+use crate::registry::CheckCode;
+use crate::{violations, Check};
+
+fn is_none_return(value: &Option<Box<Expr>>) -> bool {
+    match value {
+        None => true,
+        Some(expr) => matches!(
+            &expr.node,
+            ExprKind::Constant {
+                value: Constant::None,
+                ..
+            }
+        ),
+    }
+}
+
+/// PLR1711
+pub fn useless_return(checker: &mut Checker, body: &[Stmt]) {
+    let Some((last, rest)) = body.split_last() else {
+        return;
+    };
+    let StmtKind::Return { value } = &last.node else {
+        return;
+    };
+    if !is_none_return(value) {
+        return;
+    }
+
+    let mut check = Check::new(violations::UselessReturn, Range::from_located(last));
+    if !rest.is_empty() && checker.patch(&CheckCode::PLR1711) {
+        check.amend(Fix::deletion(last.location, last.end_location.unwrap()));
+    }
+    checker.checks.push(check);
+}
@@ -0,0 +1,34 @@
+use rustpython_ast::{Constant, Expr, ExprKind};
+
+use crate::ast::types::Range;
+use crate::registry::Check;
+use crate::violations;
+
+/// Return `true` if `test` is a literal whose truthiness is always the same,
+/// regardless of any names or expressions it might otherwise appear to
+/// depend on (e.g. a non-empty string literal, or a list/dict/set display).
+///
+/// `True`, `False`, `None`, and `...` are deliberately excluded, since a
+/// literal boolean (or `None`, or an ellipsis placeholder) is almost always
+/// an intentional, readable way to write an always-taken or never-taken
+/// branch, rather than a bug.
+fn is_constant_test(test: &Expr) -> bool {
+    match &test.node {
+        ExprKind::Constant { value, .. } => {
+            matches!(value, Constant::Str(_) | Constant::Bytes(_))
+        }
+        ExprKind::List { .. } | ExprKind::Dict { .. } | ExprKind::Set { .. } => true,
+        _ => false,
+    }
+}
+
+/// PLW0125
+pub fn using_constant_test(test: &Expr) -> Option<Check> {
+    if is_constant_test(test) {
+        return Some(Check::new(
+            violations::UsingConstantTest,
+            Range::from_located(test),
+        ));
+    }
+    None
+}
@@ -0,0 +1,110 @@
+//! Settings for the `pylint` plugin.
+
+use ruff_macros::ConfigurationOptions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, PartialEq, Eq, Serialize, Deserialize, Default, ConfigurationOptions, JsonSchema,
+)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case", rename = "PylintOptions")]
+pub struct Options {
+    #[option(
+        default = "6",
+        value_type = "usize",
+        example = "max-returns = 10"
+    )]
+    /// Maximum number of return statements allowed for a function or method body (see:
+    /// `PLR0911`).
+    pub max_returns: Option<usize>,
+    #[option(
+        default = "12",
+        value_type = "usize",
+        example = "max-branches = 20"
+    )]
+    /// Maximum number of branches allowed for a function or method body (see: `PLR0912`).
+    pub max_branches: Option<usize>,
+    #[option(
+        default = "5",
+        value_type = "usize",
+        example = "max-args = 10"
+    )]
+    /// Maximum number of arguments allowed for a function or method definition (see:
+    /// `PLR0913`).
+    pub max_args: Option<usize>,
+    #[option(
+        default = "50",
+        value_type = "usize",
+        example = "max-statements = 100"
+    )]
+    /// Maximum number of statements allowed for a function or method body (see:
+    /// `PLR0915`).
+    pub max_statements: Option<usize>,
+    #[option(
+        default = r#"["0", "1", "-1", "\"\""]"#,
+        value_type = "Vec<String>",
+        example = r#"
+            # Allow the magic values `0`, `1`, `2`, `-1`, and `""` in comparisons.
+            allowed-magic-values = ["0", "1", "2", "-1", "\"\""]
+        "#
+    )]
+    /// Values that are permitted to appear as a bare literal in a comparison, without
+    /// triggering `PLR2004`.
+    pub allowed_magic_values: Option<Vec<String>>,
+}
+
+fn default_allowed_magic_values() -> Vec<String> {
+    vec![
+        "0".to_string(),
+        "1".to_string(),
+        "-1".to_string(),
+        "\"\"".to_string(),
+    ]
+}
+
+#[derive(Debug, Hash)]
+pub struct Settings {
+    pub max_returns: usize,
+    pub max_branches: usize,
+    pub max_args: usize,
+    pub max_statements: usize,
+    pub allowed_magic_values: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_returns: 6,
+            max_branches: 12,
+            max_args: 5,
+            max_statements: 50,
+            allowed_magic_values: default_allowed_magic_values(),
+        }
+    }
+}
+
+impl From<Options> for Settings {
+    fn from(options: Options) -> Self {
+        Self {
+            max_returns: options.max_returns.unwrap_or(6),
+            max_branches: options.max_branches.unwrap_or(12),
+            max_args: options.max_args.unwrap_or(5),
+            max_statements: options.max_statements.unwrap_or(50),
+            allowed_magic_values: options
+                .allowed_magic_values
+                .unwrap_or_else(default_allowed_magic_values),
+        }
+    }
+}
+
+impl From<Settings> for Options {
+    fn from(settings: Settings) -> Self {
+        Self {
+            max_returns: Some(settings.max_returns),
+            max_branches: Some(settings.max_branches),
+            max_args: Some(settings.max_args),
+            max_statements: Some(settings.max_statements),
+            allowed_magic_values: Some(settings.allowed_magic_values),
+        }
+    }
+}
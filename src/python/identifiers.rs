@@ -3,3 +3,8 @@ use regex::Regex;
 
 pub static IDENTIFIER_REGEX: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^[A-Za-z_][A-Za-z0-9_]*$").unwrap());
+
+/// Returns `true` if `name` is a dunder, e.g. `__init__`.
+pub fn is_dunder(name: &str) -> bool {
+    name.starts_with("__") && name.ends_with("__")
+}
@@ -4,7 +4,7 @@ use std::fmt;
 
 use once_cell::sync::Lazy;
 use ruff_macros::CheckCodePrefix;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use rustpython_ast::Cmpop;
 use rustpython_parser::ast::Location;
 use serde::{Deserialize, Serialize};
@@ -112,6 +112,16 @@ macro_rules! define_rule_mapping {
 
 define_rule_mapping!(
     // pycodestyle errors
+    E201 => violations::WhitespaceAfterOpenBracket,
+    E202 => violations::WhitespaceBeforeCloseBracket,
+    E211 => violations::WhitespaceBeforeParameters,
+    E231 => violations::MissingWhitespaceAfterComma,
+    E301 => violations::BlankLineBetweenMethods,
+    E302 => violations::BlankLinesTopLevel,
+    E303 => violations::TooManyBlankLines,
+    E306 => violations::BlankLineBeforeNestedDefinition,
+    E111 => violations::IndentationWithInvalidMultiple,
+    E117 => violations::OverIndented,
     E401 => violations::MultipleImportsOnOneLine,
     E402 => violations::ModuleImportNotAtTopOfFile,
     E501 => violations::LineTooLong,
@@ -128,7 +138,11 @@ define_rule_mapping!(
     E902 => violations::IOError,
     E999 => violations::SyntaxError,
     // pycodestyle warnings
+    W291 => violations::TrailingWhitespace,
     W292 => violations::NoNewLineAtEndOfFile,
+    W293 => violations::WhitespaceOnBlankLine,
+    W503 => violations::LineBreakBeforeBinaryOperator,
+    W504 => violations::LineBreakAfterBinaryOperator,
     W605 => violations::InvalidEscapeSequence,
     // pyflakes
     F401 => violations::UnusedImport,
@@ -177,16 +191,36 @@ define_rule_mapping!(
     // pylint
     PLC0414 => violations::UselessImportAlias,
     PLC2201 => violations::MisplacedComparisonConstant,
+    PLC2701 => violations::ImportPrivateName,
+    PLC2801 => violations::UnnecessaryDunderCall,
     PLC3002 => violations::UnnecessaryDirectLambdaCall,
+    PLE0101 => violations::ReturnInInit,
     PLE0117 => violations::NonlocalWithoutBinding,
     PLE0118 => violations::UsedPriorGlobalDeclaration,
+    PLE0241 => violations::DuplicateBases,
+    PLE0302 => violations::BadDunderMethodSignature,
+    PLE1132 => violations::RepeatedKeywordArgument,
     PLE1142 => violations::AwaitOutsideAsync,
     PLR0206 => violations::PropertyWithParameters,
+    PLR0124 => violations::ComparisonWithItself,
+    PLR0133 => violations::ComparisonOfConstant,
     PLR0402 => violations::ConsiderUsingFromImport,
+    PLR0911 => violations::TooManyReturnStatements,
+    PLR0912 => violations::TooManyBranches,
+    PLR0913 => violations::TooManyArguments,
+    PLR0915 => violations::TooManyStatements,
+    PLR2004 => violations::MagicValueComparison,
     PLR1701 => violations::ConsiderMergingIsinstance,
+    PLR1711 => violations::UselessReturn,
     PLR1722 => violations::UseSysExit,
+    PLR5501 => violations::CollapsibleElseIf,
+    PLW0101 => violations::UnreachableCode,
     PLW0120 => violations::UselessElseOnLoop,
+    PLW0125 => violations::UsingConstantTest,
+    PLW0406 => violations::ImportSelf,
+    PLW3301 => violations::NestedMinMax,
     PLW0602 => violations::GlobalVariableNotAssigned,
+    PLW2901 => violations::RedefinedLoopName,
     // flake8-builtins
     A001 => violations::BuiltinVariableShadowing,
     A002 => violations::BuiltinArgumentShadowing,
@@ -220,6 +254,11 @@ define_rule_mapping!(
     B027 => violations::EmptyMethodWithoutAbstractDecorator,
     B904 => violations::RaiseWithoutFromInsideExcept,
     B905 => violations::ZipWithoutExplicitStrict,
+    B028 => violations::NoExplicitStacklevel,
+    B029 => violations::StarImportShadowsExisting,
+    B030 => violations::ExceptWithNonExceptionClasses,
+    B031 => violations::ReuseOfGroupbyGenerator,
+    B032 => violations::DictComprehensionWithStaticKey,
     // flake8-blind-except
     BLE001 => violations::BlindExcept,
     // flake8-comprehensions
@@ -239,6 +278,8 @@ define_rule_mapping!(
     C415 => violations::UnnecessarySubscriptReversal,
     C416 => violations::UnnecessaryComprehension,
     C417 => violations::UnnecessaryMap,
+    C418 => violations::UnnecessaryDictPassedToDict,
+    C419 => violations::UnnecessaryComprehensionAnyAll,
     // flake8-debugger
     T100 => violations::Debugger,
     // mccabe
@@ -300,6 +341,8 @@ define_rule_mapping!(
     SIM109 => violations::CompareWithTuple,
     SIM110 => violations::ConvertLoopToAny,
     SIM111 => violations::ConvertLoopToAll,
+    SIM112 => violations::UseCapitalEnvironmentVariables,
+    SIM115 => violations::UseContextManagerForOpen,
     SIM117 => violations::MultipleWithStatements,
     SIM118 => violations::KeyInDict,
     SIM201 => violations::NegateEqualOp,
@@ -313,6 +356,7 @@ define_rule_mapping!(
     SIM222 => violations::OrTrue,
     SIM223 => violations::AndFalse,
     SIM300 => violations::YodaConditions,
+    SIM401 => violations::UseDictGetWithDefault,
     // pyupgrade
     UP001 => violations::UselessMetaclassType,
     UP003 => violations::TypeOfPrimitive,
@@ -417,7 +461,23 @@ define_rule_mapping!(
     S106 => violations::HardcodedPasswordFuncArg,
     S107 => violations::HardcodedPasswordDefault,
     S108 => violations::HardcodedTempFile,
+    S110 => violations::TryExceptPass,
+    S112 => violations::TryExceptContinue,
     S113 => violations::RequestWithoutTimeout,
+    S301 => violations::SuspiciousPickleUsage,
+    S302 => violations::SuspiciousMarshalUsage,
+    S303 => violations::InsecureCipherUsage,
+    S304 => violations::InsecureCipherModeUsage,
+    S305 => violations::InsecureSSLProtocolUsage,
+    S311 => violations::SuspiciousNonCryptographicRandomUsage,
+    S608 => violations::HardcodedSQLExpression,
+    S602 => violations::SubprocessPopenWithShellEqualsTrue,
+    S603 => violations::SubprocessWithoutShellEqualsTrue,
+    S604 => violations::CallWithShellEqualsTrue,
+    S605 => violations::StartProcessWithAShell,
+    S606 => violations::StartProcessWithNoShell,
+    S607 => violations::StartProcessWithPartialPath,
+    S609 => violations::UnixCommandWildcardInjection,
     S324 => violations::HashlibInsecureHashFunction,
     S501 => violations::RequestWithNoCertValidation,
     S506 => violations::UnsafeYAMLLoad,
@@ -443,11 +503,14 @@ define_rule_mapping!(
     DTZ007 => violations::CallDatetimeStrptimeWithoutZone,
     DTZ011 => violations::CallDateToday,
     DTZ012 => violations::CallDateFromtimestamp,
+    DTZ013 => violations::CallDatetimeTimeWithoutTzinfo,
+    DTZ014 => violations::CallDatetimeAstimezoneOnNaiveDatetime,
     // pygrep-hooks
     PGH001 => violations::NoEval,
     PGH002 => violations::DeprecatedLogWarn,
     PGH003 => violations::BlanketTypeIgnore,
     PGH004 => violations::BlanketNOQA,
+    PGH005 => violations::InvalidMockAccess,
     // pandas-vet
     PD002 => violations::UseOfInplaceArgument,
     PD003 => violations::UseOfDotIsNull,
@@ -460,6 +523,7 @@ define_rule_mapping!(
     PD012 => violations::UseOfDotReadTable,
     PD013 => violations::UseOfDotStack,
     PD015 => violations::UseOfPdMerge,
+    PD101 => violations::UseOfLenAndUnique,
     PD901 => violations::DfIsABadVariableName,
     // flake8-errmsg
     EM101 => violations::RawStringInException,
@@ -500,7 +564,34 @@ define_rule_mapping!(
     RUF002 => violations::AmbiguousUnicodeCharacterDocstring,
     RUF003 => violations::AmbiguousUnicodeCharacterComment,
     RUF004 => violations::KeywordArgumentBeforeStarArgument,
+    RUF005 => violations::ImplicitStringConcatenationInCollection,
+    RUF006 => violations::CollectionLiteralConcatenation,
+    RUF022 => violations::UnsortedDunderAll,
+    RUF023 => violations::AssertMessageSideEffect,
+    RUF008 => violations::MutableDataclassDefault,
+    RUF012 => violations::MutableClassDefault,
+    RUF013 => violations::MixedLineEndings,
     RUF100 => violations::UnusedNOQA,
+    RUF101 => violations::MalformedNOQA,
+    RUF102 => violations::MisplacedNOQA,
+    // flake8-pyi
+    PYI001 => violations::DocstringInStub,
+    PYI002 => violations::NonEmptyStubBody,
+    // numpy
+    NPY001 => violations::DeprecatedTypeAlias,
+    NPY002 => violations::LegacyNumpyRandom,
+    // perflint
+    PERF101 => violations::UnnecessaryListCast,
+    PERF102 => violations::TryExceptInLoop,
+    // refurb
+    FURB105 => violations::FloatInfLiteral,
+    FURB110 => violations::IfElseDictGet,
+    // darglint
+    DAR201 => violations::UndocumentedReturn,
+    DAR301 => violations::UndocumentedYield,
+    DAR401 => violations::UndocumentedException,
+    // flake8-doctests
+    DOC001 => violations::SyntaxErrorInDoctest,
 );
 
 #[derive(EnumIter, Debug, PartialEq, Eq)]
@@ -537,7 +628,13 @@ pub enum CheckCategory {
     PygrepHooks,
     Pylint,
     Flake8Pie,
+    Flake8Pyi,
+    NumPy,
+    Perflint,
+    Refurb,
     Ruff,
+    Darglint,
+    Flake8Doctests,
 }
 
 pub enum Platform {
@@ -589,7 +686,13 @@ impl CheckCategory {
             CheckCategory::Pylint => "Pylint",
             CheckCategory::Pyupgrade => "pyupgrade",
             CheckCategory::Flake8Pie => "flake8-pie",
+            CheckCategory::Flake8Pyi => "flake8-pyi",
+            CheckCategory::NumPy => "NumPy-specific rules",
+            CheckCategory::Perflint => "perflint",
+            CheckCategory::Refurb => "refurb",
             CheckCategory::Ruff => "Ruff-specific rules",
+            CheckCategory::Darglint => "darglint",
+            CheckCategory::Flake8Doctests => "flake8-doctests",
         }
     }
 
@@ -632,7 +735,13 @@ impl CheckCategory {
             ],
             CheckCategory::Pyupgrade => vec![CheckCodePrefix::UP],
             CheckCategory::Flake8Pie => vec![CheckCodePrefix::PIE],
+            CheckCategory::Flake8Pyi => vec![CheckCodePrefix::PYI],
+            CheckCategory::NumPy => vec![CheckCodePrefix::NPY],
+            CheckCategory::Perflint => vec![CheckCodePrefix::PERF],
+            CheckCategory::Refurb => vec![CheckCodePrefix::FURB],
             CheckCategory::Ruff => vec![CheckCodePrefix::RUF],
+            CheckCategory::Darglint => vec![CheckCodePrefix::DAR],
+            CheckCategory::Flake8Doctests => vec![CheckCodePrefix::DOC],
         }
     }
 
@@ -757,11 +866,59 @@ impl CheckCategory {
                 "https://pypi.org/project/flake8-pie/0.16.0/",
                 &Platform::PyPI,
             )),
+            CheckCategory::Flake8Pyi => Some((
+                "https://pypi.org/project/flake8-pyi/22.11.0/",
+                &Platform::PyPI,
+            )),
+            CheckCategory::NumPy => None,
+            CheckCategory::Perflint => Some((
+                "https://pypi.org/project/perflint/0.7.3/",
+                &Platform::PyPI,
+            )),
+            CheckCategory::Refurb => Some((
+                "https://pypi.org/project/refurb/1.9.1/",
+                &Platform::PyPI,
+            )),
             CheckCategory::Ruff => None,
+            CheckCategory::Darglint => Some((
+                "https://pypi.org/project/darglint/1.8.1/",
+                &Platform::PyPI,
+            )),
+            // Ruff-original: there's no upstream flake8 plugin that lints
+            // doctest examples embedded in docstrings.
+            CheckCategory::Flake8Doctests => None,
         }
     }
 }
 
+impl TryFrom<String> for CheckCodePrefix {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        use std::str::FromStr;
+
+        use strum::IntoEnumIterator;
+
+        CheckCodePrefix::from_str(&value).map_err(|_| {
+            let suggestion = CheckCodePrefix::iter().max_by(|a, b| {
+                let a_similarity = strsim::jaro_winkler(&value, a.as_ref());
+                let b_similarity = strsim::jaro_winkler(&value, b.as_ref());
+                a_similarity
+                    .partial_cmp(&b_similarity)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            match suggestion {
+                Some(suggestion) => format!(
+                    "`{value}` is not a valid check code or code prefix (did you mean `{}`?)",
+                    suggestion.as_ref()
+                ),
+                None => format!("`{value}` is not a valid check code or code prefix"),
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum LintSource {
     AST,
@@ -869,19 +1026,31 @@ impl CheckCode {
     /// physical lines).
     pub fn lint_source(&self) -> &'static LintSource {
         match self {
-            CheckCode::RUF100 => &LintSource::NoQA,
+            CheckCode::RUF100 | CheckCode::RUF101 | CheckCode::RUF102 => &LintSource::NoQA,
             CheckCode::E501
+            | CheckCode::W291
             | CheckCode::W292
+            | CheckCode::W293
             | CheckCode::UP009
             | CheckCode::PGH003
-            | CheckCode::PGH004 => &LintSource::Lines,
-            CheckCode::ERA001
+            | CheckCode::PGH004
+            | CheckCode::PGH005
+            | CheckCode::RUF013 => &LintSource::Lines,
+            CheckCode::E201
+            | CheckCode::E202
+            | CheckCode::E211
+            | CheckCode::E231
+            | CheckCode::E111
+            | CheckCode::E117
+            | CheckCode::ERA001
             | CheckCode::ISC001
             | CheckCode::ISC002
             | CheckCode::Q000
             | CheckCode::Q001
             | CheckCode::Q002
             | CheckCode::Q003
+            | CheckCode::W503
+            | CheckCode::W504
             | CheckCode::W605
             | CheckCode::RUF001
             | CheckCode::RUF002
@@ -946,6 +1115,11 @@ impl CheckCode {
             CheckCode::B027 => CheckCategory::Flake8Bugbear,
             CheckCode::B904 => CheckCategory::Flake8Bugbear,
             CheckCode::B905 => CheckCategory::Flake8Bugbear,
+            CheckCode::B028 => CheckCategory::Flake8Bugbear,
+            CheckCode::B029 => CheckCategory::Flake8Bugbear,
+            CheckCode::B030 => CheckCategory::Flake8Bugbear,
+            CheckCode::B031 => CheckCategory::Flake8Bugbear,
+            CheckCode::B032 => CheckCategory::Flake8Bugbear,
             // flake8-blind-except
             CheckCode::BLE001 => CheckCategory::Flake8BlindExcept,
             // flake8-comprehensions
@@ -965,6 +1139,8 @@ impl CheckCode {
             CheckCode::C415 => CheckCategory::Flake8Comprehensions,
             CheckCode::C416 => CheckCategory::Flake8Comprehensions,
             CheckCode::C417 => CheckCategory::Flake8Comprehensions,
+            CheckCode::C418 => CheckCategory::Flake8Comprehensions,
+            CheckCode::C419 => CheckCategory::Flake8Comprehensions,
             // mccabe
             CheckCode::C901 => CheckCategory::McCabe,
             // pydocstyle
@@ -1023,7 +1199,19 @@ impl CheckCode {
             CheckCode::DTZ007 => CheckCategory::Flake8Datetimez,
             CheckCode::DTZ011 => CheckCategory::Flake8Datetimez,
             CheckCode::DTZ012 => CheckCategory::Flake8Datetimez,
+            CheckCode::DTZ013 => CheckCategory::Flake8Datetimez,
+            CheckCode::DTZ014 => CheckCategory::Flake8Datetimez,
             // pycodestyle (errors)
+            CheckCode::E201 => CheckCategory::Pycodestyle,
+            CheckCode::E202 => CheckCategory::Pycodestyle,
+            CheckCode::E211 => CheckCategory::Pycodestyle,
+            CheckCode::E231 => CheckCategory::Pycodestyle,
+            CheckCode::E301 => CheckCategory::Pycodestyle,
+            CheckCode::E302 => CheckCategory::Pycodestyle,
+            CheckCode::E303 => CheckCategory::Pycodestyle,
+            CheckCode::E306 => CheckCategory::Pycodestyle,
+            CheckCode::E111 => CheckCategory::Pycodestyle,
+            CheckCode::E117 => CheckCategory::Pycodestyle,
             CheckCode::E401 => CheckCategory::Pycodestyle,
             CheckCode::E402 => CheckCategory::Pycodestyle,
             CheckCode::E501 => CheckCategory::Pycodestyle,
@@ -1129,25 +1317,47 @@ impl CheckCode {
             CheckCode::PD012 => CheckCategory::PandasVet,
             CheckCode::PD013 => CheckCategory::PandasVet,
             CheckCode::PD015 => CheckCategory::PandasVet,
+            CheckCode::PD101 => CheckCategory::PandasVet,
             CheckCode::PD901 => CheckCategory::PandasVet,
             // pygrep-hooks
             CheckCode::PGH001 => CheckCategory::PygrepHooks,
             CheckCode::PGH002 => CheckCategory::PygrepHooks,
             CheckCode::PGH003 => CheckCategory::PygrepHooks,
             CheckCode::PGH004 => CheckCategory::PygrepHooks,
+            CheckCode::PGH005 => CheckCategory::PygrepHooks,
             // pylint
             CheckCode::PLC0414 => CheckCategory::Pylint,
             CheckCode::PLC2201 => CheckCategory::Pylint,
+            CheckCode::PLC2701 => CheckCategory::Pylint,
+            CheckCode::PLC2801 => CheckCategory::Pylint,
             CheckCode::PLC3002 => CheckCategory::Pylint,
+            CheckCode::PLE0101 => CheckCategory::Pylint,
             CheckCode::PLE0117 => CheckCategory::Pylint,
             CheckCode::PLE0118 => CheckCategory::Pylint,
+            CheckCode::PLE0241 => CheckCategory::Pylint,
+            CheckCode::PLE0302 => CheckCategory::Pylint,
+            CheckCode::PLE1132 => CheckCategory::Pylint,
             CheckCode::PLE1142 => CheckCategory::Pylint,
             CheckCode::PLR0206 => CheckCategory::Pylint,
+            CheckCode::PLR0124 => CheckCategory::Pylint,
+            CheckCode::PLR0133 => CheckCategory::Pylint,
             CheckCode::PLR0402 => CheckCategory::Pylint,
+            CheckCode::PLR0911 => CheckCategory::Pylint,
+            CheckCode::PLR0912 => CheckCategory::Pylint,
+            CheckCode::PLR0913 => CheckCategory::Pylint,
+            CheckCode::PLR0915 => CheckCategory::Pylint,
+            CheckCode::PLR2004 => CheckCategory::Pylint,
             CheckCode::PLR1701 => CheckCategory::Pylint,
+            CheckCode::PLR1711 => CheckCategory::Pylint,
             CheckCode::PLR1722 => CheckCategory::Pylint,
+            CheckCode::PLR5501 => CheckCategory::Pylint,
+            CheckCode::PLW0101 => CheckCategory::Pylint,
             CheckCode::PLW0120 => CheckCategory::Pylint,
+            CheckCode::PLW0125 => CheckCategory::Pylint,
+            CheckCode::PLW0406 => CheckCategory::Pylint,
+            CheckCode::PLW3301 => CheckCategory::Pylint,
             CheckCode::PLW0602 => CheckCategory::Pylint,
+            CheckCode::PLW2901 => CheckCategory::Pylint,
             // flake8-pytest-style
             CheckCode::PT001 => CheckCategory::Flake8PytestStyle,
             CheckCode::PT002 => CheckCategory::Flake8PytestStyle,
@@ -1197,7 +1407,23 @@ impl CheckCode {
             CheckCode::S106 => CheckCategory::Flake8Bandit,
             CheckCode::S107 => CheckCategory::Flake8Bandit,
             CheckCode::S108 => CheckCategory::Flake8Bandit,
+            CheckCode::S110 => CheckCategory::Flake8Bandit,
+            CheckCode::S112 => CheckCategory::Flake8Bandit,
             CheckCode::S113 => CheckCategory::Flake8Bandit,
+            CheckCode::S301 => CheckCategory::Flake8Bandit,
+            CheckCode::S302 => CheckCategory::Flake8Bandit,
+            CheckCode::S303 => CheckCategory::Flake8Bandit,
+            CheckCode::S304 => CheckCategory::Flake8Bandit,
+            CheckCode::S305 => CheckCategory::Flake8Bandit,
+            CheckCode::S311 => CheckCategory::Flake8Bandit,
+            CheckCode::S608 => CheckCategory::Flake8Bandit,
+            CheckCode::S602 => CheckCategory::Flake8Bandit,
+            CheckCode::S603 => CheckCategory::Flake8Bandit,
+            CheckCode::S604 => CheckCategory::Flake8Bandit,
+            CheckCode::S605 => CheckCategory::Flake8Bandit,
+            CheckCode::S606 => CheckCategory::Flake8Bandit,
+            CheckCode::S607 => CheckCategory::Flake8Bandit,
+            CheckCode::S609 => CheckCategory::Flake8Bandit,
             CheckCode::S324 => CheckCategory::Flake8Bandit,
             CheckCode::S501 => CheckCategory::Flake8Bandit,
             CheckCode::S506 => CheckCategory::Flake8Bandit,
@@ -1211,6 +1437,8 @@ impl CheckCode {
             CheckCode::SIM109 => CheckCategory::Flake8Simplify,
             CheckCode::SIM110 => CheckCategory::Flake8Simplify,
             CheckCode::SIM111 => CheckCategory::Flake8Simplify,
+            CheckCode::SIM112 => CheckCategory::Flake8Simplify,
+            CheckCode::SIM115 => CheckCategory::Flake8Simplify,
             CheckCode::SIM117 => CheckCategory::Flake8Simplify,
             CheckCode::SIM118 => CheckCategory::Flake8Simplify,
             CheckCode::SIM201 => CheckCategory::Flake8Simplify,
@@ -1224,6 +1452,7 @@ impl CheckCode {
             CheckCode::SIM222 => CheckCategory::Flake8Simplify,
             CheckCode::SIM223 => CheckCategory::Flake8Simplify,
             CheckCode::SIM300 => CheckCategory::Flake8Simplify,
+            CheckCode::SIM401 => CheckCategory::Flake8Simplify,
             // flake8-debugger
             CheckCode::T100 => CheckCategory::Flake8Debugger,
             // flake8-print
@@ -1262,7 +1491,11 @@ impl CheckCode {
             CheckCode::UP028 => CheckCategory::Pyupgrade,
             CheckCode::UP029 => CheckCategory::Pyupgrade,
             // pycodestyle (warnings)
+            CheckCode::W291 => CheckCategory::Pycodestyle,
             CheckCode::W292 => CheckCategory::Pycodestyle,
+            CheckCode::W293 => CheckCategory::Pycodestyle,
+            CheckCode::W503 => CheckCategory::Pycodestyle,
+            CheckCode::W504 => CheckCategory::Pycodestyle,
             CheckCode::W605 => CheckCategory::Pycodestyle,
             // flake8-2020
             CheckCode::YTT101 => CheckCategory::Flake82020,
@@ -1284,7 +1517,34 @@ impl CheckCode {
             CheckCode::RUF002 => CheckCategory::Ruff,
             CheckCode::RUF003 => CheckCategory::Ruff,
             CheckCode::RUF004 => CheckCategory::Ruff,
+            CheckCode::RUF005 => CheckCategory::Ruff,
+            CheckCode::RUF006 => CheckCategory::Ruff,
+            CheckCode::RUF022 => CheckCategory::Ruff,
+            CheckCode::RUF023 => CheckCategory::Ruff,
+            CheckCode::RUF008 => CheckCategory::Ruff,
+            CheckCode::RUF012 => CheckCategory::Ruff,
+            CheckCode::RUF013 => CheckCategory::Ruff,
             CheckCode::RUF100 => CheckCategory::Ruff,
+            CheckCode::RUF101 => CheckCategory::Ruff,
+            CheckCode::RUF102 => CheckCategory::Ruff,
+            // flake8-pyi
+            CheckCode::PYI001 => CheckCategory::Flake8Pyi,
+            CheckCode::PYI002 => CheckCategory::Flake8Pyi,
+            // numpy
+            CheckCode::NPY001 => CheckCategory::NumPy,
+            CheckCode::NPY002 => CheckCategory::NumPy,
+            // perflint
+            CheckCode::PERF101 => CheckCategory::Perflint,
+            CheckCode::PERF102 => CheckCategory::Perflint,
+            // refurb
+            CheckCode::FURB105 => CheckCategory::Refurb,
+            CheckCode::FURB110 => CheckCategory::Refurb,
+            // darglint
+            CheckCode::DAR201 => CheckCategory::Darglint,
+            CheckCode::DAR301 => CheckCategory::Darglint,
+            CheckCode::DAR401 => CheckCategory::Darglint,
+            // flake8-doctests
+            CheckCode::DOC001 => CheckCategory::Flake8Doctests,
         }
     }
 }
@@ -1335,6 +1595,9 @@ pub struct Check {
     pub end_location: Location,
     pub fix: Option<Fix>,
     pub parent: Option<Location>,
+    /// Whether this check would normally have been suppressed by a `noqa`
+    /// directive, but is being surfaced anyway (e.g., via `--ignore-noqa`).
+    pub is_suppressed: bool,
 }
 
 impl Check {
@@ -1345,6 +1608,7 @@ impl Check {
             end_location: range.end_location,
             fix: None,
             parent: None,
+            is_suppressed: false,
         }
     }
 
@@ -1360,12 +1624,36 @@ impl Check {
 }
 
 /// Pairs of checks that shouldn't be enabled together.
-pub const INCOMPATIBLE_CODES: &[(CheckCode, CheckCode, &str)] = &[(
-    CheckCode::D203,
-    CheckCode::D211,
-    "`D203` (OneBlankLineBeforeClass) and `D211` (NoBlankLinesBeforeClass) are incompatible. \
-     Consider adding `D203` to `ignore`.",
-)];
+pub const INCOMPATIBLE_CODES: &[(CheckCode, CheckCode, &str)] = &[
+    (
+        CheckCode::D203,
+        CheckCode::D211,
+        "`D203` (OneBlankLineBeforeClass) and `D211` (NoBlankLinesBeforeClass) are incompatible. \
+         Consider adding `D203` to `ignore`.",
+    ),
+    (
+        CheckCode::W503,
+        CheckCode::W504,
+        "`W503` (LineBreakBeforeBinaryOperator) and `W504` (LineBreakAfterBinaryOperator) \
+         enforce opposite styles and are both disabled by default. Select only the one that \
+         matches your team's preference.",
+    ),
+];
+
+/// Checks that are only enabled when preview mode is turned on. New checks
+/// can be added here to ship disabled-by-default, so that opting into a
+/// newer version of Ruff doesn't silently change which checks run in CI;
+/// users opt in explicitly via the `preview` setting. Empty for now, since
+/// no currently-shipped check has been marked as unstable.
+pub static PREVIEW_CODES: Lazy<FxHashSet<CheckCode>> = Lazy::new(FxHashSet::default);
+
+/// Checks whose fix is destructive enough (e.g., deleting code outright, or
+/// rewriting a statement into a materially different one) that it's excluded
+/// from the default `fixable` set even though its code falls under a
+/// selected category. A user has to name it explicitly via
+/// `fixable`/`extend-fixable` (or their CLI equivalents) to opt in.
+pub static UNSAFE_FIXES: Lazy<FxHashSet<CheckCode>> =
+    Lazy::new(|| FxHashSet::from_iter([CheckCode::ERA001, CheckCode::PT009]));
 
 /// A hash map from deprecated to latest `CheckCode`.
 pub static CODE_REDIRECTS: Lazy<FxHashMap<&'static str, CheckCode>> = Lazy::new(|| {
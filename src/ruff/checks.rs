@@ -1,14 +1,31 @@
+use itertools::Itertools;
 use once_cell::sync::Lazy;
-use rustc_hash::FxHashMap;
-use rustpython_ast::{Expr, ExprKind, Keyword, KeywordData, Location};
+use ropey::Rope;
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustpython_ast::{Expr, ExprKind, Keyword, KeywordData, Location, Stmt, StmtKind};
+use rustpython_parser::lexer::{LexResult, Tok};
 
+use crate::ast::helpers::{collect_call_paths, dealias_call_path, match_call_path};
 use crate::ast::types::Range;
 use crate::autofix::Fix;
 use crate::registry::CheckKind;
 use crate::settings::flags;
 use crate::source_code_locator::SourceCodeLocator;
+use crate::source_code_style::detect_line_ending;
 use crate::{violations, Check, Settings};
 
+/// Built-in constructors that produce a mutable value, mirroring the table
+/// used by `flake8-bugbear`'s mutable-argument-default check.
+const MUTABLE_FUNCS: &[(&str, &str)] = &[
+    ("", "dict"),
+    ("", "list"),
+    ("", "set"),
+    ("collections", "Counter"),
+    ("collections", "OrderedDict"),
+    ("collections", "defaultdict"),
+    ("collections", "deque"),
+];
+
 /// See: <https://github.com/microsoft/vscode/blob/095ddabc52b82498ee7f718a34f9dd11d59099a8/src/vs/base/common/strings.ts#L1094>
 static CONFUSABLES: Lazy<FxHashMap<u32, u32>> = Lazy::new(|| {
     #[allow(clippy::unreadable_literal)]
@@ -1699,3 +1716,217 @@ pub fn keyword_argument_before_star_argument(args: &[Expr], keywords: &[Keyword]
     }
     checks
 }
+
+fn is_mutable_expr(
+    expr: &Expr,
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> bool {
+    match &expr.node {
+        ExprKind::List { .. } | ExprKind::Dict { .. } | ExprKind::Set { .. } => true,
+        ExprKind::Call { func, .. } => {
+            let call_path = dealias_call_path(collect_call_paths(func), import_aliases);
+            MUTABLE_FUNCS
+                .iter()
+                .any(|(module, member)| match_call_path(&call_path, module, member, from_imports))
+        }
+        _ => false,
+    }
+}
+
+fn is_dataclass_decorator(
+    decorator_list: &[Expr],
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> bool {
+    decorator_list.iter().any(|expr| {
+        let call_path = dealias_call_path(collect_call_paths(expr), import_aliases);
+        match_call_path(&call_path, "dataclasses", "dataclass", from_imports)
+    })
+}
+
+fn is_class_var_annotation(
+    annotation: &Expr,
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> bool {
+    let target = match &annotation.node {
+        ExprKind::Subscript { value, .. } => value,
+        _ => annotation,
+    };
+    let call_path = dealias_call_path(collect_call_paths(target), import_aliases);
+    match_call_path(&call_path, "typing", "ClassVar", from_imports)
+}
+
+/// Return the default value assigned to a dataclass field, ignoring an
+/// enclosing `dataclasses.field(default_factory=...)` call (which is the
+/// blessed way to give a field a mutable default).
+fn dataclass_field_default<'a>(
+    value: &'a Expr,
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Option<&'a Expr> {
+    if let ExprKind::Call { func, keywords, .. } = &value.node {
+        let call_path = dealias_call_path(collect_call_paths(func), import_aliases);
+        if match_call_path(&call_path, "dataclasses", "field", from_imports) {
+            if keywords
+                .iter()
+                .any(|keyword| keyword.node.arg.as_deref() == Some("default_factory"))
+            {
+                return None;
+            }
+            return keywords
+                .iter()
+                .find(|keyword| keyword.node.arg.as_deref() == Some("default"))
+                .map(|keyword| &keyword.node.value);
+        }
+    }
+    Some(value)
+}
+
+/// RUF008
+pub fn mutable_dataclass_default(
+    decorator_list: &[Expr],
+    body: &[Stmt],
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Vec<Check> {
+    let mut checks = vec![];
+    if !is_dataclass_decorator(decorator_list, from_imports, import_aliases) {
+        return checks;
+    }
+    for stmt in body {
+        if let StmtKind::AnnAssign {
+            value: Some(value), ..
+        } = &stmt.node
+        {
+            if let Some(default) = dataclass_field_default(value, from_imports, import_aliases) {
+                if is_mutable_expr(default, from_imports, import_aliases) {
+                    checks.push(Check::new(
+                        violations::MutableDataclassDefault,
+                        Range::from_located(default),
+                    ));
+                }
+            }
+        }
+    }
+    checks
+}
+
+/// RUF012
+pub fn mutable_class_default(
+    decorator_list: &[Expr],
+    body: &[Stmt],
+    from_imports: &FxHashMap<&str, FxHashSet<&str>>,
+    import_aliases: &FxHashMap<&str, &str>,
+) -> Vec<Check> {
+    let mut checks = vec![];
+    // Dataclass fields are turned into per-instance attributes by the
+    // generated `__init__`, so they don't need `ClassVar` to opt out of
+    // being a shared mutable default; that case is covered by RUF008
+    // instead.
+    if is_dataclass_decorator(decorator_list, from_imports, import_aliases) {
+        return checks;
+    }
+    for stmt in body {
+        if let StmtKind::AnnAssign {
+            annotation,
+            value: Some(value),
+            ..
+        } = &stmt.node
+        {
+            if is_mutable_expr(value, from_imports, import_aliases)
+                && !is_class_var_annotation(annotation, from_imports, import_aliases)
+            {
+                checks.push(Check::new(
+                    violations::MutableClassDefault,
+                    Range::from_located(value),
+                ));
+            }
+        }
+    }
+    checks
+}
+
+/// RUF005
+///
+/// Unlike `flake8-implicit-str-concat`'s ISC001/ISC002 (which flag any
+/// adjacent string literals, including ones split across lines for
+/// readability), this only flags adjacent string literals that appear
+/// directly inside a `(`, `[`, or `{` — i.e. as an element of a list, tuple,
+/// set, or dict literal, or as a call argument. In that position, a missing
+/// comma is far more likely than an intentional concatenation.
+pub fn implicit_string_concat_in_collection(tokens: &[LexResult]) -> Vec<Check> {
+    let mut checks = vec![];
+    let mut depth = 0u32;
+    for ((a_start, a_tok, _), (_, b_tok, b_end)) in tokens.iter().flatten().tuple_windows() {
+        match a_tok {
+            Tok::Lpar | Tok::Lsqb | Tok::Lbrace => depth += 1,
+            Tok::Rpar | Tok::Rsqb | Tok::Rbrace => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        if depth > 0 && matches!(a_tok, Tok::String { .. }) && matches!(b_tok, Tok::String { .. })
+        {
+            checks.push(Check::new(
+                violations::ImplicitStringConcatenationInCollection,
+                Range {
+                    location: *a_start,
+                    end_location: *b_end,
+                },
+            ));
+        }
+    }
+    checks
+}
+
+/// RUF013
+pub fn mixed_line_endings(contents: &str, autofix: bool) -> Option<Check> {
+    let mut has_lf = false;
+    let mut has_cr = false;
+    let mut has_crlf = false;
+    let mut chars = contents.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\r' {
+            if matches!(chars.peek(), Some('\n')) {
+                chars.next();
+                has_crlf = true;
+            } else {
+                has_cr = true;
+            }
+        } else if c == '\n' {
+            has_lf = true;
+        }
+    }
+    if [has_lf, has_cr, has_crlf]
+        .iter()
+        .filter(|&&found| found)
+        .count()
+        <= 1
+    {
+        return None;
+    }
+
+    // Both locations are at the start of the file; the fix (if any) covers the
+    // whole file, since every non-canonical line ending needs replacing.
+    let location = Location::new(1, 0);
+    let mut check = Check::new(violations::MixedLineEndings, Range::new(location, location));
+    if autofix {
+        let ending: &str = &detect_line_ending(contents).unwrap_or_default();
+        let normalized = contents
+            .replace("\r\n", "\n")
+            .replace('\r', "\n")
+            .replace('\n', ending);
+        // The replacement spans the whole file, so its end has to be the true
+        // end of the file. `str::lines()` doesn't emit a trailing empty line for
+        // content ending in a line terminator and doesn't split on a lone `\r`
+        // at all, so it undercounts the real EOF offset in the common case
+        // (files ending in a newline) and mishandles CR-only trailers. Ropey's
+        // line indexing (with the `cr_lines` feature, matching how `apply_fixes`
+        // slices the rest of the file) gives the exact offset instead.
+        let rope = Rope::from_str(contents);
+        let last_row = rope.len_lines() - 1;
+        let end_location = Location::new(last_row + 1, rope.line(last_row).len_chars());
+        check.amend(Fix::replacement(normalized, location, end_location));
+    }
+    Some(check)
+}
@@ -1,6 +1,7 @@
 //! Module for Ruff-specific rules.
 
 pub mod checks;
+pub mod plugins;
 
 #[cfg(test)]
 mod tests {
@@ -14,6 +15,15 @@ mod tests {
     use crate::registry::CheckCode;
     use crate::settings;
     #[test_case(CheckCode::RUF004, Path::new("RUF004.py"); "RUF004")]
+    #[test_case(CheckCode::RUF005, Path::new("RUF005.py"); "RUF005")]
+    #[test_case(CheckCode::RUF006, Path::new("RUF006.py"); "RUF006")]
+    #[test_case(CheckCode::RUF008, Path::new("RUF008.py"); "RUF008")]
+    #[test_case(CheckCode::RUF012, Path::new("RUF012.py"); "RUF012")]
+    #[test_case(CheckCode::RUF013, Path::new("RUF013.py"); "RUF013")]
+    #[test_case(CheckCode::RUF022, Path::new("RUF022.py"); "RUF022")]
+    #[test_case(CheckCode::RUF023, Path::new("RUF023.py"); "RUF023")]
+    #[test_case(CheckCode::RUF101, Path::new("RUF101.py"); "RUF101")]
+    #[test_case(CheckCode::RUF102, Path::new("RUF102.py"); "RUF102")]
     fn checks(check_code: CheckCode, path: &Path) -> Result<()> {
         let snapshot = format!("{}_{}", check_code.as_ref(), path.to_string_lossy());
         let checks = test_path(
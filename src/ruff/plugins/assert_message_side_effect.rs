@@ -0,0 +1,46 @@
+use rustpython_ast::{Expr, ExprKind};
+
+use crate::ast::types::Range;
+use crate::ast::visitor;
+use crate::ast::visitor::Visitor;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::violations;
+
+#[derive(Default)]
+struct SideEffectVisitor {
+    found: bool,
+}
+
+impl<'a> Visitor<'a> for SideEffectVisitor {
+    fn visit_expr(&mut self, expr: &'a Expr) {
+        match &expr.node {
+            ExprKind::Call { .. } | ExprKind::NamedExpr { .. } => {
+                self.found = true;
+            }
+            // A call inside a `lambda` isn't executed unless the lambda
+            // itself is called, so it isn't a side effect of the `assert`.
+            ExprKind::Lambda { .. } => return,
+            _ => {}
+        }
+        if !self.found {
+            visitor::walk_expr(self, expr);
+        }
+    }
+}
+
+/// RUF023
+pub fn assert_message_side_effect(checker: &mut Checker, msg: &Expr) {
+    // Since `assert` statements (test *and* message) are stripped entirely
+    // under `python -O`, any side effect in the message only runs
+    // sometimes -- which is almost always a sign the side effect belongs
+    // outside the `assert` altogether.
+    let mut visitor = SideEffectVisitor::default();
+    visitor.visit_expr(msg);
+    if visitor.found {
+        checker.checks.push(Check::new(
+            violations::AssertMessageSideEffect,
+            Range::from_located(msg),
+        ));
+    }
+}
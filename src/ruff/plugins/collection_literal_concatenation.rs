@@ -0,0 +1,100 @@
+use rustpython_ast::{Expr, ExprContext, ExprKind, Location};
+
+use crate::ast::types::Range;
+use crate::autofix::Fix;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::source_code_generator::SourceCodeGenerator;
+use crate::violations;
+
+/// Return the sole argument of a bare `list(...)` call, if `expr` is one.
+fn list_call_argument(expr: &Expr) -> Option<&Expr> {
+    let ExprKind::Call {
+        func,
+        args,
+        keywords,
+    } = &expr.node
+    else {
+        return None;
+    };
+    if !keywords.is_empty() {
+        return None;
+    }
+    let [arg] = args.as_slice() else {
+        return None;
+    };
+    let ExprKind::Name { id, .. } = &func.node else {
+        return None;
+    };
+    if id != "list" {
+        return None;
+    }
+    Some(arg)
+}
+
+fn starred(expr: &Expr) -> Expr {
+    Expr::new(
+        Location::default(),
+        Location::default(),
+        ExprKind::Starred {
+            value: Box::new(expr.clone()),
+            ctx: ExprContext::Load,
+        },
+    )
+}
+
+/// RUF006
+pub fn collection_literal_concatenation(
+    checker: &mut Checker,
+    expr: &Expr,
+    left: &Expr,
+    right: &Expr,
+) {
+    let (list_elts, list_on_left, other) = if let ExprKind::List { elts, .. } = &left.node {
+        let Some(other) = list_call_argument(right) else {
+            return;
+        };
+        (elts, true, other)
+    } else if let ExprKind::List { elts, .. } = &right.node {
+        let Some(other) = list_call_argument(left) else {
+            return;
+        };
+        (elts, false, other)
+    } else {
+        return;
+    };
+
+    let mut check = Check::new(
+        violations::CollectionLiteralConcatenation,
+        Range::from_located(expr),
+    );
+    if checker.patch(check.kind.code()) {
+        let new_elts: Vec<Expr> = if list_on_left {
+            list_elts
+                .iter()
+                .cloned()
+                .chain(std::iter::once(starred(other)))
+                .collect()
+        } else {
+            std::iter::once(starred(other))
+                .chain(list_elts.iter().cloned())
+                .collect()
+        };
+        let new_expr = Expr::new(
+            Location::default(),
+            Location::default(),
+            ExprKind::List {
+                elts: new_elts,
+                ctx: ExprContext::Load,
+            },
+        );
+        let mut generator: SourceCodeGenerator = checker.style.into();
+        generator.unparse_expr(&new_expr, 0);
+        check.amend(Fix::replacement(
+            generator.generate(),
+            expr.location,
+            expr.end_location.unwrap(),
+        ));
+    }
+    checker.checks.push(check);
+}
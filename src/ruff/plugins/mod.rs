@@ -0,0 +1,7 @@
+pub use assert_message_side_effect::assert_message_side_effect;
+pub use collection_literal_concatenation::collection_literal_concatenation;
+pub use unsorted_dunder_all::unsorted_dunder_all;
+
+mod assert_message_side_effect;
+mod collection_literal_concatenation;
+mod unsorted_dunder_all;
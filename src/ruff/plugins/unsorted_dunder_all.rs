@@ -0,0 +1,90 @@
+use rustpython_ast::{Constant, Expr, ExprContext, ExprKind, Location};
+
+use crate::ast::types::Range;
+use crate::autofix::Fix;
+use crate::checkers::ast::Checker;
+use crate::registry::Check;
+use crate::source_code_generator::SourceCodeGenerator;
+use crate::violations;
+
+/// Return the string values of `elts`, or `None` if any element isn't a
+/// string literal.
+fn string_values(elts: &[Expr]) -> Option<Vec<&str>> {
+    elts.iter()
+        .map(|elt| match &elt.node {
+            ExprKind::Constant {
+                value: Constant::Str(value),
+                ..
+            } => Some(value.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// RUF022
+pub fn unsorted_dunder_all(checker: &mut Checker, value: &Expr) {
+    let (elts, is_tuple) = match &value.node {
+        ExprKind::List { elts, .. } => (elts, false),
+        ExprKind::Tuple { elts, .. } => (elts, true),
+        _ => return,
+    };
+    let Some(names) = string_values(elts) else {
+        // `__all__` should only ever contain string literals; if it
+        // doesn't, that's a different problem than sort order.
+        return;
+    };
+
+    let mut sorted_names = names.clone();
+    sorted_names.sort_unstable();
+    if names == sorted_names {
+        return;
+    }
+
+    let mut check = Check::new(violations::UnsortedDunderAll, Range::from_located(value));
+    if checker.patch(check.kind.code()) {
+        // Only autofix the common single-line case. A multi-line `__all__`
+        // may interleave per-entry comments that we can't safely re-attach
+        // when regenerating the node from scratch, so we leave those for
+        // the user to sort by hand.
+        if value.location.row() == value.end_location.unwrap().row() {
+            let mut sorted_elts = elts.to_vec();
+            sorted_elts.sort_unstable_by(|a, b| {
+                let ExprKind::Constant {
+                    value: Constant::Str(a),
+                    ..
+                } = &a.node
+                else {
+                    unreachable!("elements were already validated as string literals")
+                };
+                let ExprKind::Constant {
+                    value: Constant::Str(b),
+                    ..
+                } = &b.node
+                else {
+                    unreachable!("elements were already validated as string literals")
+                };
+                a.cmp(b)
+            });
+            let new_node = if is_tuple {
+                ExprKind::Tuple {
+                    elts: sorted_elts,
+                    ctx: ExprContext::Load,
+                }
+            } else {
+                ExprKind::List {
+                    elts: sorted_elts,
+                    ctx: ExprContext::Load,
+                }
+            };
+            let new_expr = Expr::new(Location::default(), Location::default(), new_node);
+            let mut generator: SourceCodeGenerator = checker.style.into();
+            generator.unparse_expr(&new_expr, 0);
+            check.amend(Fix::replacement(
+                generator.generate(),
+                value.location,
+                value.end_location.unwrap(),
+            ));
+        }
+    }
+    checker.checks.push(check);
+}
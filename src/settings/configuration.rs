@@ -20,9 +20,10 @@ use crate::settings::types::{
     FilePattern, PerFileIgnore, PythonVersion, SerializationFormat, Version,
 };
 use crate::{
-    flake8_annotations, flake8_bandit, flake8_bugbear, flake8_errmsg, flake8_import_conventions,
-    flake8_pytest_style, flake8_quotes, flake8_tidy_imports, flake8_unused_arguments, fs, isort,
-    mccabe, pep8_naming, pycodestyle, pydocstyle, pyupgrade,
+    flake8_annotations, flake8_bandit, flake8_boolean_trap, flake8_builtins, flake8_bugbear,
+    flake8_errmsg, flake8_import_conventions, flake8_print, flake8_pytest_style, flake8_quotes,
+    flake8_tidy_imports, flake8_unused_arguments, fs, isort, mccabe, pandas_vet, pep8_naming,
+    pycodestyle, pydocstyle, pyflakes, pylint, pyupgrade,
 };
 
 #[derive(Debug, Default)]
@@ -33,6 +34,7 @@ pub struct Configuration {
     pub exclude: Option<Vec<FilePattern>>,
     pub extend: Option<PathBuf>,
     pub extend_exclude: Vec<FilePattern>,
+    pub extend_fixable: Option<Vec<CheckCodePrefix>>,
     pub extend_ignore: Vec<Vec<CheckCodePrefix>>,
     pub extend_select: Vec<Vec<CheckCodePrefix>>,
     pub external: Option<Vec<String>>,
@@ -43,8 +45,11 @@ pub struct Configuration {
     pub format: Option<SerializationFormat>,
     pub ignore: Option<Vec<CheckCodePrefix>>,
     pub ignore_init_module_imports: Option<bool>,
+    pub latin1_fallback: Option<bool>,
     pub line_length: Option<usize>,
+    pub tab_size: Option<usize>,
     pub per_file_ignores: Option<Vec<PerFileIgnore>>,
+    pub preview: Option<bool>,
     pub required_version: Option<Version>,
     pub respect_gitignore: Option<bool>,
     pub select: Option<Vec<CheckCodePrefix>>,
@@ -57,18 +62,24 @@ pub struct Configuration {
     // Plugins
     pub flake8_annotations: Option<flake8_annotations::settings::Options>,
     pub flake8_bandit: Option<flake8_bandit::settings::Options>,
+    pub flake8_boolean_trap: Option<flake8_boolean_trap::settings::Options>,
+    pub flake8_builtins: Option<flake8_builtins::settings::Options>,
     pub flake8_bugbear: Option<flake8_bugbear::settings::Options>,
     pub flake8_errmsg: Option<flake8_errmsg::settings::Options>,
     pub flake8_import_conventions: Option<flake8_import_conventions::settings::Options>,
+    pub flake8_print: Option<flake8_print::settings::Options>,
     pub flake8_pytest_style: Option<flake8_pytest_style::settings::Options>,
     pub flake8_quotes: Option<flake8_quotes::settings::Options>,
     pub flake8_tidy_imports: Option<flake8_tidy_imports::settings::Options>,
     pub flake8_unused_arguments: Option<flake8_unused_arguments::settings::Options>,
     pub isort: Option<isort::settings::Options>,
     pub mccabe: Option<mccabe::settings::Options>,
+    pub pandas_vet: Option<pandas_vet::settings::Options>,
     pub pep8_naming: Option<pep8_naming::settings::Options>,
     pub pycodestyle: Option<pycodestyle::settings::Options>,
     pub pydocstyle: Option<pydocstyle::settings::Options>,
+    pub pyflakes: Option<pyflakes::settings::Options>,
+    pub pylint: Option<pylint::settings::Options>,
     pub pyupgrade: Option<pyupgrade::settings::Options>,
 }
 
@@ -122,6 +133,7 @@ impl Configuration {
                         .collect()
                 })
                 .unwrap_or_default(),
+            extend_fixable: options.extend_fixable,
             extend_ignore: vec![options.extend_ignore.unwrap_or_default()],
             extend_select: vec![options.extend_select.unwrap_or_default()],
             external: options.external,
@@ -132,7 +144,9 @@ impl Configuration {
             force_exclude: options.force_exclude,
             ignore: options.ignore,
             ignore_init_module_imports: options.ignore_init_module_imports,
+            latin1_fallback: options.latin1_fallback,
             line_length: options.line_length,
+            tab_size: options.tab_size,
             per_file_ignores: options.per_file_ignores.map(|per_file_ignores| {
                 per_file_ignores
                     .into_iter()
@@ -142,6 +156,7 @@ impl Configuration {
                     })
                     .collect()
             }),
+            preview: options.preview,
             required_version: options.required_version,
             respect_gitignore: options.respect_gitignore,
             select: options.select,
@@ -157,18 +172,24 @@ impl Configuration {
             // Plugins
             flake8_annotations: options.flake8_annotations,
             flake8_bandit: options.flake8_bandit,
+            flake8_boolean_trap: options.flake8_boolean_trap,
+            flake8_builtins: options.flake8_builtins,
             flake8_bugbear: options.flake8_bugbear,
             flake8_errmsg: options.flake8_errmsg,
             flake8_import_conventions: options.flake8_import_conventions,
+            flake8_print: options.flake8_print,
             flake8_pytest_style: options.flake8_pytest_style,
             flake8_quotes: options.flake8_quotes,
             flake8_tidy_imports: options.flake8_tidy_imports,
             flake8_unused_arguments: options.flake8_unused_arguments,
             isort: options.isort,
             mccabe: options.mccabe,
+            pandas_vet: options.pandas_vet,
             pep8_naming: options.pep8_naming,
             pycodestyle: options.pycodestyle,
             pydocstyle: options.pydocstyle,
+            pyflakes: options.pyflakes,
+            pylint: options.pylint,
             pyupgrade: options.pyupgrade,
         })
     }
@@ -186,6 +207,7 @@ impl Configuration {
                 .into_iter()
                 .chain(self.extend_exclude.into_iter())
                 .collect(),
+            extend_fixable: self.extend_fixable.or(config.extend_fixable),
             extend_ignore: config
                 .extend_ignore
                 .into_iter()
@@ -206,8 +228,11 @@ impl Configuration {
             ignore_init_module_imports: self
                 .ignore_init_module_imports
                 .or(config.ignore_init_module_imports),
+            latin1_fallback: self.latin1_fallback.or(config.latin1_fallback),
             line_length: self.line_length.or(config.line_length),
+            tab_size: self.tab_size.or(config.tab_size),
             per_file_ignores: self.per_file_ignores.or(config.per_file_ignores),
+            preview: self.preview.or(config.preview),
             required_version: self.required_version.or(config.required_version),
             respect_gitignore: self.respect_gitignore.or(config.respect_gitignore),
             select: self.select.or(config.select),
@@ -220,11 +245,14 @@ impl Configuration {
             // Plugins
             flake8_annotations: self.flake8_annotations.or(config.flake8_annotations),
             flake8_bandit: self.flake8_bandit.or(config.flake8_bandit),
+            flake8_boolean_trap: self.flake8_boolean_trap.or(config.flake8_boolean_trap),
+            flake8_builtins: self.flake8_builtins.or(config.flake8_builtins),
             flake8_bugbear: self.flake8_bugbear.or(config.flake8_bugbear),
             flake8_errmsg: self.flake8_errmsg.or(config.flake8_errmsg),
             flake8_import_conventions: self
                 .flake8_import_conventions
                 .or(config.flake8_import_conventions),
+            flake8_print: self.flake8_print.or(config.flake8_print),
             flake8_pytest_style: self.flake8_pytest_style.or(config.flake8_pytest_style),
             flake8_quotes: self.flake8_quotes.or(config.flake8_quotes),
             flake8_tidy_imports: self.flake8_tidy_imports.or(config.flake8_tidy_imports),
@@ -233,9 +261,12 @@ impl Configuration {
                 .or(config.flake8_unused_arguments),
             isort: self.isort.or(config.isort),
             mccabe: self.mccabe.or(config.mccabe),
+            pandas_vet: self.pandas_vet.or(config.pandas_vet),
             pep8_naming: self.pep8_naming.or(config.pep8_naming),
             pycodestyle: self.pycodestyle.or(config.pycodestyle),
             pydocstyle: self.pydocstyle.or(config.pydocstyle),
+            pyflakes: self.pyflakes.or(config.pyflakes),
+            pylint: self.pylint.or(config.pylint),
             pyupgrade: self.pyupgrade.or(config.pyupgrade),
         }
     }
@@ -262,6 +293,9 @@ impl Configuration {
         if let Some(fixable) = overrides.fixable {
             self.fixable = Some(fixable);
         }
+        if let Some(extend_fixable) = overrides.extend_fixable {
+            self.extend_fixable = Some(extend_fixable);
+        }
         if let Some(format) = overrides.format {
             self.format = Some(format);
         }
@@ -282,6 +316,9 @@ impl Configuration {
         if let Some(per_file_ignores) = overrides.per_file_ignores {
             self.per_file_ignores = Some(collect_per_file_ignores(per_file_ignores));
         }
+        if let Some(preview) = overrides.preview {
+            self.preview = Some(preview);
+        }
         if let Some(respect_gitignore) = overrides.respect_gitignore {
             self.respect_gitignore = Some(respect_gitignore);
         }
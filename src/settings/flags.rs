@@ -30,6 +30,11 @@ impl From<fixer::Mode> for Autofix {
 pub enum Noqa {
     Enabled,
     Disabled,
+    /// Like `Enabled`, but `noqa` directives are evaluated without suppressing
+    /// the violations they'd otherwise hide — those violations are instead
+    /// reported with `Check::is_suppressed` set, so that suppressed output
+    /// can be audited (e.g., via `--ignore-noqa`).
+    Ignored,
 }
 
 impl From<bool> for Noqa {
@@ -57,3 +62,22 @@ impl From<bool> for Cache {
         }
     }
 }
+
+/// Whether to sort violations into a stable, path-based order before
+/// printing them. Checks run in parallel, so without sorting, output order
+/// tracks completion order and varies from run to run.
+#[derive(Debug, Copy, Clone, Hash)]
+pub enum Sort {
+    Enabled,
+    Disabled,
+}
+
+impl From<bool> for Sort {
+    fn from(value: bool) -> Self {
+        if value {
+            Sort::Enabled
+        } else {
+            Sort::Disabled
+        }
+    }
+}
@@ -17,15 +17,19 @@ use regex::Regex;
 use rustc_hash::FxHashSet;
 
 use crate::cache::cache_dir;
-use crate::registry::{CheckCode, CheckCodePrefix, SuffixLength, CATEGORIES, INCOMPATIBLE_CODES};
+use crate::registry::{
+    CheckCode, CheckCodePrefix, SuffixLength, CATEGORIES, INCOMPATIBLE_CODES, PREVIEW_CODES,
+    UNSAFE_FIXES,
+};
 use crate::settings::configuration::Configuration;
 use crate::settings::types::{
     FilePattern, PerFileIgnore, PythonVersion, SerializationFormat, Version,
 };
 use crate::{
-    flake8_annotations, flake8_bandit, flake8_bugbear, flake8_errmsg, flake8_import_conventions,
-    flake8_pytest_style, flake8_quotes, flake8_tidy_imports, flake8_unused_arguments, isort,
-    mccabe, one_time_warning, pep8_naming, pycodestyle, pydocstyle, pyupgrade,
+    flake8_annotations, flake8_bandit, flake8_boolean_trap, flake8_builtins, flake8_bugbear,
+    flake8_errmsg, flake8_import_conventions, flake8_print, flake8_pytest_style, flake8_quotes,
+    flake8_tidy_imports, flake8_unused_arguments, isort, mccabe, one_time_warning, pandas_vet,
+    pep8_naming, pycodestyle, pydocstyle, pyflakes, pylint, pyupgrade,
 };
 
 pub mod configuration;
@@ -52,8 +56,11 @@ pub struct Settings {
     pub force_exclude: bool,
     pub format: SerializationFormat,
     pub ignore_init_module_imports: bool,
+    pub latin1_fallback: bool,
     pub line_length: usize,
+    pub tab_size: usize,
     pub per_file_ignores: Vec<(GlobMatcher, GlobMatcher, FxHashSet<CheckCode>)>,
+    pub preview: bool,
     pub required_version: Option<Version>,
     pub respect_gitignore: bool,
     pub show_source: bool,
@@ -64,18 +71,24 @@ pub struct Settings {
     // Plugins
     pub flake8_annotations: flake8_annotations::settings::Settings,
     pub flake8_bandit: flake8_bandit::settings::Settings,
+    pub flake8_boolean_trap: flake8_boolean_trap::settings::Settings,
+    pub flake8_builtins: flake8_builtins::settings::Settings,
     pub flake8_bugbear: flake8_bugbear::settings::Settings,
     pub flake8_errmsg: flake8_errmsg::settings::Settings,
     pub flake8_import_conventions: flake8_import_conventions::settings::Settings,
+    pub flake8_print: flake8_print::settings::Settings,
     pub flake8_pytest_style: flake8_pytest_style::settings::Settings,
     pub flake8_quotes: flake8_quotes::settings::Settings,
     pub flake8_tidy_imports: flake8_tidy_imports::settings::Settings,
     pub flake8_unused_arguments: flake8_unused_arguments::settings::Settings,
     pub isort: isort::settings::Settings,
     pub mccabe: mccabe::settings::Settings,
+    pub pandas_vet: pandas_vet::settings::Settings,
     pub pep8_naming: pep8_naming::settings::Settings,
     pub pycodestyle: pycodestyle::settings::Settings,
     pub pydocstyle: pydocstyle::settings::Settings,
+    pub pyflakes: pyflakes::settings::Settings,
+    pub pylint: pylint::settings::Settings,
     pub pyupgrade: pyupgrade::settings::Settings,
 }
 
@@ -108,6 +121,7 @@ static DEFAULT_DUMMY_VARIABLE_RGX: Lazy<Regex> =
 
 impl Settings {
     pub fn from_configuration(config: Configuration, project_root: &Path) -> Result<Self> {
+        let preview = config.preview.unwrap_or(false);
         Ok(Self {
             allowed_confusables: config
                 .allowed_confusables
@@ -117,57 +131,90 @@ impl Settings {
             dummy_variable_rgx: config
                 .dummy_variable_rgx
                 .unwrap_or_else(|| DEFAULT_DUMMY_VARIABLE_RGX.clone()),
-            enabled: validate_enabled(resolve_codes(
-                [CheckCodeSpec {
-                    select: &config
-                        .select
-                        .unwrap_or_else(|| vec![CheckCodePrefix::E, CheckCodePrefix::F]),
-                    ignore: &config.ignore.unwrap_or_default(),
-                }]
-                .into_iter()
-                .chain(
-                    config
-                        .extend_select
-                        .iter()
-                        .zip(config.extend_ignore.iter())
-                        .map(|(select, ignore)| CheckCodeSpec { select, ignore }),
-                )
-                .chain(
-                    // If a docstring convention is specified, force-disable any incompatible error
-                    // codes.
-                    if let Some(convention) = config
-                        .pydocstyle
-                        .as_ref()
-                        .and_then(|pydocstyle| pydocstyle.convention)
-                    {
-                        Left(iter::once(CheckCodeSpec {
-                            select: &[],
-                            ignore: convention.codes(),
-                        }))
-                    } else {
-                        Right(iter::empty())
-                    },
-                ),
-            )),
+            enabled: {
+                let mut enabled = validate_enabled(resolve_codes(
+                    [CheckCodeSpec {
+                        select: &config
+                            .select
+                            .unwrap_or_else(|| vec![CheckCodePrefix::E, CheckCodePrefix::F]),
+                        ignore: &config.ignore.unwrap_or_default(),
+                    }]
+                    .into_iter()
+                    .chain(
+                        config
+                            .extend_select
+                            .iter()
+                            .zip(config.extend_ignore.iter())
+                            .map(|(select, ignore)| CheckCodeSpec { select, ignore }),
+                    )
+                    .chain(
+                        // If a docstring convention is specified, force-disable any incompatible error
+                        // codes.
+                        if let Some(convention) = config
+                            .pydocstyle
+                            .as_ref()
+                            .and_then(|pydocstyle| pydocstyle.convention)
+                        {
+                            Left(iter::once(CheckCodeSpec {
+                                select: &[],
+                                ignore: convention.codes(),
+                            }))
+                        } else {
+                            Right(iter::empty())
+                        },
+                    ),
+                ));
+                // Preview checks are excluded from the enabled set unless preview
+                // mode is turned on, regardless of how they were selected, so that
+                // newly added checks can ship disabled-by-default.
+                if !preview {
+                    enabled.retain(|check_code| !PREVIEW_CODES.contains(check_code));
+                }
+                enabled
+            },
             exclude: resolve_globset(config.exclude.unwrap_or_else(|| DEFAULT_EXCLUDE.clone()))?,
             extend_exclude: resolve_globset(config.extend_exclude)?,
             external: FxHashSet::from_iter(config.external.unwrap_or_default()),
             fix: config.fix.unwrap_or(false),
             fix_only: config.fix_only.unwrap_or(false),
-            fixable: resolve_codes(
-                [CheckCodeSpec {
-                    select: &config.fixable.unwrap_or_else(|| CATEGORIES.to_vec()),
-                    ignore: &config.unfixable.unwrap_or_default(),
-                }]
-                .into_iter(),
-            ),
+            fixable: {
+                let fixable_explicit = config.fixable.is_some();
+                let extend_fixable = config.extend_fixable.unwrap_or_default();
+                let mut fixable = resolve_codes(
+                    [CheckCodeSpec {
+                        select: &config.fixable.unwrap_or_else(|| CATEGORIES.to_vec()),
+                        ignore: &config.unfixable.unwrap_or_default(),
+                    }]
+                    .into_iter()
+                    .chain(iter::once(CheckCodeSpec {
+                        select: &extend_fixable,
+                        ignore: &[],
+                    })),
+                );
+                // Unless the user named `fixable` explicitly, drop any code
+                // in UNSAFE_FIXES that only made it in via the default
+                // category list -- but still honor an explicit opt-in via
+                // `extend-fixable`.
+                if !fixable_explicit {
+                    fixable.retain(|code| {
+                        !UNSAFE_FIXES.contains(code)
+                            || extend_fixable
+                                .iter()
+                                .any(|prefix| prefix.codes().contains(code))
+                    });
+                }
+                fixable
+            },
             format: config.format.unwrap_or_default(),
             force_exclude: config.force_exclude.unwrap_or(false),
             ignore_init_module_imports: config.ignore_init_module_imports.unwrap_or_default(),
+            latin1_fallback: config.latin1_fallback.unwrap_or_default(),
             line_length: config.line_length.unwrap_or(88),
+            tab_size: config.tab_size.unwrap_or(8),
             per_file_ignores: resolve_per_file_ignores(
                 config.per_file_ignores.unwrap_or_default(),
             )?,
+            preview,
             respect_gitignore: config.respect_gitignore.unwrap_or(true),
             required_version: config.required_version,
             show_source: config.show_source.unwrap_or_default(),
@@ -185,12 +232,18 @@ impl Settings {
                 .map(Into::into)
                 .unwrap_or_default(),
             flake8_bandit: config.flake8_bandit.map(Into::into).unwrap_or_default(),
+            flake8_boolean_trap: config
+                .flake8_boolean_trap
+                .map(Into::into)
+                .unwrap_or_default(),
+            flake8_builtins: config.flake8_builtins.map(Into::into).unwrap_or_default(),
             flake8_bugbear: config.flake8_bugbear.map(Into::into).unwrap_or_default(),
             flake8_errmsg: config.flake8_errmsg.map(Into::into).unwrap_or_default(),
             flake8_import_conventions: config
                 .flake8_import_conventions
                 .map(Into::into)
                 .unwrap_or_default(),
+            flake8_print: config.flake8_print.map(Into::into).unwrap_or_default(),
             flake8_pytest_style: config
                 .flake8_pytest_style
                 .map(Into::into)
@@ -206,9 +259,12 @@ impl Settings {
                 .unwrap_or_default(),
             isort: config.isort.map(Into::into).unwrap_or_default(),
             mccabe: config.mccabe.map(Into::into).unwrap_or_default(),
+            pandas_vet: config.pandas_vet.map(Into::into).unwrap_or_default(),
             pep8_naming: config.pep8_naming.map(Into::into).unwrap_or_default(),
             pycodestyle: config.pycodestyle.map(Into::into).unwrap_or_default(),
             pydocstyle: config.pydocstyle.map(Into::into).unwrap_or_default(),
+            pyflakes: config.pyflakes.map(Into::into).unwrap_or_default(),
+            pylint: config.pylint.map(Into::into).unwrap_or_default(),
             pyupgrade: config.pyupgrade.map(Into::into).unwrap_or_default(),
         })
     }
@@ -228,8 +284,11 @@ impl Settings {
             force_exclude: false,
             format: SerializationFormat::Text,
             ignore_init_module_imports: false,
+            latin1_fallback: false,
             line_length: 88,
+            tab_size: 8,
             per_file_ignores: vec![],
+            preview: false,
             required_version: None,
             respect_gitignore: true,
             show_source: false,
@@ -239,18 +298,24 @@ impl Settings {
             update_check: false,
             flake8_annotations: flake8_annotations::settings::Settings::default(),
             flake8_bandit: flake8_bandit::settings::Settings::default(),
+            flake8_boolean_trap: flake8_boolean_trap::settings::Settings::default(),
+            flake8_builtins: flake8_builtins::settings::Settings::default(),
             flake8_bugbear: flake8_bugbear::settings::Settings::default(),
             flake8_errmsg: flake8_errmsg::settings::Settings::default(),
             flake8_import_conventions: flake8_import_conventions::settings::Settings::default(),
+            flake8_print: flake8_print::settings::Settings::default(),
             flake8_pytest_style: flake8_pytest_style::settings::Settings::default(),
             flake8_quotes: flake8_quotes::settings::Settings::default(),
             flake8_tidy_imports: flake8_tidy_imports::settings::Settings::default(),
             flake8_unused_arguments: flake8_unused_arguments::settings::Settings::default(),
             isort: isort::settings::Settings::default(),
             mccabe: mccabe::settings::Settings::default(),
+            pandas_vet: pandas_vet::settings::Settings::default(),
             pep8_naming: pep8_naming::settings::Settings::default(),
             pycodestyle: pycodestyle::settings::Settings::default(),
             pydocstyle: pydocstyle::settings::Settings::default(),
+            pyflakes: pyflakes::settings::Settings::default(),
+            pylint: pylint::settings::Settings::default(),
             pyupgrade: pyupgrade::settings::Settings::default(),
         }
     }
@@ -270,8 +335,11 @@ impl Settings {
             force_exclude: false,
             format: SerializationFormat::Text,
             ignore_init_module_imports: false,
+            latin1_fallback: false,
             line_length: 88,
+            tab_size: 8,
             per_file_ignores: vec![],
+            preview: false,
             required_version: None,
             respect_gitignore: true,
             show_source: false,
@@ -281,18 +349,24 @@ impl Settings {
             update_check: false,
             flake8_annotations: flake8_annotations::settings::Settings::default(),
             flake8_bandit: flake8_bandit::settings::Settings::default(),
+            flake8_boolean_trap: flake8_boolean_trap::settings::Settings::default(),
+            flake8_builtins: flake8_builtins::settings::Settings::default(),
             flake8_bugbear: flake8_bugbear::settings::Settings::default(),
             flake8_errmsg: flake8_errmsg::settings::Settings::default(),
             flake8_import_conventions: flake8_import_conventions::settings::Settings::default(),
+            flake8_print: flake8_print::settings::Settings::default(),
             flake8_pytest_style: flake8_pytest_style::settings::Settings::default(),
             flake8_quotes: flake8_quotes::settings::Settings::default(),
             flake8_tidy_imports: flake8_tidy_imports::settings::Settings::default(),
             flake8_unused_arguments: flake8_unused_arguments::settings::Settings::default(),
             isort: isort::settings::Settings::default(),
             mccabe: mccabe::settings::Settings::default(),
+            pandas_vet: pandas_vet::settings::Settings::default(),
             pep8_naming: pep8_naming::settings::Settings::default(),
             pycodestyle: pycodestyle::settings::Settings::default(),
             pydocstyle: pydocstyle::settings::Settings::default(),
+            pyflakes: pyflakes::settings::Settings::default(),
+            pylint: pylint::settings::Settings::default(),
             pyupgrade: pyupgrade::settings::Settings::default(),
         }
     }
@@ -328,7 +402,9 @@ impl Hash for Settings {
             value.hash(state);
         }
         self.ignore_init_module_imports.hash(state);
+        self.latin1_fallback.hash(state);
         self.line_length.hash(state);
+        self.tab_size.hash(state);
         for (absolute, basename, codes) in &self.per_file_ignores {
             absolute.glob().hash(state);
             basename.glob().hash(state);
@@ -342,17 +418,23 @@ impl Hash for Settings {
         // Add plugin properties in alphabetical order.
         self.flake8_annotations.hash(state);
         self.flake8_bandit.hash(state);
+        self.flake8_boolean_trap.hash(state);
+        self.flake8_builtins.hash(state);
         self.flake8_bugbear.hash(state);
         self.flake8_errmsg.hash(state);
         self.flake8_import_conventions.hash(state);
+        self.flake8_print.hash(state);
         self.flake8_pytest_style.hash(state);
         self.flake8_quotes.hash(state);
         self.flake8_tidy_imports.hash(state);
         self.flake8_unused_arguments.hash(state);
         self.isort.hash(state);
         self.mccabe.hash(state);
+        self.pandas_vet.hash(state);
         self.pep8_naming.hash(state);
         self.pydocstyle.hash(state);
+        self.pyflakes.hash(state);
+        self.pylint.hash(state);
         self.pyupgrade.hash(state);
     }
 }
@@ -396,14 +478,22 @@ struct CheckCodeSpec<'a> {
 fn resolve_codes<'a>(specs: impl Iterator<Item = CheckCodeSpec<'a>>) -> FxHashSet<CheckCode> {
     let mut codes: FxHashSet<CheckCode> = FxHashSet::default();
     for spec in specs {
-        for specificity in [
-            SuffixLength::None,
-            SuffixLength::Zero,
-            SuffixLength::One,
-            SuffixLength::Two,
-            SuffixLength::Three,
-            SuffixLength::Four,
-        ] {
+        // Process prefixes from least to most specific (e.g., `ALL`, then `E`,
+        // then `E5`, then `E501`), so that a more specific selector always
+        // takes precedence over a less specific one, regardless of the order
+        // in which they were passed. Computed from whichever specificities
+        // are actually in play, rather than a hardcoded, hand-maintained
+        // list, so it keeps working as codes with longer suffixes are added.
+        let mut specificities: Vec<SuffixLength> = spec
+            .select
+            .iter()
+            .chain(spec.ignore.iter())
+            .map(CheckCodePrefix::specificity)
+            .collect();
+        specificities.sort_unstable();
+        specificities.dedup();
+
+        for specificity in specificities {
             for prefix in spec.select {
                 if prefix.specificity() == specificity {
                     codes.extend(prefix.codes());
@@ -8,9 +8,10 @@ use serde::{Deserialize, Serialize};
 use crate::registry::CheckCodePrefix;
 use crate::settings::types::{PythonVersion, SerializationFormat, Version};
 use crate::{
-    flake8_annotations, flake8_bandit, flake8_bugbear, flake8_errmsg, flake8_import_conventions,
-    flake8_pytest_style, flake8_quotes, flake8_tidy_imports, flake8_unused_arguments, isort,
-    mccabe, pep8_naming, pycodestyle, pydocstyle, pyupgrade,
+    flake8_annotations, flake8_bandit, flake8_boolean_trap, flake8_builtins, flake8_bugbear,
+    flake8_errmsg, flake8_import_conventions, flake8_print, flake8_pytest_style, flake8_quotes,
+    flake8_tidy_imports, flake8_unused_arguments, isort, mccabe, pandas_vet, pep8_naming,
+    pycodestyle, pydocstyle, pyflakes, pylint, pyupgrade,
 };
 
 #[derive(
@@ -109,6 +110,17 @@ pub struct Options {
     /// A list of file patterns to omit from linting, in addition to those
     /// specified by `exclude`.
     pub extend_exclude: Option<Vec<String>>,
+    #[option(
+        default = "[]",
+        value_type = "Vec<CheckCodePrefix>",
+        example = r#"
+            # On top of the default `fixable` set, allow autofixing unused imports (`F401`).
+            extend-fixable = ["F401"]
+        "#
+    )]
+    /// A list of check code prefixes to consider autofix-able, in addition to
+    /// those specified by `fixable`.
+    pub extend_fixable: Option<Vec<CheckCodePrefix>>,
     #[option(
         default = "[]",
         value_type = "Vec<CheckCodePrefix>",
@@ -223,6 +235,22 @@ pub struct Options {
     /// symbol, or re-exported with a redundant alias (e.g., `import os as
     /// os`).
     pub ignore_init_module_imports: Option<bool>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            latin1-fallback = true
+        "#
+    )]
+    /// Whether to fall back to decoding a source file as Latin-1 (ISO-8859-1)
+    /// when it isn't valid UTF-8 and doesn't declare an encoding via a PEP
+    /// 263 coding cookie (e.g., `# -*- coding: ... -*-`) on one of its first
+    /// two lines. Off by default, since Latin-1 maps every byte to a
+    /// codepoint and so never fails to "decode" -- it's a guess of last
+    /// resort, not a real encoding detection. A file that does declare
+    /// `latin-1`, `iso-8859-1`, or `cp1252` in a coding cookie is always
+    /// decoded accordingly, regardless of this setting.
+    pub latin1_fallback: Option<bool>,
     #[option(
         default = "88",
         value_type = "usize",
@@ -234,6 +262,27 @@ pub struct Options {
     /// The line length to use when enforcing long-lines violations (like
     /// `E501`).
     pub line_length: Option<usize>,
+    #[option(
+        default = "8",
+        value_type = "usize",
+        example = r#"
+            # Treat each tab as equivalent to 4 columns, rather than 8.
+            tab-size = 4
+        "#
+    )]
+    /// The number of columns a tab counts as when measuring line length
+    /// (`E501`).
+    pub tab_size: Option<usize>,
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            preview = true
+        "#
+    )]
+    /// Whether to enable preview mode. When preview mode is enabled, Ruff
+    /// will use unstable rules. Fixes are not affected by this setting.
+    pub preview: Option<bool>,
     #[option(
         default = "None",
         value_type = "String",
@@ -365,12 +414,21 @@ pub struct Options {
     /// Options for the `flake8-bandit` plugin.
     pub flake8_bandit: Option<flake8_bandit::settings::Options>,
     #[option_group]
+    /// Options for the `flake8-boolean-trap` plugin.
+    pub flake8_boolean_trap: Option<flake8_boolean_trap::settings::Options>,
+    #[option_group]
+    /// Options for the `flake8-builtins` plugin.
+    pub flake8_builtins: Option<flake8_builtins::settings::Options>,
+    #[option_group]
     /// Options for the `flake8-bugbear` plugin.
     pub flake8_bugbear: Option<flake8_bugbear::settings::Options>,
     #[option_group]
     /// Options for the `flake8-errmsg` plugin.
     pub flake8_errmsg: Option<flake8_errmsg::settings::Options>,
     #[option_group]
+    /// Options for the `flake8-print` plugin.
+    pub flake8_print: Option<flake8_print::settings::Options>,
+    #[option_group]
     /// Options for the `flake8-quotes` plugin.
     pub flake8_quotes: Option<flake8_quotes::settings::Options>,
     #[option_group]
@@ -392,6 +450,9 @@ pub struct Options {
     /// Options for the `mccabe` plugin.
     pub mccabe: Option<mccabe::settings::Options>,
     #[option_group]
+    /// Options for the `pandas-vet` plugin.
+    pub pandas_vet: Option<pandas_vet::settings::Options>,
+    #[option_group]
     /// Options for the `pep8-naming` plugin.
     pub pep8_naming: Option<pep8_naming::settings::Options>,
     #[option_group]
@@ -401,6 +462,12 @@ pub struct Options {
     /// Options for the `pydocstyle` plugin.
     pub pydocstyle: Option<pydocstyle::settings::Options>,
     #[option_group]
+    /// Options for the `pyflakes` plugin.
+    pub pyflakes: Option<pyflakes::settings::Options>,
+    #[option_group]
+    /// Options for the `pylint` plugin.
+    pub pylint: Option<pylint::settings::Options>,
+    #[option_group]
     /// Options for the `pyupgrade` plugin.
     pub pyupgrade: Option<pyupgrade::settings::Options>,
     // Tables are required to go last.
@@ -180,6 +180,7 @@ mod tests {
                     ignore: None,
                     ignore_init_module_imports: None,
                     line_length: None,
+                    tab_size: None,
                     per_file_ignores: None,
                     required_version: None,
                     respect_gitignore: None,
@@ -204,6 +205,7 @@ mod tests {
                     pep8_naming: None,
                     pycodestyle: None,
                     pydocstyle: None,
+                    pylint: None,
                     pyupgrade: None,
                 })
             })
@@ -236,6 +238,7 @@ line-length = 79
                     ignore: None,
                     ignore_init_module_imports: None,
                     line_length: Some(79),
+                    tab_size: None,
                     per_file_ignores: None,
                     respect_gitignore: None,
                     required_version: None,
@@ -261,6 +264,7 @@ line-length = 79
                     pep8_naming: None,
                     pycodestyle: None,
                     pydocstyle: None,
+                    pylint: None,
                     pyupgrade: None,
                 })
             })
@@ -294,6 +298,7 @@ exclude = ["foo.py"]
                     ignore: None,
                     ignore_init_module_imports: None,
                     line_length: None,
+                    tab_size: None,
                     per_file_ignores: None,
                     required_version: None,
                     respect_gitignore: None,
@@ -318,6 +323,7 @@ exclude = ["foo.py"]
                     pep8_naming: None,
                     pycodestyle: None,
                     pydocstyle: None,
+                    pylint: None,
                     pyupgrade: None,
                 })
             })
@@ -351,6 +357,7 @@ select = ["E501"]
                     ignore: None,
                     ignore_init_module_imports: None,
                     line_length: None,
+                    tab_size: None,
                     per_file_ignores: None,
                     required_version: None,
                     respect_gitignore: None,
@@ -375,6 +382,7 @@ select = ["E501"]
                     pep8_naming: None,
                     pycodestyle: None,
                     pydocstyle: None,
+                    pylint: None,
                     pyupgrade: None,
                 })
             })
@@ -409,6 +417,7 @@ ignore = ["E501"]
                     ignore: Some(vec![CheckCodePrefix::E501]),
                     ignore_init_module_imports: None,
                     line_length: None,
+                    tab_size: None,
                     per_file_ignores: None,
                     required_version: None,
                     respect_gitignore: None,
@@ -433,6 +442,7 @@ ignore = ["E501"]
                     pep8_naming: None,
                     pycodestyle: None,
                     pydocstyle: None,
+                    pylint: None,
                     pyupgrade: None,
                 })
             })
@@ -486,6 +496,7 @@ other-attribute = 1
             Options {
                 allowed_confusables: Some(vec!['−', 'ρ', '∗']),
                 line_length: Some(88),
+                tab_size: None,
                 fix: None,
                 fix_only: None,
                 exclude: None,
@@ -611,6 +622,7 @@ other-attribute = 1
                 }),
                 pycodestyle: None,
                 pydocstyle: None,
+                pylint: None,
                 pyupgrade: None,
             }
         );
@@ -159,6 +159,7 @@ pub enum SerializationFormat {
     Grouped,
     Github,
     Gitlab,
+    Sarif,
 }
 
 impl Default for SerializationFormat {
@@ -25,6 +25,15 @@ impl<'a> SourceCodeLocator<'a> {
         self.rope.get_or_init(|| Rope::from_str(self.contents))
     }
 
+    /// Return the (zero-indexed) line at `row`, without slicing any of the
+    /// surrounding file. Useful for walking a handful of lines around a
+    /// `Location` (e.g. counting blank lines) without paying to materialize
+    /// everything before or after them.
+    pub fn line(&self, row: usize) -> Cow<'_, str> {
+        let rope = self.get_or_init_rope();
+        Cow::from(rope.line(row))
+    }
+
     pub fn slice_source_code_at(&self, location: &Location) -> Cow<'_, str> {
         let rope = self.get_or_init_rope();
         let offset = rope.line_to_char(location.row() - 1) + location.column();
@@ -49,7 +49,7 @@ impl<'a> SourceCodeStyleDetector<'a> {
 }
 
 /// The quotation style used in Python source code.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Quote {
     Single,
     Double,
@@ -139,38 +139,66 @@ impl Deref for LineEnding {
     }
 }
 
-/// Detect the indentation style of the given tokens.
+/// Detect the dominant indentation style of the given tokens, i.e. the
+/// indent string used by the largest number of `Indent` tokens (ties go to
+/// whichever indent string was encountered first), rather than just
+/// whichever indent happens to appear first in the file.
 fn detect_indentation(contents: &str, locator: &SourceCodeLocator) -> Option<Indentation> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
     for (_start, tok, end) in lexer::make_tokenizer(contents).flatten() {
         if let Tok::Indent { .. } = tok {
             let start = Location::new(end.row(), 0);
-            let whitespace = locator.slice_source_code_range(&Range::new(start, end));
-            return Some(Indentation(whitespace.to_string()));
+            let whitespace = locator
+                .slice_source_code_range(&Range::new(start, end))
+                .to_string();
+            match counts.iter_mut().find(|(seen, _)| *seen == whitespace) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((whitespace, 1)),
+            }
         }
     }
-    None
+    counts
+        .into_iter()
+        .reduce(|best, next| if next.1 > best.1 { next } else { best })
+        .map(|(whitespace, _)| Indentation(whitespace))
 }
 
-/// Detect the quotation style of the given tokens.
+/// Detect the dominant quotation style of the given tokens, i.e. whichever
+/// of `'` or `"` is used by the largest number of string literals (ties go
+/// to whichever quote style was encountered first), rather than just
+/// whichever quote happens to appear first in the file.
 fn detect_quote(contents: &str, locator: &SourceCodeLocator) -> Option<Quote> {
+    let mut single = 0usize;
+    let mut double = 0usize;
+    let mut first = None;
     for (start, tok, end) in lexer::make_tokenizer(contents).flatten() {
         if let Tok::String { .. } = tok {
             let content = locator.slice_source_code_range(&Range::new(start, end));
             if let Some(pattern) = leading_quote(&content) {
-                if pattern.contains('\'') {
-                    return Some(Quote::Single);
+                let quote = if pattern.contains('\'') {
+                    Quote::Single
                 } else if pattern.contains('"') {
-                    return Some(Quote::Double);
+                    Quote::Double
+                } else {
+                    unreachable!("Expected string to start with a valid quote prefix")
+                };
+                match quote {
+                    Quote::Single => single += 1,
+                    Quote::Double => double += 1,
                 }
-                unreachable!("Expected string to start with a valid quote prefix")
+                first.get_or_insert(quote);
             }
         }
     }
-    None
+    match single.cmp(&double) {
+        std::cmp::Ordering::Greater => Some(Quote::Single),
+        std::cmp::Ordering::Less => Some(Quote::Double),
+        std::cmp::Ordering::Equal => first,
+    }
 }
 
 /// Detect the line ending style of the given contents.
-fn detect_line_ending(contents: &str) -> Option<LineEnding> {
+pub(crate) fn detect_line_ending(contents: &str) -> Option<LineEnding> {
     if let Some(position) = contents.find('\n') {
         let position = position.saturating_sub(1);
         return if let Some('\r') = contents.chars().nth(position) {
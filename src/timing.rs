@@ -0,0 +1,62 @@
+//! Lightweight, opt-in timing of how long each `LintSource` spends running
+//! checks, aggregated across every file in a run and reported via
+//! `--timing`/`RUFF_TIMING`.
+//!
+//! This is coarse-grained (per lint source, not per individual rule):
+//! `check_tokens`, `check_ast`, `check_lines`, `check_imports`, and
+//! `check_noqa` each dispatch to dozens of rules internally, and threading a
+//! timer through every one of those call sites would be a far more invasive
+//! change. Per-lint-source timing is enough to tell you, e.g., that AST-based
+//! checks dominate a slow run, without paying that cost on every check.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use rustc_hash::FxHashMap;
+
+use crate::registry::LintSource;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ELAPSED: Lazy<Mutex<FxHashMap<LintSource, Duration>>> = Lazy::new(Default::default);
+
+/// Enable timing instrumentation for the remainder of the process.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Return `true` if timing instrumentation is enabled.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Run `f`, and if timing is enabled, add its duration to the running total
+/// for `source`.
+pub fn time<T>(source: LintSource, f: impl FnOnce() -> T) -> T {
+    if !enabled() {
+        return f();
+    }
+    let start = Instant::now();
+    let result = f();
+    *ELAPSED
+        .lock()
+        .unwrap()
+        .entry(source)
+        .or_insert_with(Duration::default) += start.elapsed();
+    result
+}
+
+/// Print the accumulated per-lint-source timing to stderr.
+pub fn report() {
+    let elapsed = ELAPSED.lock().unwrap();
+    if elapsed.is_empty() {
+        return;
+    }
+    eprintln!("Timing by lint source:");
+    let mut sources: Vec<_> = elapsed.iter().collect();
+    sources.sort_by_key(|(_, duration)| std::cmp::Reverse(**duration));
+    for (source, duration) in sources {
+        eprintln!("  {source:?}: {duration:.2?}");
+    }
+}
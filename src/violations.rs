@@ -163,11 +163,15 @@ impl Violation for TypeComparison {
 define_violation!(
     pub struct DoNotUseBareExcept;
 );
-impl Violation for DoNotUseBareExcept {
+impl AlwaysAutofixableViolation for DoNotUseBareExcept {
     fn message(&self) -> String {
         "Do not use bare `except`".to_string()
     }
 
+    fn autofix_title(&self) -> String {
+        "Replace bare `except` with `except Exception`".to_string()
+    }
+
     fn placeholder() -> Self {
         DoNotUseBareExcept
     }
@@ -233,6 +237,191 @@ impl Violation for AmbiguousFunctionName {
     }
 }
 
+define_violation!(
+    pub struct WhitespaceAfterOpenBracket;
+);
+impl AlwaysAutofixableViolation for WhitespaceAfterOpenBracket {
+    fn message(&self) -> String {
+        "Whitespace after '('".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove whitespace".to_string()
+    }
+
+    fn placeholder() -> Self {
+        WhitespaceAfterOpenBracket
+    }
+}
+
+define_violation!(
+    pub struct WhitespaceBeforeCloseBracket;
+);
+impl AlwaysAutofixableViolation for WhitespaceBeforeCloseBracket {
+    fn message(&self) -> String {
+        "Whitespace before ')'".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove whitespace".to_string()
+    }
+
+    fn placeholder() -> Self {
+        WhitespaceBeforeCloseBracket
+    }
+}
+
+define_violation!(
+    pub struct WhitespaceBeforeParameters;
+);
+impl AlwaysAutofixableViolation for WhitespaceBeforeParameters {
+    fn message(&self) -> String {
+        "Whitespace before '(' or '['".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove whitespace".to_string()
+    }
+
+    fn placeholder() -> Self {
+        WhitespaceBeforeParameters
+    }
+}
+
+define_violation!(
+    pub struct MissingWhitespaceAfterComma;
+);
+impl AlwaysAutofixableViolation for MissingWhitespaceAfterComma {
+    fn message(&self) -> String {
+        "Missing whitespace after ','".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Add whitespace after ','".to_string()
+    }
+
+    fn placeholder() -> Self {
+        MissingWhitespaceAfterComma
+    }
+}
+
+define_violation!(
+    pub struct BlankLineBetweenMethods(pub usize);
+);
+impl Violation for BlankLineBetweenMethods {
+    fn message(&self) -> String {
+        let BlankLineBetweenMethods(blank_lines) = self;
+        format!("Expected 1 blank line, found {blank_lines}")
+    }
+
+    fn placeholder() -> Self {
+        BlankLineBetweenMethods(0)
+    }
+}
+
+define_violation!(
+    pub struct BlankLinesTopLevel(pub usize);
+);
+impl Violation for BlankLinesTopLevel {
+    fn message(&self) -> String {
+        let BlankLinesTopLevel(blank_lines) = self;
+        format!("Expected 2 blank lines, found {blank_lines}")
+    }
+
+    fn placeholder() -> Self {
+        BlankLinesTopLevel(0)
+    }
+}
+
+define_violation!(
+    pub struct TooManyBlankLines(pub usize);
+);
+impl Violation for TooManyBlankLines {
+    fn message(&self) -> String {
+        let TooManyBlankLines(blank_lines) = self;
+        format!("Too many blank lines ({blank_lines})")
+    }
+
+    fn placeholder() -> Self {
+        TooManyBlankLines(3)
+    }
+}
+
+define_violation!(
+    pub struct BlankLineBeforeNestedDefinition(pub usize);
+);
+impl Violation for BlankLineBeforeNestedDefinition {
+    fn message(&self) -> String {
+        let BlankLineBeforeNestedDefinition(blank_lines) = self;
+        format!("Expected 1 blank line before a nested definition, found {blank_lines}")
+    }
+
+    fn placeholder() -> Self {
+        BlankLineBeforeNestedDefinition(0)
+    }
+}
+
+define_violation!(
+    pub struct IndentationWithInvalidMultiple(pub usize);
+);
+impl Violation for IndentationWithInvalidMultiple {
+    fn message(&self) -> String {
+        let IndentationWithInvalidMultiple(indent_size) = self;
+        format!("Indentation is not a multiple of {indent_size}")
+    }
+
+    fn placeholder() -> Self {
+        IndentationWithInvalidMultiple(4)
+    }
+}
+
+define_violation!(
+    pub struct OverIndented;
+);
+impl Violation for OverIndented {
+    fn message(&self) -> String {
+        "Over-indented".to_string()
+    }
+
+    fn placeholder() -> Self {
+        OverIndented
+    }
+}
+
+define_violation!(
+    pub struct TrailingWhitespace;
+);
+impl AlwaysAutofixableViolation for TrailingWhitespace {
+    fn message(&self) -> String {
+        "Trailing whitespace".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove trailing whitespace".to_string()
+    }
+
+    fn placeholder() -> Self {
+        TrailingWhitespace
+    }
+}
+
+define_violation!(
+    pub struct WhitespaceOnBlankLine;
+);
+impl AlwaysAutofixableViolation for WhitespaceOnBlankLine {
+    fn message(&self) -> String {
+        "Whitespace on blank line".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove whitespace from blank line".to_string()
+    }
+
+    fn placeholder() -> Self {
+        WhitespaceOnBlankLine
+    }
+}
+
 define_violation!(
     pub struct IOError(pub String);
 );
@@ -298,6 +487,32 @@ impl AlwaysAutofixableViolation for InvalidEscapeSequence {
     }
 }
 
+define_violation!(
+    pub struct LineBreakBeforeBinaryOperator;
+);
+impl Violation for LineBreakBeforeBinaryOperator {
+    fn message(&self) -> String {
+        "Line break occurred before a binary operator".to_string()
+    }
+
+    fn placeholder() -> Self {
+        LineBreakBeforeBinaryOperator
+    }
+}
+
+define_violation!(
+    pub struct LineBreakAfterBinaryOperator;
+);
+impl Violation for LineBreakAfterBinaryOperator {
+    fn message(&self) -> String {
+        "Line break occurred after a binary operator".to_string()
+    }
+
+    fn placeholder() -> Self {
+        LineBreakAfterBinaryOperator
+    }
+}
+
 // pyflakes
 
 define_violation!(
@@ -1079,6 +1294,271 @@ impl Violation for PropertyWithParameters {
     }
 }
 
+define_violation!(
+    pub struct TooManyReturnStatements(pub usize, pub usize);
+);
+impl Violation for TooManyReturnStatements {
+    fn message(&self) -> String {
+        let TooManyReturnStatements(returns, max_returns) = self;
+        format!("Too many return statements ({returns} > {max_returns})")
+    }
+
+    fn placeholder() -> Self {
+        TooManyReturnStatements(0, 0)
+    }
+}
+
+define_violation!(
+    pub struct TooManyBranches(pub usize, pub usize);
+);
+impl Violation for TooManyBranches {
+    fn message(&self) -> String {
+        let TooManyBranches(branches, max_branches) = self;
+        format!("Too many branches ({branches} > {max_branches})")
+    }
+
+    fn placeholder() -> Self {
+        TooManyBranches(0, 0)
+    }
+}
+
+define_violation!(
+    pub struct TooManyArguments(pub usize, pub usize);
+);
+impl Violation for TooManyArguments {
+    fn message(&self) -> String {
+        let TooManyArguments(args, max_args) = self;
+        format!("Too many arguments to function call ({args} > {max_args})")
+    }
+
+    fn placeholder() -> Self {
+        TooManyArguments(0, 0)
+    }
+}
+
+define_violation!(
+    pub struct TooManyStatements(pub usize, pub usize);
+);
+impl Violation for TooManyStatements {
+    fn message(&self) -> String {
+        let TooManyStatements(statements, max_statements) = self;
+        format!("Too many statements ({statements} > {max_statements})")
+    }
+
+    fn placeholder() -> Self {
+        TooManyStatements(0, 0)
+    }
+}
+
+define_violation!(
+    pub struct MagicValueComparison(pub String);
+);
+impl Violation for MagicValueComparison {
+    fn message(&self) -> String {
+        let MagicValueComparison(value) = self;
+        format!(
+            "Magic value used in comparison, consider replacing {value} with a constant variable"
+        )
+    }
+
+    fn placeholder() -> Self {
+        MagicValueComparison("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct RedefinedLoopName(pub String);
+);
+impl Violation for RedefinedLoopName {
+    fn message(&self) -> String {
+        let RedefinedLoopName(name) = self;
+        format!("Outer loop variable `{name}` overwritten by inner assignment target")
+    }
+
+    fn placeholder() -> Self {
+        RedefinedLoopName("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct ReturnInInit;
+);
+impl Violation for ReturnInInit {
+    fn message(&self) -> String {
+        "Explicit return in `__init__`".to_string()
+    }
+
+    fn placeholder() -> Self {
+        ReturnInInit
+    }
+}
+
+define_violation!(
+    pub struct BadDunderMethodSignature(pub String, pub usize);
+);
+impl Violation for BadDunderMethodSignature {
+    fn message(&self) -> String {
+        let BadDunderMethodSignature(name, expected) = self;
+        format!("`{name}` should take {expected} argument(s) in addition to `self`")
+    }
+
+    fn placeholder() -> Self {
+        BadDunderMethodSignature("...".to_string(), 0)
+    }
+}
+
+define_violation!(
+    pub struct ComparisonOfConstant;
+);
+impl Violation for ComparisonOfConstant {
+    fn message(&self) -> String {
+        "Two constants compared in a comparison, consider replacing them with a single constant"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        ComparisonOfConstant
+    }
+}
+
+define_violation!(
+    pub struct ComparisonWithItself;
+);
+impl Violation for ComparisonWithItself {
+    fn message(&self) -> String {
+        "Redundant comparison; both sides of the comparison are the same expression".to_string()
+    }
+
+    fn placeholder() -> Self {
+        ComparisonWithItself
+    }
+}
+
+define_violation!(
+    pub struct UselessReturn;
+);
+impl Violation for UselessReturn {
+    fn message(&self) -> String {
+        "Useless `return` statement at end of function".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UselessReturn
+    }
+}
+
+define_violation!(
+    pub struct UnnecessaryDunderCall(pub String, pub String);
+);
+impl AlwaysAutofixableViolation for UnnecessaryDunderCall {
+    fn message(&self) -> String {
+        let UnnecessaryDunderCall(dunder, builtin) = self;
+        format!("Unnecessary dunder call to `{dunder}`, use `{builtin}(...)` instead")
+    }
+
+    fn autofix_title(&self) -> String {
+        let UnnecessaryDunderCall(_, builtin) = self;
+        format!("Replace with `{builtin}(...)`")
+    }
+
+    fn placeholder() -> Self {
+        UnnecessaryDunderCall("__len__".to_string(), "len".to_string())
+    }
+}
+
+define_violation!(
+    pub struct ImportSelf(pub String);
+);
+impl Violation for ImportSelf {
+    fn message(&self) -> String {
+        let ImportSelf(name) = self;
+        format!("Module `{name}` imports itself")
+    }
+
+    fn placeholder() -> Self {
+        ImportSelf("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct ImportPrivateName(pub String, pub String);
+);
+impl Violation for ImportPrivateName {
+    fn message(&self) -> String {
+        let ImportPrivateName(module, name) = self;
+        format!("Imported private name `{name}` from external module `{module}`")
+    }
+
+    fn placeholder() -> Self {
+        ImportPrivateName("...".to_string(), "..._private".to_string())
+    }
+}
+
+define_violation!(
+    pub struct RepeatedKeywordArgument(pub String);
+);
+impl Violation for RepeatedKeywordArgument {
+    fn message(&self) -> String {
+        let RepeatedKeywordArgument(name) = self;
+        format!("Repeated keyword argument: `{name}`")
+    }
+
+    fn placeholder() -> Self {
+        RepeatedKeywordArgument("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct DuplicateBases(pub String);
+);
+impl Violation for DuplicateBases {
+    fn message(&self) -> String {
+        let DuplicateBases(name) = self;
+        format!("Duplicate base `{name}` for class")
+    }
+
+    fn placeholder() -> Self {
+        DuplicateBases("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct NestedMinMax(pub String);
+);
+impl AlwaysAutofixableViolation for NestedMinMax {
+    fn message(&self) -> String {
+        let NestedMinMax(func) = self;
+        format!("Nested `{func}` calls can be flattened")
+    }
+
+    fn autofix_title(&self) -> String {
+        let NestedMinMax(func) = self;
+        format!("Flatten nested `{func}` call")
+    }
+
+    fn placeholder() -> Self {
+        NestedMinMax("min".to_string())
+    }
+}
+
+define_violation!(
+    pub struct CollapsibleElseIf;
+);
+impl AlwaysAutofixableViolation for CollapsibleElseIf {
+    fn message(&self) -> String {
+        "Consider using `elif` instead of `else:` then `if` to remove one indentation level"
+            .to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Convert to `elif`".to_string()
+    }
+
+    fn placeholder() -> Self {
+        CollapsibleElseIf
+    }
+}
+
 define_violation!(
     pub struct ConsiderUsingFromImport(pub String, pub String);
 );
@@ -1156,6 +1636,32 @@ impl Violation for GlobalVariableNotAssigned {
     }
 }
 
+define_violation!(
+    pub struct UnreachableCode;
+);
+impl Violation for UnreachableCode {
+    fn message(&self) -> String {
+        "Unreachable code".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UnreachableCode
+    }
+}
+
+define_violation!(
+    pub struct UsingConstantTest;
+);
+impl Violation for UsingConstantTest {
+    fn message(&self) -> String {
+        "Using a conditional statement with a constant value".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UsingConstantTest
+    }
+}
+
 // flake8-builtins
 
 define_violation!(
@@ -1261,11 +1767,15 @@ impl Violation for StripWithMultiCharacters {
 define_violation!(
     pub struct MutableArgumentDefault;
 );
-impl Violation for MutableArgumentDefault {
+impl AlwaysAutofixableViolation for MutableArgumentDefault {
     fn message(&self) -> String {
         "Do not use mutable data structures for argument defaults".to_string()
     }
 
+    fn autofix_title(&self) -> String {
+        "Replace with `None`; initialize within function".to_string()
+    }
+
     fn placeholder() -> Self {
         MutableArgumentDefault
     }
@@ -1581,67 +2091,136 @@ impl Violation for DuplicateTryBlockException {
     }
 
     fn placeholder() -> Self {
-        DuplicateTryBlockException("Exception".to_string())
+        DuplicateTryBlockException("Exception".to_string())
+    }
+}
+
+define_violation!(
+    pub struct StarArgUnpackingAfterKeywordArg;
+);
+impl Violation for StarArgUnpackingAfterKeywordArg {
+    fn message(&self) -> String {
+        "Star-arg unpacking after a keyword argument is strongly discouraged. It only works when \
+         the keyword parameter is declared after all parameters supplied by the unpacked sequence, \
+         and this change of ordering can surprise and mislead readers."
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        StarArgUnpackingAfterKeywordArg
+    }
+}
+
+define_violation!(
+    pub struct EmptyMethodWithoutAbstractDecorator(pub String);
+);
+impl Violation for EmptyMethodWithoutAbstractDecorator {
+    fn message(&self) -> String {
+        let EmptyMethodWithoutAbstractDecorator(name) = self;
+        format!(
+            "`{name}` is an empty method in an abstract base class, but has no abstract decorator"
+        )
+    }
+
+    fn placeholder() -> Self {
+        EmptyMethodWithoutAbstractDecorator("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct RaiseWithoutFromInsideExcept;
+);
+impl Violation for RaiseWithoutFromInsideExcept {
+    fn message(&self) -> String {
+        "Within an except clause, raise exceptions with raise ... from err or raise ... from None \
+         to distinguish them from errors in exception handling"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        RaiseWithoutFromInsideExcept
+    }
+}
+
+define_violation!(
+    pub struct ZipWithoutExplicitStrict;
+);
+impl Violation for ZipWithoutExplicitStrict {
+    fn message(&self) -> String {
+        "`zip()` without an explicit `strict=` parameter".to_string()
+    }
+
+    fn placeholder() -> Self {
+        ZipWithoutExplicitStrict
+    }
+}
+
+define_violation!(
+    pub struct NoExplicitStacklevel;
+);
+impl Violation for NoExplicitStacklevel {
+    fn message(&self) -> String {
+        "No explicit `stacklevel` keyword argument found".to_string()
+    }
+
+    fn placeholder() -> Self {
+        NoExplicitStacklevel
     }
 }
 
 define_violation!(
-    pub struct StarArgUnpackingAfterKeywordArg;
+    pub struct StarImportShadowsExisting(pub String);
 );
-impl Violation for StarArgUnpackingAfterKeywordArg {
+impl Violation for StarImportShadowsExisting {
     fn message(&self) -> String {
-        "Star-arg unpacking after a keyword argument is strongly discouraged. It only works when \
-         the keyword parameter is declared after all parameters supplied by the unpacked sequence, \
-         and this change of ordering can surprise and mislead readers."
-            .to_string()
+        let StarImportShadowsExisting(name) = self;
+        format!("`from {name} import *` shadows names already defined in this scope")
     }
 
     fn placeholder() -> Self {
-        StarArgUnpackingAfterKeywordArg
+        StarImportShadowsExisting("...".to_string())
     }
 }
 
 define_violation!(
-    pub struct EmptyMethodWithoutAbstractDecorator(pub String);
+    pub struct ExceptWithNonExceptionClasses;
 );
-impl Violation for EmptyMethodWithoutAbstractDecorator {
+impl Violation for ExceptWithNonExceptionClasses {
     fn message(&self) -> String {
-        let EmptyMethodWithoutAbstractDecorator(name) = self;
-        format!(
-            "`{name}` is an empty method in an abstract base class, but has no abstract decorator"
-        )
+        "`except` handlers should only be exception classes or tuples of exception classes"
+            .to_string()
     }
 
     fn placeholder() -> Self {
-        EmptyMethodWithoutAbstractDecorator("...".to_string())
+        ExceptWithNonExceptionClasses
     }
 }
 
 define_violation!(
-    pub struct RaiseWithoutFromInsideExcept;
+    pub struct ReuseOfGroupbyGenerator;
 );
-impl Violation for RaiseWithoutFromInsideExcept {
+impl Violation for ReuseOfGroupbyGenerator {
     fn message(&self) -> String {
-        "Within an except clause, raise exceptions with raise ... from err or raise ... from None \
-         to distinguish them from errors in exception handling"
+        "Using the generator returned from `itertools.groupby()` more than once will do nothing \
+         on the second usage"
             .to_string()
     }
 
     fn placeholder() -> Self {
-        RaiseWithoutFromInsideExcept
+        ReuseOfGroupbyGenerator
     }
 }
 
 define_violation!(
-    pub struct ZipWithoutExplicitStrict;
+    pub struct DictComprehensionWithStaticKey;
 );
-impl Violation for ZipWithoutExplicitStrict {
+impl Violation for DictComprehensionWithStaticKey {
     fn message(&self) -> String {
-        "`zip()` without an explicit `strict=` parameter".to_string()
+        "Dict comprehension uses a static key; consider a `dict` literal instead".to_string()
     }
 
     fn placeholder() -> Self {
-        ZipWithoutExplicitStrict
+        DictComprehensionWithStaticKey
     }
 }
 
@@ -1975,6 +2554,40 @@ impl Violation for UnnecessaryMap {
     }
 }
 
+define_violation!(
+    pub struct UnnecessaryDictPassedToDict;
+);
+impl AlwaysAutofixableViolation for UnnecessaryDictPassedToDict {
+    fn message(&self) -> String {
+        "Unnecessary `dict` passed to `dict()` (remove the outer call to `dict()`)".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove outer `dict` call".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UnnecessaryDictPassedToDict
+    }
+}
+
+define_violation!(
+    pub struct UnnecessaryComprehensionAnyAll;
+);
+impl AlwaysAutofixableViolation for UnnecessaryComprehensionAnyAll {
+    fn message(&self) -> String {
+        "Unnecessary comprehension (rewrite using a generator expression)".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Rewrite as a generator expression".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UnnecessaryComprehensionAnyAll
+    }
+}
+
 // flake8-debugger
 
 define_violation!(
@@ -2255,7 +2868,7 @@ impl AlwaysAutofixableViolation for PPrintFound {
 define_violation!(
     pub struct BadQuotesInlineString(pub Quote);
 );
-impl Violation for BadQuotesInlineString {
+impl AlwaysAutofixableViolation for BadQuotesInlineString {
     fn message(&self) -> String {
         let BadQuotesInlineString(quote) = self;
         match quote {
@@ -2264,6 +2877,10 @@ impl Violation for BadQuotesInlineString {
         }
     }
 
+    fn autofix_title(&self) -> String {
+        "Replace double quotes with single quotes".to_string()
+    }
+
     fn placeholder() -> Self {
         BadQuotesInlineString(Quote::Double)
     }
@@ -2272,7 +2889,7 @@ impl Violation for BadQuotesInlineString {
 define_violation!(
     pub struct BadQuotesMultilineString(pub Quote);
 );
-impl Violation for BadQuotesMultilineString {
+impl AlwaysAutofixableViolation for BadQuotesMultilineString {
     fn message(&self) -> String {
         let BadQuotesMultilineString(quote) = self;
         match quote {
@@ -2281,6 +2898,10 @@ impl Violation for BadQuotesMultilineString {
         }
     }
 
+    fn autofix_title(&self) -> String {
+        "Replace multiline string quotes".to_string()
+    }
+
     fn placeholder() -> Self {
         BadQuotesMultilineString(Quote::Double)
     }
@@ -2289,7 +2910,7 @@ impl Violation for BadQuotesMultilineString {
 define_violation!(
     pub struct BadQuotesDocstring(pub Quote);
 );
-impl Violation for BadQuotesDocstring {
+impl AlwaysAutofixableViolation for BadQuotesDocstring {
     fn message(&self) -> String {
         let BadQuotesDocstring(quote) = self;
         match quote {
@@ -2298,6 +2919,10 @@ impl Violation for BadQuotesDocstring {
         }
     }
 
+    fn autofix_title(&self) -> String {
+        "Replace docstring quotes".to_string()
+    }
+
     fn placeholder() -> Self {
         BadQuotesDocstring(Quote::Double)
     }
@@ -2306,11 +2931,15 @@ impl Violation for BadQuotesDocstring {
 define_violation!(
     pub struct AvoidQuoteEscape;
 );
-impl Violation for AvoidQuoteEscape {
+impl AlwaysAutofixableViolation for AvoidQuoteEscape {
     fn message(&self) -> String {
         "Change outer quotes to avoid escaping inner quotes".to_string()
     }
 
+    fn autofix_title(&self) -> String {
+        "Change outer quotes to avoid escaping inner quotes".to_string()
+    }
+
     fn placeholder() -> Self {
         AvoidQuoteEscape
     }
@@ -2635,11 +3264,15 @@ impl AlwaysAutofixableViolation for DuplicateIsinstanceCall {
 define_violation!(
     pub struct NestedIfStatements;
 );
-impl Violation for NestedIfStatements {
+impl AlwaysAutofixableViolation for NestedIfStatements {
     fn message(&self) -> String {
         "Use a single `if` statement instead of nested `if` statements".to_string()
     }
 
+    fn autofix_title(&self) -> String {
+        "Combine `if` statements using `and`".to_string()
+    }
+
     fn placeholder() -> Self {
         NestedIfStatements
     }
@@ -2667,17 +3300,36 @@ impl AlwaysAutofixableViolation for ReturnBoolConditionDirectly {
 define_violation!(
     pub struct UseContextlibSuppress(pub String);
 );
-impl Violation for UseContextlibSuppress {
+impl AlwaysAutofixableViolation for UseContextlibSuppress {
     fn message(&self) -> String {
         let UseContextlibSuppress(exception) = self;
         format!("Use `contextlib.suppress({exception})` instead of try-except-pass")
     }
 
+    fn autofix_title(&self) -> String {
+        let UseContextlibSuppress(exception) = self;
+        format!("Replace with `contextlib.suppress({exception})`")
+    }
+
     fn placeholder() -> Self {
         UseContextlibSuppress("...".to_string())
     }
 }
 
+define_violation!(
+    pub struct UseCapitalEnvironmentVariables(pub String, pub String);
+);
+impl Violation for UseCapitalEnvironmentVariables {
+    fn message(&self) -> String {
+        let UseCapitalEnvironmentVariables(original, expected) = self;
+        format!("Use capitalized environment variable `{expected}` instead of `{original}`")
+    }
+
+    fn placeholder() -> Self {
+        UseCapitalEnvironmentVariables("foo".to_string(), "FOO".to_string())
+    }
+}
+
 define_violation!(
     pub struct ReturnInTryExceptFinally;
 );
@@ -2950,6 +3602,43 @@ impl AlwaysAutofixableViolation for YodaConditions {
     }
 }
 
+define_violation!(
+    pub struct UseDictGetWithDefault(pub String, pub String, pub String, pub String);
+);
+impl AlwaysAutofixableViolation for UseDictGetWithDefault {
+    fn message(&self) -> String {
+        let UseDictGetWithDefault(_, dict, key, default) = self;
+        format!("Use `{dict}.get({key}, {default})` instead of an `if` block")
+    }
+
+    fn autofix_title(&self) -> String {
+        let UseDictGetWithDefault(target, dict, key, default) = self;
+        format!("Replace with `{target} = {dict}.get({key}, {default})`")
+    }
+
+    fn placeholder() -> Self {
+        UseDictGetWithDefault(
+            "var".to_string(),
+            "dict".to_string(),
+            "key".to_string(),
+            "default".to_string(),
+        )
+    }
+}
+
+define_violation!(
+    pub struct UseContextManagerForOpen;
+);
+impl Violation for UseContextManagerForOpen {
+    fn message(&self) -> String {
+        "Use context handler for opening files".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UseContextManagerForOpen
+    }
+}
+
 define_violation!(
     pub struct IfExprWithTrueFalse(pub String);
 );
@@ -3648,11 +4337,15 @@ impl Violation for PublicInit {
 define_violation!(
     pub struct FitsOnOneLine;
 );
-impl Violation for FitsOnOneLine {
+impl AlwaysAutofixableViolation for FitsOnOneLine {
     fn message(&self) -> String {
         "One-line docstring should fit on one line".to_string()
     }
 
+    fn autofix_title(&self) -> String {
+        "Collapse to one line".to_string()
+    }
+
     fn placeholder() -> Self {
         FitsOnOneLine
     }
@@ -3747,11 +4440,7 @@ impl Violation for BlankLineAfterSummary {
     }
 
     fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
-        let num_lines = self.0;
-        if num_lines > 0 {
-            return Some(fmt_blank_line_after_summary_autofix_msg);
-        }
-        None
+        Some(fmt_blank_line_after_summary_autofix_msg)
     }
 
     fn placeholder() -> Self {
@@ -3980,11 +4669,15 @@ impl Violation for NoSignature {
 define_violation!(
     pub struct FirstLineCapitalized;
 );
-impl Violation for FirstLineCapitalized {
+impl AlwaysAutofixableViolation for FirstLineCapitalized {
     fn message(&self) -> String {
         "First word of the first line should be properly capitalized".to_string()
     }
 
+    fn autofix_title(&self) -> String {
+        "Capitalize first word".to_string()
+    }
+
     fn placeholder() -> Self {
         FirstLineCapitalized
     }
@@ -4536,11 +5229,225 @@ define_violation!(
 );
 impl Violation for ExecUsed {
     fn message(&self) -> String {
-        "Use of `exec` detected".to_string()
+        "Use of `exec` detected".to_string()
+    }
+
+    fn placeholder() -> Self {
+        ExecUsed
+    }
+}
+
+define_violation!(
+    pub struct SubprocessPopenWithShellEqualsTrue;
+);
+impl Violation for SubprocessPopenWithShellEqualsTrue {
+    fn message(&self) -> String {
+        "`subprocess` call with `shell=True` identified, security issue".to_string()
+    }
+
+    fn placeholder() -> Self {
+        SubprocessPopenWithShellEqualsTrue
+    }
+}
+
+define_violation!(
+    pub struct CallWithShellEqualsTrue;
+);
+impl Violation for CallWithShellEqualsTrue {
+    fn message(&self) -> String {
+        "Function call with `shell=True` parameter identified, security issue".to_string()
+    }
+
+    fn placeholder() -> Self {
+        CallWithShellEqualsTrue
+    }
+}
+
+define_violation!(
+    pub struct SubprocessWithoutShellEqualsTrue;
+);
+impl Violation for SubprocessWithoutShellEqualsTrue {
+    fn message(&self) -> String {
+        "`subprocess` call: check for execution of untrusted input".to_string()
+    }
+
+    fn placeholder() -> Self {
+        SubprocessWithoutShellEqualsTrue
+    }
+}
+
+define_violation!(
+    pub struct StartProcessWithAShell;
+);
+impl Violation for StartProcessWithAShell {
+    fn message(&self) -> String {
+        "Starting a process with a shell, possible injection detected".to_string()
+    }
+
+    fn placeholder() -> Self {
+        StartProcessWithAShell
+    }
+}
+
+define_violation!(
+    pub struct StartProcessWithNoShell;
+);
+impl Violation for StartProcessWithNoShell {
+    fn message(&self) -> String {
+        "Starting a process without a shell".to_string()
+    }
+
+    fn placeholder() -> Self {
+        StartProcessWithNoShell
+    }
+}
+
+define_violation!(
+    pub struct StartProcessWithPartialPath;
+);
+impl Violation for StartProcessWithPartialPath {
+    fn message(&self) -> String {
+        "Starting a process with a partial executable path".to_string()
+    }
+
+    fn placeholder() -> Self {
+        StartProcessWithPartialPath
+    }
+}
+
+define_violation!(
+    pub struct UnixCommandWildcardInjection;
+);
+impl Violation for UnixCommandWildcardInjection {
+    fn message(&self) -> String {
+        "Possible wildcard injection in call due to `*` usage".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UnixCommandWildcardInjection
+    }
+}
+
+define_violation!(
+    pub struct SuspiciousPickleUsage;
+);
+impl Violation for SuspiciousPickleUsage {
+    fn message(&self) -> String {
+        "Consider possible security implications associated with the `pickle` module"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        SuspiciousPickleUsage
+    }
+}
+
+define_violation!(
+    pub struct SuspiciousMarshalUsage;
+);
+impl Violation for SuspiciousMarshalUsage {
+    fn message(&self) -> String {
+        "Consider possible security implications associated with the `marshal` module"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        SuspiciousMarshalUsage
+    }
+}
+
+define_violation!(
+    pub struct InsecureCipherUsage(pub String);
+);
+impl Violation for InsecureCipherUsage {
+    fn message(&self) -> String {
+        let InsecureCipherUsage(cipher) = self;
+        format!("Use of insecure cipher `{cipher}`, known to have security issues")
+    }
+
+    fn placeholder() -> Self {
+        InsecureCipherUsage("ARC4".to_string())
+    }
+}
+
+define_violation!(
+    pub struct InsecureCipherModeUsage(pub String);
+);
+impl Violation for InsecureCipherModeUsage {
+    fn message(&self) -> String {
+        let InsecureCipherModeUsage(mode) = self;
+        format!("Use of insecure cipher mode `{mode}`, known to have security issues")
+    }
+
+    fn placeholder() -> Self {
+        InsecureCipherModeUsage("ECB".to_string())
+    }
+}
+
+define_violation!(
+    pub struct InsecureSSLProtocolUsage(pub String);
+);
+impl Violation for InsecureSSLProtocolUsage {
+    fn message(&self) -> String {
+        let InsecureSSLProtocolUsage(protocol) = self;
+        format!("Use of insecure SSL/TLS protocol `{protocol}`, known to have security issues")
+    }
+
+    fn placeholder() -> Self {
+        InsecureSSLProtocolUsage("PROTOCOL_TLSv1".to_string())
+    }
+}
+
+define_violation!(
+    pub struct SuspiciousNonCryptographicRandomUsage;
+);
+impl Violation for SuspiciousNonCryptographicRandomUsage {
+    fn message(&self) -> String {
+        "Standard pseudo-random generators are not suitable for security/cryptographic purposes"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        SuspiciousNonCryptographicRandomUsage
+    }
+}
+
+define_violation!(
+    pub struct HardcodedSQLExpression;
+);
+impl Violation for HardcodedSQLExpression {
+    fn message(&self) -> String {
+        "Possible SQL injection vector through string-based query construction".to_string()
+    }
+
+    fn placeholder() -> Self {
+        HardcodedSQLExpression
+    }
+}
+
+define_violation!(
+    pub struct TryExceptPass;
+);
+impl Violation for TryExceptPass {
+    fn message(&self) -> String {
+        "`try`-`except`-`pass` detected, consider logging the exception".to_string()
+    }
+
+    fn placeholder() -> Self {
+        TryExceptPass
+    }
+}
+
+define_violation!(
+    pub struct TryExceptContinue;
+);
+impl Violation for TryExceptContinue {
+    fn message(&self) -> String {
+        "`try`-`except`-`continue` detected, consider logging the exception".to_string()
     }
 
     fn placeholder() -> Self {
-        ExecUsed
+        TryExceptContinue
     }
 }
 
@@ -4967,6 +5874,32 @@ impl Violation for CallDateFromtimestamp {
     }
 }
 
+define_violation!(
+    pub struct CallDatetimeTimeWithoutTzinfo;
+);
+impl Violation for CallDatetimeTimeWithoutTzinfo {
+    fn message(&self) -> String {
+        "The use of `datetime.time()` without `tzinfo` argument is not allowed".to_string()
+    }
+
+    fn placeholder() -> Self {
+        CallDatetimeTimeWithoutTzinfo
+    }
+}
+
+define_violation!(
+    pub struct CallDatetimeAstimezoneOnNaiveDatetime;
+);
+impl Violation for CallDatetimeAstimezoneOnNaiveDatetime {
+    fn message(&self) -> String {
+        "The use of `.astimezone()` on a naive `datetime.datetime` is not allowed".to_string()
+    }
+
+    fn placeholder() -> Self {
+        CallDatetimeAstimezoneOnNaiveDatetime
+    }
+}
+
 // pygrep-hooks
 
 define_violation!(
@@ -5021,6 +5954,20 @@ impl Violation for BlanketNOQA {
     }
 }
 
+define_violation!(
+    pub struct InvalidMockAccess(pub String);
+);
+impl Violation for InvalidMockAccess {
+    fn message(&self) -> String {
+        let InvalidMockAccess(name) = self;
+        format!("Mock method `{name}` should be called with `assert_{name}`")
+    }
+
+    fn placeholder() -> Self {
+        InvalidMockAccess("called_once_with".to_string())
+    }
+}
+
 // pandas-vet
 
 define_violation!(
@@ -5169,15 +6116,29 @@ impl Violation for UseOfPdMerge {
 }
 
 define_violation!(
-    pub struct DfIsABadVariableName;
+    pub struct UseOfLenAndUnique;
+);
+impl Violation for UseOfLenAndUnique {
+    fn message(&self) -> String {
+        "Use `.nunique()` instead of `len(.unique())`".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UseOfLenAndUnique
+    }
+}
+
+define_violation!(
+    pub struct DfIsABadVariableName(pub String);
 );
 impl Violation for DfIsABadVariableName {
     fn message(&self) -> String {
-        "`df` is a bad variable name. Be kinder to your future self.".to_string()
+        let DfIsABadVariableName(name) = self;
+        format!("`{name}` is a bad variable name. Be kinder to your future self.")
     }
 
     fn placeholder() -> Self {
-        DfIsABadVariableName
+        DfIsABadVariableName("df".to_string())
     }
 }
 
@@ -5735,6 +6696,96 @@ impl Violation for KeywordArgumentBeforeStarArgument {
     }
 }
 
+define_violation!(
+    pub struct ImplicitStringConcatenationInCollection;
+);
+impl Violation for ImplicitStringConcatenationInCollection {
+    fn message(&self) -> String {
+        "Implicitly concatenated string literals in a list, tuple, set, dict, or call argument \
+         list are likely a missing comma"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        ImplicitStringConcatenationInCollection
+    }
+}
+
+define_violation!(
+    pub struct CollectionLiteralConcatenation;
+);
+impl AlwaysAutofixableViolation for CollectionLiteralConcatenation {
+    fn message(&self) -> String {
+        "Consider iterable unpacking instead of concatenation".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Replace with iterable unpacking".to_string()
+    }
+
+    fn placeholder() -> Self {
+        CollectionLiteralConcatenation
+    }
+}
+
+define_violation!(
+    pub struct UnsortedDunderAll;
+);
+impl Violation for UnsortedDunderAll {
+    fn message(&self) -> String {
+        "`__all__` is not sorted".to_string()
+    }
+
+    fn autofix_title_formatter(&self) -> Option<fn(&Self) -> String> {
+        Some(|_| "Sort `__all__`".to_string())
+    }
+
+    fn placeholder() -> Self {
+        UnsortedDunderAll
+    }
+}
+
+define_violation!(
+    pub struct AssertMessageSideEffect;
+);
+impl Violation for AssertMessageSideEffect {
+    fn message(&self) -> String {
+        "`assert` message should not have side effects, since it only runs when the assertion \
+         fails"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        AssertMessageSideEffect
+    }
+}
+
+define_violation!(
+    pub struct MutableDataclassDefault;
+);
+impl Violation for MutableDataclassDefault {
+    fn message(&self) -> String {
+        "Do not use mutable default values for dataclass attributes".to_string()
+    }
+
+    fn placeholder() -> Self {
+        MutableDataclassDefault
+    }
+}
+
+define_violation!(
+    pub struct MutableClassDefault;
+);
+impl Violation for MutableClassDefault {
+    fn message(&self) -> String {
+        "Mutable class attributes should be annotated with `typing.ClassVar`".to_string()
+    }
+
+    fn placeholder() -> Self {
+        MutableClassDefault
+    }
+}
+
 define_violation!(
     pub struct UnusedNOQA(pub Option<UnusedCodes>);
 );
@@ -5792,3 +6843,234 @@ impl AlwaysAutofixableViolation for UnusedNOQA {
         UnusedNOQA(None)
     }
 }
+
+define_violation!(
+    pub struct MalformedNOQA;
+);
+impl AlwaysAutofixableViolation for MalformedNOQA {
+    fn message(&self) -> String {
+        "Malformed `noqa` directive".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Remove malformed `noqa` directive".to_string()
+    }
+
+    fn placeholder() -> Self {
+        MalformedNOQA
+    }
+}
+
+define_violation!(
+    pub struct MisplacedNOQA(pub usize);
+);
+impl Violation for MisplacedNOQA {
+    fn message(&self) -> String {
+        let MisplacedNOQA(lineno) = self;
+        format!("`noqa` directive on this line is ineffective; should be on line {lineno}")
+    }
+
+    fn placeholder() -> Self {
+        MisplacedNOQA(0)
+    }
+}
+
+define_violation!(
+    pub struct MixedLineEndings;
+);
+impl AlwaysAutofixableViolation for MixedLineEndings {
+    fn message(&self) -> String {
+        "File contains a mix of line endings".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Normalize line endings".to_string()
+    }
+
+    fn placeholder() -> Self {
+        MixedLineEndings
+    }
+}
+
+// flake8-pyi
+
+define_violation!(
+    pub struct DocstringInStub;
+);
+impl Violation for DocstringInStub {
+    fn message(&self) -> String {
+        "Docstrings should not be included in stubs".to_string()
+    }
+
+    fn placeholder() -> Self {
+        DocstringInStub
+    }
+}
+
+define_violation!(
+    pub struct NonEmptyStubBody;
+);
+impl Violation for NonEmptyStubBody {
+    fn message(&self) -> String {
+        "Function body must contain only `...`".to_string()
+    }
+
+    fn placeholder() -> Self {
+        NonEmptyStubBody
+    }
+}
+
+// numpy
+
+define_violation!(
+    pub struct DeprecatedTypeAlias(pub String);
+);
+impl AlwaysAutofixableViolation for DeprecatedTypeAlias {
+    fn message(&self) -> String {
+        let DeprecatedTypeAlias(name) = self;
+        format!("Type alias `{name}` is deprecated, replace with builtin type")
+    }
+
+    fn autofix_title(&self) -> String {
+        let DeprecatedTypeAlias(name) = self;
+        format!("Replace `{name}` with builtin type")
+    }
+
+    fn placeholder() -> Self {
+        DeprecatedTypeAlias("numpy.bool".to_string())
+    }
+}
+
+define_violation!(
+    pub struct LegacyNumpyRandom(pub String);
+);
+impl Violation for LegacyNumpyRandom {
+    fn message(&self) -> String {
+        let LegacyNumpyRandom(name) = self;
+        format!(
+            "Use of legacy `numpy.random.{name}`; prefer `numpy.random.Generator` instead"
+        )
+    }
+
+    fn placeholder() -> Self {
+        LegacyNumpyRandom("seed".to_string())
+    }
+}
+
+// perflint
+
+define_violation!(
+    pub struct UnnecessaryListCast;
+);
+impl Violation for UnnecessaryListCast {
+    fn message(&self) -> String {
+        "Use `next(iter(...))` instead of casting to a `list` to access the first element"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        UnnecessaryListCast
+    }
+}
+
+define_violation!(
+    pub struct TryExceptInLoop;
+);
+impl Violation for TryExceptInLoop {
+    fn message(&self) -> String {
+        "`try`-`except` block within a loop incurs performance overhead on every iteration"
+            .to_string()
+    }
+
+    fn placeholder() -> Self {
+        TryExceptInLoop
+    }
+}
+
+// furb
+
+define_violation!(
+    pub struct FloatInfLiteral;
+);
+impl AlwaysAutofixableViolation for FloatInfLiteral {
+    fn message(&self) -> String {
+        "Use `math.inf` instead of `float(\"inf\")`".to_string()
+    }
+
+    fn autofix_title(&self) -> String {
+        "Replace with `math.inf`".to_string()
+    }
+
+    fn placeholder() -> Self {
+        FloatInfLiteral
+    }
+}
+
+define_violation!(
+    pub struct IfElseDictGet;
+);
+impl Violation for IfElseDictGet {
+    fn message(&self) -> String {
+        "Use `dict.get(key, default)` instead of an `if`-`else` block".to_string()
+    }
+
+    fn placeholder() -> Self {
+        IfElseDictGet
+    }
+}
+
+// darglint
+
+define_violation!(
+    pub struct UndocumentedReturn;
+);
+impl Violation for UndocumentedReturn {
+    fn message(&self) -> String {
+        "Function returns a value that is not documented in a `Returns` section".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UndocumentedReturn
+    }
+}
+
+define_violation!(
+    pub struct UndocumentedYield;
+);
+impl Violation for UndocumentedYield {
+    fn message(&self) -> String {
+        "Function yields a value that is not documented in a `Yields` section".to_string()
+    }
+
+    fn placeholder() -> Self {
+        UndocumentedYield
+    }
+}
+
+define_violation!(
+    pub struct UndocumentedException(pub String);
+);
+impl Violation for UndocumentedException {
+    fn message(&self) -> String {
+        let UndocumentedException(name) = self;
+        format!("Raised exception `{name}` is not documented in the docstring")
+    }
+
+    fn placeholder() -> Self {
+        UndocumentedException("...".to_string())
+    }
+}
+
+define_violation!(
+    pub struct SyntaxErrorInDoctest(pub String);
+);
+impl Violation for SyntaxErrorInDoctest {
+    fn message(&self) -> String {
+        let SyntaxErrorInDoctest(parse_error) = self;
+        format!("Docstring contains a doctest with invalid syntax: {parse_error}")
+    }
+
+    fn placeholder() -> Self {
+        SyntaxErrorInDoctest("...".to_string())
+    }
+}